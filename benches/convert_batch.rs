@@ -0,0 +1,65 @@
+//! Compares per-item conversion with `convert_batch` on a day of log timestamps.
+//! 
+//! Filling a freshly allocated million-item vector costs about as much as converting into
+//! it, so each approach is timed both into a new vector and into a reused one.
+//! 
+//! Run with `cargo bench --bench convert_batch`.
+
+use std::hint::black_box;
+use std::time::{Duration as StdDuration, Instant};
+
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use timezone_converter::TimeZoneConverter;
+
+const ITEMS: i64 = 1_000_000;
+const ROUNDS: u32 = 5;
+
+fn time<F: FnMut()>(mut f: F) -> StdDuration {
+    let mut best = StdDuration::MAX;
+    for _ in 0..ROUNDS {
+        let start = Instant::now();
+        f();
+        best = best.min(start.elapsed());
+    }
+    best
+}
+
+fn main() {
+    let converter = TimeZoneConverter::new("UTC", "America/New_York").unwrap();
+
+    // One timestamp every ~86ms across the spring-forward day
+    let start = Utc.with_ymd_and_hms(2024, 3, 10, 0, 0, 0).unwrap();
+    let items: Vec<DateTime<Utc>> = (0..ITEMS)
+        .map(|i| start + Duration::microseconds(i * 86_400))
+        .collect();
+
+    let per_item = time(|| {
+        let converted: Vec<_> = items.iter().map(|dt| converter.convert(*dt).unwrap()).collect();
+        black_box(converted);
+    });
+
+    let mut reused = Vec::new();
+    let per_item_into = time(|| {
+        reused.clear();
+        reused.extend(items.iter().map(|dt| converter.convert(*dt).unwrap()));
+        black_box(&reused);
+    });
+
+    let batch = time(|| {
+        black_box(converter.convert_batch(black_box(&items)).unwrap());
+    });
+
+    let mut buffer = Vec::new();
+    let batch_into = time(|| {
+        converter.convert_batch_into(black_box(&items), &mut buffer).unwrap();
+        black_box(&buffer);
+    });
+
+    println!("{} items, best of {} rounds", ITEMS, ROUNDS);
+    println!("into a new vector:");
+    println!("  convert (per item):  {:?}", per_item);
+    println!("  convert_batch:       {:?} ({:.1}x)", batch, per_item.as_secs_f64() / batch.as_secs_f64());
+    println!("into a reused vector:");
+    println!("  convert (per item):  {:?}", per_item_into);
+    println!("  convert_batch_into:  {:?} ({:.1}x)", batch_into, per_item_into.as_secs_f64() / batch_into.as_secs_f64());
+}
@@ -0,0 +1,114 @@
+#ifndef TIMEZONE_CONVERTER_H
+#define TIMEZONE_CONVERTER_H
+
+/* Generated by cbindgen from src/ffi.rs; do not edit by hand. */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+// The result of every fallible `tzc_` function
+typedef enum TzcStatus {
+  // The call succeeded and its outputs were written
+  TZC_STATUS_OK = 0,
+  // A required pointer was null or a string was not valid UTF-8
+  TZC_STATUS_INVALID_ARGUMENT = 1,
+  // A timezone identifier was not recognised
+  TZC_STATUS_INVALID_TIME_ZONE = 2,
+  // The instant is outside the UTC years 1 to 9999 that the converter supports
+  TZC_STATUS_OUT_OF_RANGE = 3,
+} TzcStatus;
+
+// Which of a converter's two timezones to describe, passed to [`tzc_local_time`] as its value
+typedef enum TzcZone {
+  // The timezone conversions start from
+  TZC_ZONE_SOURCE = 0,
+  // The timezone conversions produce
+  TZC_ZONE_TARGET = 1,
+} TzcZone;
+
+// A converter created by [`tzc_converter_new`], opaque to C
+typedef struct TzcConverter TzcConverter;
+
+// A wall-clock time in a timezone, with the offset and DST status in effect
+typedef struct TzcLocalTime {
+  // The year, e.g. 2024
+  int32_t year;
+  // The month, 1 to 12
+  uint32_t month;
+  // The day of the month, 1 to 31
+  uint32_t day;
+  // The hour, 0 to 23
+  uint32_t hour;
+  // The minute, 0 to 59
+  uint32_t minute;
+  // The second, 0 to 59
+  uint32_t second;
+  // The offset from UTC in seconds, positive east of Greenwich
+  int32_t offset_seconds;
+  // Whether Daylight Saving Time is in effect
+  bool is_dst;
+} TzcLocalTime;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+// Creates a converter between two IANA timezone identifiers or literal offsets such as `"+05:30"`
+// 
+// On success the converter is written to `out` and must be released with
+// [`tzc_converter_free`].
+// 
+// # Safety
+// 
+// `source` and `target` must be null or point to NUL-terminated strings, and `out` must
+// be null or valid for writing a pointer.
+enum TzcStatus tzc_converter_new(const char *source, const char *target, struct TzcConverter **out);
+
+// Releases a converter created by [`tzc_converter_new`]; null is ignored
+// 
+// # Safety
+// 
+// `converter` must be null or a pointer returned by [`tzc_converter_new`] that has not
+// already been freed.
+void tzc_converter_free(struct TzcConverter *converter);
+
+// Converts Unix epoch seconds to the wall-clock time in the target timezone
+// 
+// # Safety
+// 
+// `converter` must be null or a live pointer from [`tzc_converter_new`], and `out` must be
+// null or valid for writing a [`TzcLocalTime`].
+enum TzcStatus tzc_convert_epoch(const struct TzcConverter *converter,
+                                 int64_t epoch_seconds,
+                                 struct TzcLocalTime *out);
+
+// Gets the wall-clock time, offset and DST status of either timezone at Unix epoch seconds
+// 
+// `zone` is a [`TzcZone`] value; any other value gives `TZC_STATUS_INVALID_ARGUMENT`.
+// 
+// # Safety
+// 
+// `converter` must be null or a live pointer from [`tzc_converter_new`], and `out` must be
+// null or valid for writing a [`TzcLocalTime`].
+enum TzcStatus tzc_local_time(const struct TzcConverter *converter,
+                              uint32_t zone,
+                              int64_t epoch_seconds,
+                              struct TzcLocalTime *out);
+
+// Gets the source offset minus the target offset in seconds at Unix epoch seconds
+// 
+// # Safety
+// 
+// `converter` must be null or a live pointer from [`tzc_converter_new`], and `out` must be
+// null or valid for writing an `int64_t`.
+enum TzcStatus tzc_difference_seconds(const struct TzcConverter *converter,
+                                      int64_t epoch_seconds,
+                                      int64_t *out);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* TIMEZONE_CONVERTER_H */
@@ -0,0 +1,130 @@
+//! Resolving timezone abbreviations such as "EST" or "CST" to zones
+
+use chrono_tz::Tz;
+
+use crate::tab_rows;
+
+/// Common abbreviations and the zones they stand for
+const ABBREVIATION_TAB: &str = include_str!("../data/abbreviations.tab");
+
+/// A part of the world, as named by the first component of tz database identifiers
+/// 
+/// Used to narrow down what an abbreviation means: "IST" is India Standard Time in
+/// [`Region::Asia`] but Irish Standard Time in [`Region::Europe`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Region {
+    /// Zones under `Africa/`
+    Africa,
+    /// Zones under `America/`, covering North and South America
+    America,
+    /// Zones under `Antarctica/`
+    Antarctica,
+    /// Zones under `Asia/`
+    Asia,
+    /// Zones under `Atlantic/`
+    Atlantic,
+    /// Zones under `Australia/`
+    Australia,
+    /// Zones under `Europe/`
+    Europe,
+    /// Zones under `Indian/`
+    Indian,
+    /// Zones under `Pacific/`
+    Pacific,
+}
+
+impl Region {
+    /// The identifier component zones in the region start with
+    fn area(self) -> &'static str {
+        match self {
+            Region::Africa => "Africa",
+            Region::America => "America",
+            Region::Antarctica => "Antarctica",
+            Region::Asia => "Asia",
+            Region::Atlantic => "Atlantic",
+            Region::Australia => "Australia",
+            Region::Europe => "Europe",
+            Region::Indian => "Indian",
+            Region::Pacific => "Pacific",
+        }
+    }
+
+    /// Whether a zone is in the region
+    fn contains(self, tz: Tz) -> bool {
+        tz.name().split('/').next() == Some(self.area())
+    }
+}
+
+/// Finds the zones a timezone abbreviation may stand for, most common meaning first
+/// 
+/// Abbreviations are matched case-insensitively against a table of those in common use,
+/// each mapped to the zone it usually means (so "EST" gives `America/New_York`, which
+/// observes DST, rather than a fixed offset). An abbreviation with several meanings, such
+/// as "CST" (US Central or China), gives one zone per meaning unless `hint` narrows it down.
+/// 
+/// # Arguments
+/// 
+/// * `abbreviation` - The abbreviation (e.g., "CST")
+/// * `hint` - The region the abbreviation is used in, if known
+/// 
+/// # Returns
+/// 
+/// * `Vec<Tz>` - The zones, or an empty list for an unknown abbreviation
+/// 
+/// # Example
+/// 
+/// ```rust
+/// use chrono_tz::Tz;
+/// use timezone_converter::{resolve_abbreviation, Region};
+/// 
+/// assert_eq!(resolve_abbreviation("CST", None), [Tz::America__Chicago, Tz::Asia__Shanghai]);
+/// assert_eq!(resolve_abbreviation("cst", Some(Region::Asia)), [Tz::Asia__Shanghai]);
+/// assert_eq!(resolve_abbreviation("PST", None), [Tz::America__Los_Angeles]);
+/// ```
+pub fn resolve_abbreviation(abbreviation: &str, hint: Option<Region>) -> Vec<Tz> {
+    let abbreviation = abbreviation.trim();
+    tab_rows(ABBREVIATION_TAB)
+        .filter(|fields| fields[0].eq_ignore_ascii_case(abbreviation))
+        .filter_map(|fields| fields.get(1)?.parse::<Tz>().ok())
+        .filter(|tz| hint.is_none_or(|region| region.contains(*tz)))
+        .collect()
+}
+
+/// Finds the tz database identifier an abbreviation is spelled like, such as `EST` for "est"
+/// 
+/// Converters read such abbreviations as the identifier whatever their case, so "EST" and
+/// "est" name the same zone.
+pub(crate) fn abbreviation_identifier(abbreviation: &str) -> Option<Tz> {
+    let abbreviation = abbreviation.trim();
+    tab_rows(ABBREVIATION_TAB)
+        .find(|fields| fields[0].eq_ignore_ascii_case(abbreviation))
+        .and_then(|fields| fields[0].parse().ok())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Duration, TimeZone, Utc};
+    use chrono_tz::OffsetName;
+
+    #[test]
+    fn resolves_abbreviations_by_region() {
+        assert_eq!(resolve_abbreviation("IST", None), [Tz::Asia__Kolkata, Tz::Europe__Dublin, Tz::Asia__Jerusalem]);
+        assert_eq!(resolve_abbreviation(" ist ", Some(Region::Europe)), [Tz::Europe__Dublin]);
+        assert_eq!(resolve_abbreviation("EST", Some(Region::America)), [Tz::America__New_York]);
+        assert!(resolve_abbreviation("EST", Some(Region::Asia)).is_empty());
+        assert!(resolve_abbreviation("XYZ", None).is_empty());
+    }
+
+    #[test]
+    fn every_abbreviation_is_used_by_its_zone() {
+        let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        for fields in tab_rows(ABBREVIATION_TAB) {
+            let tz: Tz = fields[1].parse().unwrap();
+            let used = (0..366)
+                .map(|day| tz.offset_from_utc_datetime(&(start + Duration::days(day)).naive_utc()))
+                .any(|offset| offset.abbreviation() == Some(fields[0]));
+            assert!(used, "{} does not use {}", tz, fields[0]);
+        }
+    }
+}
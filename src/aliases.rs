@@ -0,0 +1,313 @@
+//! Resolution of deprecated and alternate timezone names to their canonical zones
+
+use chrono::Offset;
+use chrono_tz::{Africa, America, Asia, Atlantic, Australia, Etc, Europe, Pacific, Tz};
+
+use crate::zone_spec::parse_offset;
+use crate::{parse_tz, Errors};
+
+/// Legacy names from the tz database's `backward` file and the zones they link to, sorted by name
+/// 
+/// These are the pre-1993 names (such as `US/Eastern`), the two-part names renamed in 1995
+/// and the alternate spellings (such as `Asia/Calcutta`). Links that merely merge one
+/// country's zone into another's with identical data are left out, since names like
+/// `Europe/Amsterdam` are still the right ones to show users, and so is `UTC`.
+const LEGACY_NAMES: &[(&str, Tz)] = &[
+    ("Africa/Asmera", Africa::Nairobi),
+    ("America/Buenos_Aires", America::Argentina::Buenos_Aires),
+    ("America/Catamarca", America::Argentina::Catamarca),
+    ("America/Cordoba", America::Argentina::Cordoba),
+    ("America/Godthab", America::Nuuk),
+    ("America/Indianapolis", America::Indiana::Indianapolis),
+    ("America/Jujuy", America::Argentina::Jujuy),
+    ("America/Knox_IN", America::Indiana::Knox),
+    ("America/Louisville", America::Kentucky::Louisville),
+    ("America/Mendoza", America::Argentina::Mendoza),
+    ("America/Virgin", America::St_Thomas),
+    ("Asia/Ashkhabad", Asia::Ashgabat),
+    ("Asia/Calcutta", Asia::Kolkata),
+    ("Asia/Chungking", Asia::Shanghai),
+    ("Asia/Dacca", Asia::Dhaka),
+    ("Asia/Istanbul", Europe::Istanbul),
+    ("Asia/Katmandu", Asia::Kathmandu),
+    ("Asia/Macao", Asia::Macau),
+    ("Asia/Rangoon", Asia::Yangon),
+    ("Asia/Saigon", Asia::Ho_Chi_Minh),
+    ("Asia/Thimbu", Asia::Thimphu),
+    ("Asia/Ujung_Pandang", Asia::Makassar),
+    ("Asia/Ulan_Bator", Asia::Ulaanbaatar),
+    ("Atlantic/Faeroe", Atlantic::Faroe),
+    ("Australia/ACT", Australia::Sydney),
+    ("Australia/LHI", Australia::Lord_Howe),
+    ("Australia/NSW", Australia::Sydney),
+    ("Australia/North", Australia::Darwin),
+    ("Australia/Queensland", Australia::Brisbane),
+    ("Australia/South", Australia::Adelaide),
+    ("Australia/Tasmania", Australia::Hobart),
+    ("Australia/Victoria", Australia::Melbourne),
+    ("Australia/West", Australia::Perth),
+    ("Australia/Yancowinna", Australia::Broken_Hill),
+    ("Brazil/Acre", America::Rio_Branco),
+    ("Brazil/DeNoronha", America::Noronha),
+    ("Brazil/East", America::Sao_Paulo),
+    ("Brazil/West", America::Manaus),
+    ("CET", Europe::Brussels),
+    ("CST6CDT", America::Chicago),
+    ("Canada/Atlantic", America::Halifax),
+    ("Canada/Central", America::Winnipeg),
+    ("Canada/Eastern", America::Toronto),
+    ("Canada/Mountain", America::Edmonton),
+    ("Canada/Newfoundland", America::St_Johns),
+    ("Canada/Pacific", America::Vancouver),
+    ("Canada/Saskatchewan", America::Regina),
+    ("Canada/Yukon", America::Whitehorse),
+    ("Chile/Continental", America::Santiago),
+    ("Chile/EasterIsland", Pacific::Easter),
+    ("Cuba", America::Havana),
+    ("EET", Europe::Athens),
+    ("EST", America::Panama),
+    ("EST5EDT", America::New_York),
+    ("Egypt", Africa::Cairo),
+    ("Eire", Europe::Dublin),
+    ("Etc/GMT+0", Etc::GMT),
+    ("Etc/GMT-0", Etc::GMT),
+    ("Etc/GMT0", Etc::GMT),
+    ("Etc/Greenwich", Etc::GMT),
+    ("Etc/UCT", Etc::UTC),
+    ("Etc/Universal", Etc::UTC),
+    ("Etc/Zulu", Etc::UTC),
+    ("Europe/Kiev", Europe::Kyiv),
+    ("Europe/Nicosia", Asia::Nicosia),
+    ("GB", Europe::London),
+    ("GB-Eire", Europe::London),
+    ("GMT+0", Etc::GMT),
+    ("GMT-0", Etc::GMT),
+    ("GMT0", Etc::GMT),
+    ("Greenwich", Etc::GMT),
+    ("HST", Pacific::Honolulu),
+    ("Hongkong", Asia::Hong_Kong),
+    ("Iceland", Atlantic::Reykjavik),
+    ("Iran", Asia::Tehran),
+    ("Israel", Asia::Jerusalem),
+    ("Jamaica", America::Jamaica),
+    ("Japan", Asia::Tokyo),
+    ("Kwajalein", Pacific::Kwajalein),
+    ("Libya", Africa::Tripoli),
+    ("MET", Europe::Brussels),
+    ("MST", America::Phoenix),
+    ("MST7MDT", America::Denver),
+    ("Mexico/BajaNorte", America::Tijuana),
+    ("Mexico/BajaSur", America::Mazatlan),
+    ("Mexico/General", America::Mexico_City),
+    ("NZ", Pacific::Auckland),
+    ("NZ-CHAT", Pacific::Chatham),
+    ("Navajo", America::Denver),
+    ("PRC", Asia::Shanghai),
+    ("PST8PDT", America::Los_Angeles),
+    ("Pacific/Ponape", Pacific::Pohnpei),
+    ("Pacific/Samoa", Pacific::Pago_Pago),
+    ("Pacific/Truk", Pacific::Chuuk),
+    ("Poland", Europe::Warsaw),
+    ("Portugal", Europe::Lisbon),
+    ("ROC", Asia::Taipei),
+    ("ROK", Asia::Seoul),
+    ("Singapore", Asia::Singapore),
+    ("Turkey", Europe::Istanbul),
+    ("UCT", Etc::UTC),
+    ("US/Alaska", America::Anchorage),
+    ("US/Aleutian", America::Adak),
+    ("US/Arizona", America::Phoenix),
+    ("US/Central", America::Chicago),
+    ("US/East-Indiana", America::Indiana::Indianapolis),
+    ("US/Eastern", America::New_York),
+    ("US/Hawaii", Pacific::Honolulu),
+    ("US/Indiana-Starke", America::Indiana::Knox),
+    ("US/Michigan", America::Detroit),
+    ("US/Mountain", America::Denver),
+    ("US/Pacific", America::Los_Angeles),
+    ("US/Samoa", Pacific::Pago_Pago),
+    ("Universal", Etc::UTC),
+    ("W-SU", Europe::Moscow),
+    ("Zulu", Etc::UTC),
+];
+
+
+/// Names from the tz database's `backward` file for places outside `zone.tab` whose clocks
+/// since 1970 match another zone's, sorted by name
+/// 
+/// They are links to zones in other places, so [`LEGACY_NAMES`] does not resolve them, but
+/// like legacy names they are left out of zone listings.
+const DUPLICATE_LOCATIONS: &[&str] = &[
+    "Africa/Timbuktu",
+    "America/Argentina/ComodRivadavia",
+    "America/Atka",
+    "America/Coral_Harbour",
+    "America/Ensenada",
+    "America/Fort_Wayne",
+    "America/Montreal",
+    "America/Nipigon",
+    "America/Pangnirtung",
+    "America/Porto_Acre",
+    "America/Rainy_River",
+    "America/Rosario",
+    "America/Santa_Isabel",
+    "America/Shiprock",
+    "America/Thunder_Bay",
+    "America/Yellowknife",
+    "Antarctica/South_Pole",
+    "Asia/Choibalsan",
+    "Asia/Chongqing",
+    "Asia/Harbin",
+    "Asia/Kashgar",
+    "Asia/Tel_Aviv",
+    "Atlantic/Jan_Mayen",
+    "Australia/Canberra",
+    "Australia/Currie",
+    "Europe/Belfast",
+    "Europe/Tiraspol",
+    "Europe/Uzhgorod",
+    "Europe/Zaporozhye",
+    "Pacific/Enderbury",
+    "Pacific/Johnston",
+    "Pacific/Yap",
+    "WET",
+];
+
+/// Whether a zone is listed under another name: a legacy name or one of [`DUPLICATE_LOCATIONS`]
+pub(crate) fn is_backward_link(tz: Tz) -> bool {
+    canonical_zone(tz).1 || DUPLICATE_LOCATIONS.binary_search(&tz.name()).is_ok()
+}
+
+/// Resolves a timezone identifier to its canonical zone, reporting whether it was a legacy alias
+/// 
+/// Deprecated names such as `US/Eastern` or `Asia/Calcutta` resolve to the zones they link
+/// to, as do whole-hour offset names such as `GMT+5`, which resolve to the matching `Etc/`
+/// zone (whose POSIX-style name has the opposite sign). Canonical names are returned as is.
+/// 
+/// # Arguments
+/// 
+/// * `name` - The timezone identifier (e.g., "US/Eastern")
+/// 
+/// # Returns
+/// 
+/// * `Result<(Tz, bool), Errors>` - The canonical zone and whether `name` was an alias, or `Errors::InvalidTimeZone` if it names no zone
+/// 
+/// # Example
+/// 
+/// ```rust
+/// use chrono_tz::Tz;
+/// use timezone_converter::canonicalize;
+/// 
+/// assert_eq!(canonicalize("Asia/Calcutta").unwrap(), (Tz::Asia__Kolkata, true));
+/// assert_eq!(canonicalize("GMT+5").unwrap(), (Tz::Etc__GMTMinus5, true));
+/// assert_eq!(canonicalize("Europe/Paris").unwrap(), (Tz::Europe__Paris, false));
+/// ```
+pub fn canonicalize(name: &str) -> Result<(Tz, bool), Errors> {
+    if let Ok(tz) = name.parse::<Tz>() {
+        return Ok(canonical_zone(tz));
+    }
+    if let Some(tz) = offset_name_zone(name) {
+        return Ok((tz, true));
+    }
+    if is_offset_name(name) {
+        return Err(invalid(name));
+    }
+    parse_tz(name).map(|tz| (tz, false))
+}
+
+/// Resolves a whole-hour offset name such as `GMT+5` or `UTC-7` to the matching `Etc/` zone
+/// 
+/// Offsets without a `GMT` or `UTC` prefix, and ones with minutes, are not names of any
+/// zone, so they give `None`.
+pub(crate) fn offset_name_zone(name: &str) -> Option<Tz> {
+    if !is_offset_name(name) {
+        return None;
+    }
+    match parse_offset(name.trim())?.fix().local_minus_utc() {
+        0 => Some(Tz::UTC),
+        // `Etc/GMT-5` is five hours ahead of UTC
+        seconds if seconds % 3600 == 0 => format!("Etc/GMT{:+}", -seconds / 3600).parse().ok(),
+        _ => None,
+    }
+}
+
+/// Whether a string is an offset written after a `GMT` or `UTC` prefix
+fn is_offset_name(name: &str) -> bool {
+    let name = name.trim();
+    let prefixed = name.get(..3).is_some_and(|prefix| prefix.eq_ignore_ascii_case("GMT") || prefix.eq_ignore_ascii_case("UTC"));
+    prefixed && parse_offset(name).is_some()
+}
+
+/// Resolves an already-parsed zone to its canonical zone, reporting whether it was a legacy alias
+pub(crate) fn canonical_zone(tz: Tz) -> (Tz, bool) {
+    match LEGACY_NAMES.binary_search_by(|(name, _)| name.cmp(&tz.name())) {
+        Ok(index) => (LEGACY_NAMES[index].1, true),
+        Err(_) => (tz, false),
+    }
+}
+
+/// The error for an offset name with no matching `Etc/` zone
+fn invalid(name: &str) -> Errors {
+    Errors::InvalidTimeZone {
+        input: name.to_string(),
+        suggestions: Vec::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resolves_legacy_names() {
+        assert_eq!(canonicalize("US/Eastern").unwrap(), (Tz::America__New_York, true));
+        assert_eq!(canonicalize("Europe/Kiev").unwrap(), (Tz::Europe__Kyiv, true));
+        assert_eq!(canonicalize("America/Buenos_Aires").unwrap(), (Tz::America__Argentina__Buenos_Aires, true));
+        assert_eq!(canonicalize("UTC-7").unwrap(), (Tz::Etc__GMTPlus7, true));
+        assert_eq!(canonicalize("UTC").unwrap(), (Tz::UTC, false));
+        assert_eq!(canonicalize("Europe/Amsterdam").unwrap(), (Tz::Europe__Amsterdam, false));
+        assert!(matches!(canonicalize("GMT+5:30"), Err(Errors::InvalidTimeZone { .. })));
+        assert!(matches!(canonicalize("UTC+15"), Err(Errors::InvalidTimeZone { .. })));
+        assert!(matches!(canonicalize("+05:00"), Err(Errors::InvalidTimeZone { .. })));
+    }
+
+    #[test]
+    fn duplicate_locations_are_sorted_links() {
+        assert!(DUPLICATE_LOCATIONS.windows(2).all(|pair| pair[0] < pair[1]));
+        for name in DUPLICATE_LOCATIONS {
+            let tz: Tz = name.parse().unwrap();
+            assert!(is_backward_link(tz), "{}", name);
+            assert!(!canonical_zone(tz).1, "{} is already a legacy name", name);
+        }
+        assert!(is_backward_link(Tz::US__Eastern));
+        assert!(!is_backward_link(Tz::Europe__Amsterdam));
+    }
+
+    #[test]
+    fn legacy_names_stay_within_their_country() {
+        assert_eq!(canonicalize("Iceland").unwrap(), (Tz::Atlantic__Reykjavik, true));
+        assert_eq!(canonicalize("Pacific/Ponape").unwrap(), (Tz::Pacific__Pohnpei, true));
+        assert_eq!(canonicalize("Pacific/Truk").unwrap(), (Tz::Pacific__Chuuk, true));
+        assert_eq!(canonicalize("America/Virgin").unwrap(), (Tz::America__St_Thomas, true));
+    }
+
+    #[test]
+    fn offset_names_resolve_like_converters_do() {
+        for name in ["GMT+5", "utc-7", "GMT+0", "UTC+5:30", "+05:00"] {
+            let converter = crate::TimeZoneConverter::new(name, "UTC").unwrap();
+            match canonicalize(name) {
+                Ok((tz, _)) => assert_eq!(*converter.zone(crate::Zone::Source), crate::ZoneSpec::Named(tz), "{}", name),
+                Err(_) => assert!(matches!(converter.zone(crate::Zone::Source), crate::ZoneSpec::Fixed(_)), "{}", name),
+            }
+        }
+    }
+
+    #[test]
+    fn legacy_names_are_sorted_and_point_at_canonical_zones() {
+        assert!(LEGACY_NAMES.windows(2).all(|pair| pair[0].0 < pair[1].0));
+        for &(name, target) in LEGACY_NAMES {
+            assert!(name.parse::<Tz>().is_ok(), "{}", name);
+            assert_eq!(canonical_zone(target), (target, false), "{}", name);
+        }
+    }
+}
@@ -0,0 +1,199 @@
+//! Streaming conversion of a timestamp column in CSV data
+
+use std::io::{BufRead, BufReader, Read, Write};
+
+use chrono::{DateTime, NaiveDateTime};
+
+use crate::{resolve_local, validate_format, Errors, TimeZoneConverter, ZoneSpec};
+
+impl TimeZoneConverter {
+    /// Streams CSV data, converting one timestamp column from the source timezone to the target timezone
+    /// 
+    /// The first record is the header, which names the column to convert. Records are read
+    /// and written one at a time, so the input is never held in memory. Quoted fields,
+    /// including ones with embedded commas, quotes or line breaks, are supported, and the
+    /// output keeps the input's line endings. Empty cells in the column are left empty.
+    /// 
+    /// Cells are parsed with `input_format` as source-local wall-clock times, resolving
+    /// skipped and repeated times with the converter's [`crate::AmbiguityPolicy`]. A format
+    /// with an offset (`%z`) is parsed as an exact instant instead.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `reader` - The CSV input
+    /// * `writer` - Where to write the converted CSV
+    /// * `column` - The header name of the timestamp column
+    /// * `input_format` - The `chrono` format of the cells (e.g., "%Y-%m-%d %H:%M:%S")
+    /// * `output_format` - The `chrono` format to write the converted cells in
+    /// 
+    /// # Returns
+    /// 
+    /// * `Result<usize, Errors>` - The number of data records written, or an error naming the failing line
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use timezone_converter::TimeZoneConverter;
+    /// 
+    /// let converter = TimeZoneConverter::new("America/New_York", "UTC").unwrap();
+    /// let input = "id,created_at\n1,2024-07-01 08:00\n2,\n";
+    /// let mut output = Vec::new();
+    /// let rows = converter.convert_csv(input.as_bytes(), &mut output, "created_at", "%Y-%m-%d %H:%M", "%Y-%m-%dT%H:%M:%SZ").unwrap();
+    /// assert_eq!(rows, 2);
+    /// assert_eq!(String::from_utf8(output).unwrap(), "id,created_at\n1,2024-07-01T12:00:00Z\n2,\n");
+    /// ```
+    pub fn convert_csv<R: Read, W: Write>(&self, reader: R, mut writer: W, column: &str, input_format: &str, output_format: &str) -> Result<usize, Errors> {
+        validate_format(input_format)?;
+        validate_format(output_format)?;
+        let mut reader = BufReader::new(reader);
+        let mut buffer = String::new();
+        let mut line = 1;
+
+        let Some(header) = read_record(&mut reader, &mut buffer, &mut line)? else {
+            return Err(Errors::ParseError("CSV input is empty".to_string()));
+        };
+        let index = header.fields
+            .iter()
+            .position(|name| name == column)
+            .ok_or_else(|| Errors::ParseError(format!("CSV header has no column '{}'", column)))?;
+        write_record(&mut writer, &header)?;
+
+        let mut rows = 0;
+        loop {
+            let record_line = line;
+            let Some(mut record) = read_record(&mut reader, &mut buffer, &mut line)? else {
+                break;
+            };
+            if let Some(cell) = record.fields.get_mut(index).filter(|cell| !cell.is_empty()) {
+                let converted = self.convert_cell(cell, input_format)
+                    .map_err(|err| Errors::ParseError(format!("CSV line {}: {}", record_line, err)))?;
+                *cell = converted.format(output_format).to_string();
+            }
+            write_record(&mut writer, &record)?;
+            rows += 1;
+        }
+        writer.flush().map_err(io_error)?;
+        Ok(rows)
+    }
+
+    /// Parses one timestamp cell as a source-local time, or as an instant if the format has an offset
+    fn convert_cell(&self, cell: &str, input_format: &str) -> Result<DateTime<ZoneSpec>, Errors> {
+        // A naive parse would accept `%z` but ignore the offset, so try an exact instant first
+        if let Ok(instant) = DateTime::parse_from_str(cell, input_format) {
+            return Ok(instant.with_timezone(&self.target_tz));
+        }
+        let naive = NaiveDateTime::parse_from_str(cell, input_format)
+            .map_err(|err| Errors::ParseError(format!("'{}' does not match '{}': {}", cell, input_format, err)))?;
+        Ok(resolve_local(&self.source_tz, naive, self.ambiguity_policy)?.with_timezone(&self.target_tz))
+    }
+}
+
+/// One CSV record and the line ending it was terminated with
+struct Record {
+    /// The unquoted field values
+    fields: Vec<String>,
+    /// The record's line ending, empty for a final record without one
+    terminator: &'static str,
+}
+
+/// Reads the next record, which may span several lines if a quoted field contains line breaks
+/// 
+/// `line` is the current line number and is advanced past the record.
+fn read_record<R: BufRead>(reader: &mut R, buffer: &mut String, line: &mut usize) -> Result<Option<Record>, Errors> {
+    buffer.clear();
+    if reader.read_line(buffer).map_err(io_error)? == 0 {
+        return Ok(None);
+    }
+    let start = *line;
+    *line += 1;
+    // An odd number of quotes means a quoted field continues onto the next line
+    while buffer.matches('"').count() % 2 == 1 {
+        if reader.read_line(buffer).map_err(io_error)? == 0 {
+            return Err(Errors::ParseError(format!("CSV line {}: unterminated quoted field", start)));
+        }
+        *line += 1;
+    }
+
+    let (text, terminator) = if let Some(text) = buffer.strip_suffix("\r\n") {
+        (text, "\r\n")
+    } else if let Some(text) = buffer.strip_suffix('\n') {
+        (text, "\n")
+    } else {
+        (buffer.as_str(), "")
+    };
+
+    let mut fields = Vec::new();
+    let mut field = String::new();
+    let mut quoted = false;
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if quoted && chars.peek() == Some(&'"') => {
+                field.push('"');
+                chars.next();
+            }
+            '"' if quoted => quoted = false,
+            '"' if field.is_empty() => quoted = true,
+            ',' if !quoted => fields.push(std::mem::take(&mut field)),
+            c => field.push(c),
+        }
+    }
+    fields.push(field);
+    Ok(Some(Record { fields, terminator }))
+}
+
+/// Writes a record, quoting fields that need it
+fn write_record<W: Write>(writer: &mut W, record: &Record) -> Result<(), Errors> {
+    let mut text = String::new();
+    for (i, field) in record.fields.iter().enumerate() {
+        if i > 0 {
+            text.push(',');
+        }
+        if field.contains([',', '"', '\n', '\r']) {
+            text.push('"');
+            text.push_str(&field.replace('"', "\"\""));
+            text.push('"');
+        } else {
+            text.push_str(field);
+        }
+    }
+    text.push_str(record.terminator);
+    writer.write_all(text.as_bytes()).map_err(io_error)
+}
+
+/// Wraps an I/O failure while streaming
+fn io_error(err: std::io::Error) -> Errors {
+    Errors::ConversionError(format!("CSV I/O error: {}", err))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn convert(source: &str, input: &str, input_format: &str) -> Result<String, Errors> {
+        let timezone = TimeZoneConverter::new(source, "Asia/Tokyo").unwrap();
+        let mut output = Vec::new();
+        timezone.convert_csv(input.as_bytes(), &mut output, "at", input_format, "%Y-%m-%d %H:%M %Z")?;
+        Ok(String::from_utf8(output).unwrap())
+    }
+
+    #[test]
+    fn keeps_quoting_and_line_endings() {
+        let input = "note,at\r\n\"multi\r\nline, \"\"quoted\"\"\",2024-01-15 00:30\r\nplain,2024-07-01 12:00";
+        assert_eq!(
+            convert("UTC", input, "%Y-%m-%d %H:%M").unwrap(),
+            "note,at\r\n\"multi\r\nline, \"\"quoted\"\"\",2024-01-15 09:30 JST\r\nplain,2024-07-01 21:00 JST"
+        );
+    }
+
+    #[test]
+    fn parses_offsets_and_reports_bad_rows() {
+        let input = "at\n2024-07-01 12:00 +0200\n";
+        assert_eq!(convert("America/New_York", input, "%Y-%m-%d %H:%M %z").unwrap(), "at\n2024-07-01 19:00 JST\n");
+
+        let err = convert("UTC", "at\n2024-07-01 12:00\nlater\n", "%Y-%m-%d %H:%M").unwrap_err();
+        assert!(err.to_string().contains("line 3"), "{}", err);
+        assert!(matches!(convert("UTC", "when\n", "%Y"), Err(Errors::ParseError(_))));
+        assert!(matches!(convert("UTC", "at\n\"open", "%Y"), Err(Errors::ParseError(_))));
+    }
+}
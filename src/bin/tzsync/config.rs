@@ -0,0 +1,122 @@
+//! The `tzsync.toml` configuration file listing the zones shown by `tzsync watch`
+
+use std::path::{Path, PathBuf};
+
+use timezone_converter::{Errors, WorldClock};
+
+/// Gets the default configuration path, `$XDG_CONFIG_HOME/tzsync.toml` or `~/.config/tzsync.toml`
+pub fn default_path() -> Option<PathBuf> {
+    std::env::var_os("XDG_CONFIG_HOME")
+        .filter(|dir| !dir.is_empty())
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))
+        .map(|dir| dir.join("tzsync.toml"))
+}
+
+/// Reads a configuration file, or the one at [`default_path`] if `path` is `None`
+pub fn load(path: Option<&Path>) -> Result<WorldClock, Errors> {
+    let path = match path {
+        Some(path) => path.to_path_buf(),
+        None => default_path().ok_or_else(|| Errors::ParseError("cannot locate ~/.config/tzsync.toml; pass --config".to_string()))?,
+    };
+    let text = std::fs::read_to_string(&path)
+        .map_err(|err| Errors::ParseError(format!("cannot read {}: {}", path.display(), err)))?;
+    parse(&text)
+}
+
+/// Parses a configuration file into a world clock
+/// 
+/// The file lists zones as an array of tables, in display order:
+/// 
+/// ```toml
+/// [[zones]]
+/// label = "London office"
+/// zone = "Europe/London"
+/// 
+/// [[zones]]
+/// zone = "Asia/Tokyo"   # the label defaults to the zone name
+/// ```
+/// 
+/// Only this subset of TOML is understood: `[[zones]]` headers, `#` comments and
+/// `key = "string"` pairs.
+pub fn parse(text: &str) -> Result<WorldClock, Errors> {
+    let mut entries: Vec<(Option<String>, Option<String>)> = Vec::new();
+    for (index, line) in text.lines().enumerate() {
+        let line = strip_comment(line).trim();
+        if line.is_empty() {
+            continue;
+        }
+        let error = |reason: &str| Errors::ParseError(format!("tzsync.toml line {}: {}", index + 1, reason));
+        if line == "[[zones]]" {
+            entries.push((None, None));
+            continue;
+        }
+        let Some((key, value)) = line.split_once('=') else {
+            return Err(error("expected `[[zones]]` or `key = \"value\"`"));
+        };
+        let value = value.trim();
+        let value = value
+            .strip_prefix('"')
+            .and_then(|value| value.strip_suffix('"'))
+            .ok_or_else(|| error("values must be double-quoted strings"))?
+            .to_string();
+        let Some(entry) = entries.last_mut() else {
+            return Err(error("keys must follow a `[[zones]]` header"));
+        };
+        match key.trim() {
+            "label" => entry.0 = Some(value),
+            "zone" => entry.1 = Some(value),
+            other => return Err(error(&format!("unknown key '{}'", other))),
+        }
+    }
+
+    let mut clock = WorldClock::new();
+    for (label, zone) in entries {
+        let zone = zone.ok_or_else(|| Errors::ParseError("tzsync.toml: every [[zones]] entry needs a zone".to_string()))?;
+        clock.add(label.as_deref().unwrap_or(&zone), &zone)?;
+    }
+    Ok(clock)
+}
+
+/// Removes a `#` comment, leaving any `#` inside a quoted string
+fn strip_comment(line: &str) -> &str {
+    let mut quoted = false;
+    for (index, c) in line.char_indices() {
+        match c {
+            '"' => quoted = !quoted,
+            '#' if !quoted => return &line[..index],
+            _ => {}
+        }
+    }
+    line
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{TimeZone, Utc};
+
+    #[test]
+    fn parses_zones_in_order() {
+        let clock = parse(r#"
+            # Offices
+            [[zones]]
+            label = "London # HQ"
+            zone = "Europe/London"
+
+            [[zones]]
+            zone = "Asia/Tokyo"  # no label
+        "#).unwrap();
+        let readings = clock.at(Utc.with_ymd_and_hms(2024, 7, 1, 12, 0, 0).unwrap());
+        let labels: Vec<&str> = readings.iter().map(|reading| reading.label.as_str()).collect();
+        assert_eq!(labels, ["London # HQ", "Asia/Tokyo"]);
+    }
+
+    #[test]
+    fn rejects_invalid_files() {
+        for invalid in ["zone = \"UTC\"", "[[zones]]\nzone = UTC", "[[zones]]\nlabel = \"x\"", "[[zones]]\ncolour = \"red\"", "[zones]"] {
+            assert!(matches!(parse(invalid), Err(Errors::ParseError(_))), "{}", invalid);
+        }
+        assert!(matches!(parse("[[zones]]\nzone = \"Mars/Olympus\""), Err(Errors::InvalidTimeZone { .. })));
+    }
+}
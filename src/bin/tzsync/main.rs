@@ -0,0 +1,354 @@
+//! The `tzsync` command-line tool for converting times and inspecting timezones
+
+mod config;
+
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::sync::Arc;
+
+use chrono::{DateTime, NaiveDateTime, SecondsFormat, Utc};
+use chrono_tz::Tz;
+use serde_json::json;
+use timezone_converter::{tzdb_version, Clock, Errors, SystemClock, TimeZoneConverter, WorldClock};
+
+const USAGE: &str = "\
+Usage: tzsync [--json] <command>
+
+Commands:
+  convert <datetime> [--from <zone>] [--to <zone>]   Convert a wall-clock time (zones default to UTC)
+  now [--in <zone>]                                  Show the current time (zone defaults to UTC)
+  diff <zone1> <zone2>                               Show how far zone1 is ahead of zone2
+  info <zone>                                        Show a zone's current offset and DST status
+  watch [--config <file>]                            Show a live world clock of the zones in
+                                                     ~/.config/tzsync.toml
+
+Options:
+  --json    Print machine-readable JSON instead of plain text
+  --help    Print this message";
+
+/// The wall-clock formats accepted by `convert`, tried in order
+const INPUT_FORMATS: [&str; 4] = ["%Y-%m-%d %H:%M", "%Y-%m-%d %H:%M:%S", "%Y-%m-%dT%H:%M", "%Y-%m-%dT%H:%M:%S"];
+
+/// A parsed subcommand
+#[derive(Debug, Clone, PartialEq)]
+enum Command {
+    /// Convert a wall-clock time from one zone to another
+    Convert { input: String, from: String, to: String },
+    /// Show the current time in a zone
+    Now { zone: String },
+    /// Show the offset difference between two zones
+    Diff { first: String, second: String },
+    /// Show a zone's current offset and DST status
+    Info { zone: String },
+    /// Show a live world clock of the configured zones
+    Watch { config: Option<PathBuf> },
+    /// Print the usage message
+    Help,
+}
+
+/// The parsed command line
+#[derive(Debug, Clone, PartialEq)]
+struct Options {
+    /// The subcommand to run
+    command: Command,
+    /// Whether to print JSON instead of plain text
+    json: bool,
+}
+
+fn main() -> ExitCode {
+    let options = match parse_args(std::env::args().skip(1)) {
+        Ok(options) => options,
+        Err(message) => {
+            eprintln!("tzsync: {}\n\n{}", message, USAGE);
+            return ExitCode::from(2);
+        }
+    };
+    if let Command::Watch { config } = &options.command {
+        let Err(err) = watch(config.as_deref(), options.json);
+        eprintln!("tzsync: {}", err);
+        return ExitCode::FAILURE;
+    }
+    match run(&options, Arc::new(SystemClock)) {
+        Ok(output) => {
+            println!("{}", output);
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            eprintln!("tzsync: {}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Parses the arguments after the program name
+fn parse_args(args: impl IntoIterator<Item = String>) -> Result<Options, String> {
+    let mut json = false;
+    let mut positional = Vec::new();
+    let mut flags: Vec<(String, String)> = Vec::new();
+
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--json" => json = true,
+            "-h" | "--help" => return Ok(Options { command: Command::Help, json }),
+            "--from" | "--to" | "--in" | "--config" => {
+                let value = args.next().ok_or_else(|| format!("{} needs a value", arg))?;
+                flags.push((arg, value));
+            }
+            flag if flag.starts_with('-') && flag.len() > 1 => return Err(format!("unknown option '{}'", flag)),
+            _ => positional.push(arg),
+        }
+    }
+
+    let mut take = |name: &str| -> Option<String> {
+        flags.iter()
+            .position(|(flag, _)| flag == name)
+            .map(|index| flags.remove(index).1)
+    };
+    let mut zone = |name: &str| take(name).unwrap_or_else(|| "UTC".to_string());
+    let command = match positional.iter().map(String::as_str).collect::<Vec<_>>()[..] {
+        ["convert", input] => Command::Convert { input: input.to_string(), from: zone("--from"), to: zone("--to") },
+        ["now"] => Command::Now { zone: zone("--in") },
+        ["diff", first, second] => Command::Diff { first: first.to_string(), second: second.to_string() },
+        ["info", zone] => Command::Info { zone: zone.to_string() },
+        ["watch"] => Command::Watch { config: take("--config").map(PathBuf::from) },
+        ["help"] => Command::Help,
+        [] => return Err("missing command".to_string()),
+        [command, ..] if !["convert", "now", "diff", "info", "watch"].contains(&command) => {
+            return Err(format!("unknown command '{}'", command));
+        }
+        [command, ..] => return Err(format!("wrong number of arguments for '{}'", command)),
+    };
+    if let Some((name, _)) = flags.first() {
+        return Err(format!("{} is not accepted here", name));
+    }
+    Ok(Options { command, json })
+}
+
+/// Runs a command and returns what it prints
+fn run(options: &Options, clock: Arc<dyn Clock>) -> Result<String, Errors> {
+    match &options.command {
+        Command::Convert { input, from, to } => {
+            let converter = TimeZoneConverter::new(from, to)?.with_clock(clock);
+            let naive = INPUT_FORMATS
+                .iter()
+                .find_map(|format| NaiveDateTime::parse_from_str(input, format).ok())
+                .ok_or_else(|| Errors::ParseError(format!("'{}' is not a date and time like \"2024-11-04 10:00\"", input)))?;
+            let result = converter.convert_checked(naive)?;
+            let source = converter.convert_back(result.datetime)?;
+
+            if options.json {
+                return Ok(json!({
+                    "from": zone_name(&source),
+                    "to": zone_name(&result.datetime),
+                    "source": rfc3339(&source),
+                    "target": rfc3339(&result.datetime),
+                    "crossed_dst_in_source": result.crossed_dst_in_source,
+                    "crossed_dst_in_target": result.crossed_dst_in_target,
+                    "date_changed": result.date_changed,
+                    "tzdb_version": result.tzdb_version,
+                }).to_string());
+            }
+            let mut output = format!("{} -> {}", describe(&source), describe(&result.datetime));
+            if result.crossed_dst_in_source {
+                output.push_str(&format!("\nnote: {} does not occur exactly once in {}", input, zone_name(&source)));
+            }
+            if result.crossed_dst_in_target {
+                output.push_str(&format!("\nnote: the result occurs twice in {}", zone_name(&result.datetime)));
+            }
+            Ok(output)
+        }
+        Command::Now { zone } => {
+            let now = TimeZoneConverter::single(zone)?.with_clock(clock).get_current_time_target()?;
+            if options.json {
+                return Ok(json!({ "zone": zone_name(&now), "time": rfc3339(&now), "tzdb_version": tzdb_version() }).to_string());
+            }
+            Ok(describe(&now))
+        }
+        Command::Diff { first, second } => {
+            let converter = TimeZoneConverter::new(first, second)?.with_clock(clock);
+            let difference = converter.time_difference();
+            let label = converter.difference_short_string()?;
+            if options.json {
+                return Ok(json!({
+                    "from": first,
+                    "to": second,
+                    "difference_seconds": difference.num_seconds(),
+                    "label": label,
+                    "tzdb_version": tzdb_version(),
+                }).to_string());
+            }
+            Ok(format!("{} is {} from {}", first, label, second))
+        }
+        Command::Info { zone } => {
+            let converter = TimeZoneConverter::single(zone)?.with_clock(clock);
+            let info = converter.get_timezone_info()?;
+            if options.json {
+                return info.to_json();
+            }
+            let now = converter.get_current_time_source()?;
+            Ok(format!(
+                "{}\n  abbreviation: {}\n  offset: UTC{}\n  dst: {}",
+                info.name(),
+                now.format("%Z"),
+                now.format("%:z"),
+                if info.is_dst() { "yes" } else { "no" },
+            ))
+        }
+        Command::Watch { config } => Ok(render_clock(&config::load(config.as_deref())?, clock.now(), options.json)),
+        Command::Help => Ok(USAGE.to_string()),
+    }
+}
+
+/// Redraws the configured world clock at the start of every second until the process is interrupted
+/// 
+/// With `json`, one line of JSON is printed per second instead of redrawing the terminal.
+fn watch(config: Option<&Path>, json: bool) -> Result<std::convert::Infallible, Errors> {
+    let clock = config::load(config)?;
+    let mut stdout = std::io::stdout();
+    loop {
+        let now = Utc::now();
+        let frame = render_clock(&clock, now, json);
+        let written = if json {
+            writeln!(stdout, "{}", frame)
+        } else {
+            // Clear the screen and move the cursor home before drawing
+            writeln!(stdout, "\x1b[2J\x1b[H{}", frame)
+        };
+        written.and_then(|_| stdout.flush()).map_err(|err| Errors::ConversionError(err.to_string()))?;
+
+        let until_next_second = 1_000_000_000 - u64::from(now.timestamp_subsec_nanos().min(999_999_999));
+        std::thread::sleep(std::time::Duration::from_nanos(until_next_second));
+    }
+}
+
+/// Renders one frame of the world clock as an aligned table, or as a JSON array
+fn render_clock(clock: &WorldClock, now: DateTime<Utc>, json: bool) -> String {
+    let readings = clock.at(now);
+    if json {
+        let readings: Vec<_> = readings
+            .iter()
+            .map(|reading| json!({
+                "label": reading.label,
+                "zone": reading.zone.name(),
+                "time": rfc3339(&reading.time),
+                "offset_seconds": reading.offset.num_seconds(),
+            }))
+            .collect();
+        return serde_json::Value::from(readings).to_string();
+    }
+
+    let width = readings.iter().map(|reading| reading.label.chars().count()).max().unwrap_or(0);
+    readings
+        .iter()
+        .map(|reading| format!(
+            "{:<width$}  {}",
+            reading.label,
+            reading.time.format("%a %d %b %H:%M:%S  UTC%:z  %Z"),
+            width = width,
+        ))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Formats a datetime with its abbreviation and zone name, e.g. "2024-11-04 10:00:00 EST (America/New_York)"
+fn describe(dt: &DateTime<Tz>) -> String {
+    format!("{} ({})", dt.format("%Y-%m-%d %H:%M:%S %Z"), zone_name(dt))
+}
+
+/// Gets the zone name of a datetime
+fn zone_name(dt: &DateTime<Tz>) -> &'static str {
+    dt.timezone().name()
+}
+
+/// Formats a datetime as RFC 3339 without fractional seconds
+fn rfc3339(dt: &DateTime<Tz>) -> String {
+    dt.to_rfc3339_opts(SecondsFormat::Secs, false)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use timezone_converter::FixedClock;
+
+    fn args(line: &str) -> Result<Options, String> {
+        parse_args(line.split_whitespace().map(str::to_string))
+    }
+
+    fn output(line: &str) -> Result<String, Errors> {
+        let clock = FixedClock::new(Utc.with_ymd_and_hms(2024, 7, 1, 12, 0, 0).unwrap());
+        run(&args(line).unwrap(), Arc::new(clock))
+    }
+
+    #[test]
+    fn parses_commands() {
+        assert_eq!(args("--json now --in Asia/Tokyo").unwrap(), Options {
+            command: Command::Now { zone: "Asia/Tokyo".to_string() },
+            json: true,
+        });
+        assert_eq!(args("convert 2024-11-04T10:00 --to Europe/London").unwrap().command, Command::Convert {
+            input: "2024-11-04T10:00".to_string(),
+            from: "UTC".to_string(),
+            to: "Europe/London".to_string(),
+        });
+        assert_eq!(args("diff --help").unwrap().command, Command::Help);
+        assert_eq!(args("watch --config zones.toml").unwrap().command, Command::Watch {
+            config: Some(PathBuf::from("zones.toml")),
+        });
+
+        for invalid in ["", "frobnicate", "diff UTC", "now --from UTC", "now --in", "watch --in UTC", "info UTC --verbose"] {
+            assert!(args(invalid).is_err(), "{}", invalid);
+        }
+    }
+
+    #[test]
+    fn plain_text_output() {
+        assert_eq!(
+            output("convert 2024-11-04T10:00 --from America/New_York --to Europe/London").unwrap(),
+            "2024-11-04 10:00:00 EST (America/New_York) -> 2024-11-04 15:00:00 GMT (Europe/London)"
+        );
+        assert_eq!(output("now --in Asia/Tokyo").unwrap(), "2024-07-01 21:00:00 JST (Asia/Tokyo)");
+        assert_eq!(output("diff Asia/Kathmandu UTC").unwrap(), "Asia/Kathmandu is +5h45m from UTC");
+        assert_eq!(
+            output("info America/New_York").unwrap(),
+            "America/New_York\n  abbreviation: EDT\n  offset: UTC-04:00\n  dst: yes"
+        );
+        assert!(matches!(output("convert 2024-11-04T10:00 --from Mars/Olympus"), Err(Errors::InvalidTimeZone { .. })));
+        assert!(matches!(output("convert tomorrow"), Err(Errors::ParseError(_))));
+    }
+
+    #[test]
+    fn json_output() {
+        let convert: serde_json::Value = serde_json::from_str(
+            &output("--json convert 2024-03-10T02:30 --from America/New_York --to UTC").unwrap()
+        ).unwrap();
+        assert_eq!(convert["source"], "2024-03-10T03:30:00-04:00");
+        assert_eq!(convert["target"], "2024-03-10T07:30:00+00:00");
+        assert_eq!(convert["crossed_dst_in_source"], true);
+        assert_eq!(convert["tzdb_version"], tzdb_version());
+
+        assert_eq!(
+            output("--json diff Europe/Berlin UTC").unwrap(),
+            format!(r#"{{"difference_seconds":7200,"from":"Europe/Berlin","label":"+2h","to":"UTC","tzdb_version":"{}"}}"#, tzdb_version())
+        );
+        assert_eq!(
+            output("--json info Asia/Tokyo").unwrap(),
+            format!(r#"{{"name":"Asia/Tokyo","offset_seconds":32400,"is_dst":false,"dst_savings_seconds":0,"tzdb_version":"{}"}}"#, tzdb_version())
+        );
+    }
+
+    #[test]
+    fn world_clock_frames() {
+        let clock = config::parse("[[zones]]\nlabel = \"NYC\"\nzone = \"America/New_York\"\n[[zones]]\nzone = \"Asia/Kolkata\"").unwrap();
+        let now = Utc.with_ymd_and_hms(2024, 7, 1, 12, 0, 5).unwrap();
+        assert_eq!(render_clock(&clock, now, false), "\
+NYC           Mon 01 Jul 08:00:05  UTC-04:00  EDT
+Asia/Kolkata  Mon 01 Jul 17:30:05  UTC+05:30  IST");
+        assert_eq!(
+            render_clock(&clock, now, true),
+            r#"[{"label":"NYC","offset_seconds":-14400,"time":"2024-07-01T08:00:05-04:00","zone":"America/New_York"},{"label":"Asia/Kolkata","offset_seconds":19800,"time":"2024-07-01T17:30:05+05:30","zone":"Asia/Kolkata"}]"#
+        );
+    }
+}
@@ -0,0 +1,253 @@
+//! A builder for configuring a [`TimeZoneConverter`] beyond its two timezones
+
+use std::sync::Arc;
+
+use chrono::FixedOffset;
+use chrono_tz::Tz;
+
+use crate::{validate_format, AmbiguityPolicy, BundledTzdb, Clock, CustomZone, Errors, PosixTz, SystemClock, TimeZoneConverter, ZoneProvider, ZoneSpec};
+
+/// A value that names a timezone: an identifier string, a literal offset such as `"+05:30"`,
+/// an already-parsed `Tz`, a `FixedOffset`, a [`PosixTz`], a [`CustomZone`] or a [`ZoneSpec`]
+pub trait IntoTimeZone {
+    /// Resolves the value to a tz database timezone, failing for fixed offsets and rule strings
+    fn into_tz(self) -> Result<Tz, Errors>;
+
+    /// Resolves the value to a timezone of any kind
+    fn into_zone(self) -> Result<ZoneSpec, Errors>
+    where
+        Self: Sized,
+    {
+        self.into_tz().map(ZoneSpec::Named)
+    }
+}
+
+impl IntoTimeZone for ZoneSpec {
+    fn into_tz(self) -> Result<Tz, Errors> {
+        tz_database_zone(self)
+    }
+
+    fn into_zone(self) -> Result<ZoneSpec, Errors> {
+        Ok(self)
+    }
+}
+
+impl IntoTimeZone for Tz {
+    fn into_tz(self) -> Result<Tz, Errors> {
+        Ok(self)
+    }
+}
+
+impl IntoTimeZone for FixedOffset {
+    fn into_tz(self) -> Result<Tz, Errors> {
+        tz_database_zone(ZoneSpec::Fixed(self))
+    }
+
+    fn into_zone(self) -> Result<ZoneSpec, Errors> {
+        Ok(ZoneSpec::Fixed(self))
+    }
+}
+
+impl IntoTimeZone for PosixTz {
+    fn into_tz(self) -> Result<Tz, Errors> {
+        tz_database_zone(ZoneSpec::Posix(self))
+    }
+
+    fn into_zone(self) -> Result<ZoneSpec, Errors> {
+        Ok(ZoneSpec::Posix(self))
+    }
+}
+
+impl IntoTimeZone for CustomZone {
+    fn into_tz(self) -> Result<Tz, Errors> {
+        tz_database_zone(ZoneSpec::Custom(self))
+    }
+
+    fn into_zone(self) -> Result<ZoneSpec, Errors> {
+        Ok(ZoneSpec::Custom(self))
+    }
+}
+
+impl IntoTimeZone for &str {
+    fn into_tz(self) -> Result<Tz, Errors> {
+        tz_database_zone(self.into_zone()?)
+    }
+
+    fn into_zone(self) -> Result<ZoneSpec, Errors> {
+        BundledTzdb.resolve(self)
+    }
+}
+
+impl IntoTimeZone for &String {
+    fn into_tz(self) -> Result<Tz, Errors> {
+        tz_database_zone(self.into_zone()?)
+    }
+
+    fn into_zone(self) -> Result<ZoneSpec, Errors> {
+        BundledTzdb.resolve(self)
+    }
+}
+
+impl IntoTimeZone for String {
+    fn into_tz(self) -> Result<Tz, Errors> {
+        tz_database_zone(self.into_zone()?)
+    }
+
+    fn into_zone(self) -> Result<ZoneSpec, Errors> {
+        BundledTzdb.resolve(&self)
+    }
+}
+
+/// Takes the tz database timezone out of a zone, failing for any other kind
+fn tz_database_zone(zone: ZoneSpec) -> Result<Tz, Errors> {
+    zone.as_tz().ok_or_else(|| Errors::InvalidTimeZone {
+        input: zone.to_string(),
+        suggestions: Vec::new(),
+    })
+}
+
+/// Configures and creates a [`TimeZoneConverter`]
+/// 
+/// Invalid timezones and formats are reported by [`Self::build`], so calls can be chained freely.
+/// 
+/// # Example
+/// 
+/// ```rust
+/// use chrono_tz::Asia::Tokyo;
+/// use timezone_converter::{AmbiguityPolicy, TimeZoneConverter};
+/// 
+/// let converter = TimeZoneConverter::builder()
+///     .source("America/New_York")
+///     .target(Tokyo)
+///     .ambiguity_policy(AmbiguityPolicy::Reject)
+///     .output_format("%Y-%m-%d %H:%M %Z")
+///     .build()
+///     .unwrap();
+/// assert_eq!(converter.ambiguity_policy(), AmbiguityPolicy::Reject);
+/// ```
+#[derive(Debug, Default)]
+pub struct TimeZoneConverterBuilder {
+    /// The source timezone, if one has been given
+    source: Option<Result<ZoneSpec, Errors>>,
+    /// The target timezone, if one has been given
+    target: Option<Result<ZoneSpec, Errors>>,
+    /// How source-local times skipped or repeated by DST are resolved
+    ambiguity_policy: AmbiguityPolicy,
+    /// The clock to read the current time from, if not the system clock
+    clock: Option<Arc<dyn Clock>>,
+    /// The strftime pattern for [`TimeZoneConverter::convert_to_string`]
+    output_format: Option<String>,
+}
+
+impl TimeZoneConverterBuilder {
+    /// Creates a builder with no timezones and the default options
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the source timezone, either as an identifier (e.g., "America/New_York"), an offset (e.g., "-05:00") or a `Tz`
+    pub fn source(mut self, zone: impl IntoTimeZone) -> Self {
+        self.source = Some(zone.into_zone());
+        self
+    }
+
+    /// Sets the target timezone, either as an identifier (e.g., "Europe/London"), an offset (e.g., "+01:00") or a `Tz`
+    pub fn target(mut self, zone: impl IntoTimeZone) -> Self {
+        self.target = Some(zone.into_zone());
+        self
+    }
+
+    /// Sets how source-local wall-clock times skipped or repeated by DST are resolved
+    /// 
+    /// Defaults to [`AmbiguityPolicy::ShiftForward`].
+    pub fn ambiguity_policy(mut self, policy: AmbiguityPolicy) -> Self {
+        self.ambiguity_policy = policy;
+        self
+    }
+
+    /// Sets the clock the converter reads the current time from
+    /// 
+    /// Defaults to [`SystemClock`].
+    pub fn clock(mut self, clock: impl Clock + 'static) -> Self {
+        self.clock = Some(Arc::new(clock));
+        self
+    }
+
+    /// Sets the strftime pattern used by [`TimeZoneConverter::convert_to_string`]
+    /// 
+    /// Defaults to RFC 3339.
+    pub fn output_format(mut self, format: &str) -> Self {
+        self.output_format = Some(format.to_string());
+        self
+    }
+
+    /// Creates the converter
+    /// 
+    /// # Returns
+    /// 
+    /// * `Result<TimeZoneConverter, Errors>` - The converter, `Errors::InvalidTimeZone` if a timezone is invalid or missing, or `Errors::ParseError` if the output format is invalid
+    pub fn build(self) -> Result<TimeZoneConverter, Errors> {
+        let source_tz = self.source.unwrap_or_else(|| Err(missing_zone("source")))?;
+        let target_tz = self.target.unwrap_or_else(|| Err(missing_zone("target")))?;
+        if let Some(format) = &self.output_format {
+            validate_format(format)?;
+        }
+
+        Ok(TimeZoneConverter {
+            source_tz,
+            target_tz,
+            ambiguity_policy: self.ambiguity_policy,
+            clock: self.clock.unwrap_or_else(|| Arc::new(SystemClock)),
+            output_format: self.output_format,
+        })
+    }
+}
+
+/// The error for a timezone that was never given to the builder
+fn missing_zone(which: &str) -> Errors {
+    Errors::InvalidTimeZone {
+        input: format!("<no {} timezone>", which),
+        suggestions: Vec::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+    use chrono_tz::Asia::Tokyo;
+
+    use super::*;
+
+    #[test]
+    fn builds_from_names_and_tz_values() {
+        let converter = TimeZoneConverterBuilder::new()
+            .source("America/New_York".to_string())
+            .target(Tokyo)
+            .output_format("%H:%M %Z")
+            .build()
+            .unwrap();
+        let dt = Utc.with_ymd_and_hms(2024, 7, 1, 0, 0, 0).unwrap();
+        assert_eq!(converter.convert_to_string(dt).unwrap(), "09:00 JST");
+        assert_eq!(converter.ambiguity_policy(), AmbiguityPolicy::ShiftForward);
+    }
+
+    #[test]
+    fn reports_invalid_settings_on_build() {
+        assert!(matches!(
+            TimeZoneConverterBuilder::new().source("tokyo").target("UTC").build(),
+            Err(Errors::InvalidTimeZone { suggestions, .. }) if suggestions == ["Asia/Tokyo"]
+        ));
+        assert!(TimeZoneConverterBuilder::new().source("UTC").build().is_err());
+        assert!(matches!(
+            TimeZoneConverterBuilder::new().source("UTC").target("UTC").output_format("%Q").build(),
+            Err(Errors::ParseError(_))
+        ));
+    }
+    #[test]
+    fn resolves_values_to_tz_or_any_zone() {
+        assert_eq!("US/Eastern".into_tz().unwrap(), chrono_tz::America::New_York);
+        assert_eq!(Tokyo.into_zone().unwrap(), ZoneSpec::Named(Tokyo));
+        assert_eq!("+05:30".into_zone().unwrap().name(), "+05:30");
+        assert!(matches!("+05:30".into_tz(), Err(Errors::InvalidTimeZone { input, .. }) if input == "+05:30"));
+    }
+}
@@ -0,0 +1,258 @@
+//! Business-day arithmetic on local calendars, with holidays
+
+use chrono::{DateTime, Datelike, NaiveDate, TimeZone as ChronoTimeZone, Weekday};
+use chrono_tz::Tz;
+
+use crate::{resolve_local, Errors, IntoTimeZone, TimeZoneConverter, Zone};
+
+/// The most consecutive non-business days searched before giving up
+const MAX_NON_BUSINESS_RUN: u32 = 366;
+
+/// The days of the week that are not business days
+/// 
+/// # Example
+/// 
+/// ```rust
+/// use chrono::Weekday;
+/// use timezone_converter::WeekendDef;
+/// 
+/// assert!(WeekendDef::SATURDAY_SUNDAY.contains(Weekday::Sun));
+/// assert!(WeekendDef::FRIDAY_SATURDAY.contains(Weekday::Fri));
+/// assert!(!WeekendDef::new(&[Weekday::Fri]).contains(Weekday::Sat));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct WeekendDef {
+    /// One bit per weekday, counted from Monday
+    days: u8,
+}
+
+impl WeekendDef {
+    /// A Saturday and Sunday weekend, as in most of the world
+    pub const SATURDAY_SUNDAY: Self = Self { days: 0b110_0000 };
+
+    /// A Friday and Saturday weekend, as in much of the Middle East
+    pub const FRIDAY_SATURDAY: Self = Self { days: 0b011_0000 };
+
+    /// No weekend, so every day is a business day unless it is a holiday
+    pub const NONE: Self = Self { days: 0 };
+
+    /// Creates a weekend of the given days
+    /// 
+    /// # Arguments
+    /// 
+    /// * `days` - The days of the week that are not business days
+    /// 
+    /// # Returns
+    /// 
+    /// * `WeekendDef` - The weekend
+    pub fn new(days: &[Weekday]) -> Self {
+        Self { days: days.iter().fold(0, |bits, day| bits | 1 << day.num_days_from_monday()) }
+    }
+
+    /// Whether a day of the week is part of the weekend
+    pub fn contains(&self, weekday: Weekday) -> bool {
+        self.days & 1 << weekday.num_days_from_monday() != 0
+    }
+}
+
+/// A holiday calendar, so business-day computations can skip the days it lists
+/// 
+/// Any `Fn(NaiveDate) -> bool` closure is a calendar with a Saturday and Sunday weekend.
+/// With the `holiday-data` feature, `HolidayCalendar` provides common national calendars.
+pub trait Holidays {
+    /// Whether a local calendar date is a holiday
+    fn is_holiday(&self, date: NaiveDate) -> bool;
+
+    /// The days of the week that are not business days where the calendar applies
+    fn weekend(&self) -> WeekendDef {
+        WeekendDef::default()
+    }
+}
+
+impl Default for WeekendDef {
+    fn default() -> Self {
+        Self::SATURDAY_SUNDAY
+    }
+}
+
+impl<F: Fn(NaiveDate) -> bool> Holidays for F {
+    fn is_holiday(&self, date: NaiveDate) -> bool {
+        self(date)
+    }
+}
+
+impl TimeZoneConverter {
+    /// Moves a datetime by a number of business days on the target timezone's local calendar
+    /// 
+    /// The local time of day is kept, so an SLA of two business days from Friday 17:00 ends
+    /// on Tuesday at 17:00 in the target timezone. Counting starts from the next day, so
+    /// one business day after a Saturday is the following Monday. A result skipped or
+    /// repeated by a DST transition is resolved with the converter's [`crate::AmbiguityPolicy`].
+    /// 
+    /// # Arguments
+    /// 
+    /// * `dt` - The datetime to move
+    /// * `n` - The number of business days to add, which may be negative
+    /// * `weekend` - The days of the week that are not business days
+    /// 
+    /// # Returns
+    /// 
+    /// * `Result<DateTime<Tz>, Errors>` - The moved datetime in the target timezone, or an error if it is out of range or the target is not a tz database zone
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use chrono::{TimeZone, Utc};
+    /// use timezone_converter::{TimeZoneConverter, WeekendDef};
+    /// 
+    /// let converter = TimeZoneConverter::new("UTC", "Asia/Tokyo").unwrap();
+    /// // Friday 23:00 UTC is already Saturday morning in Tokyo
+    /// let opened = Utc.with_ymd_and_hms(2024, 7, 5, 23, 0, 0).unwrap();
+    /// let due = converter.add_business_days(opened, 2, WeekendDef::SATURDAY_SUNDAY).unwrap();
+    /// assert_eq!(due.to_string(), "2024-07-09 08:00:00 JST");
+    /// ```
+    pub fn add_business_days<T: ChronoTimeZone>(&self, dt: DateTime<T>, n: i32, weekend: WeekendDef) -> Result<DateTime<Tz>, Errors> {
+        self.add_business_days_with(dt, n, weekend, &|_: NaiveDate| false)
+    }
+
+    /// Moves a datetime by business days on the target timezone's local calendar, skipping
+    /// a calendar's holidays and its weekend
+    /// 
+    /// # Arguments
+    /// 
+    /// * `dt` - The datetime to move
+    /// * `n` - The number of business days to add, which may be negative
+    /// * `calendar` - The holiday calendar, which also defines the weekend
+    /// 
+    /// # Returns
+    /// 
+    /// * `Result<DateTime<Tz>, Errors>` - The moved datetime in the target timezone, or an error if it is out of range, no business day is found within a year or the target is not a tz database zone
+    pub fn add_business_days_in<T: ChronoTimeZone>(&self, dt: DateTime<T>, n: i32, calendar: &dyn Holidays) -> Result<DateTime<Tz>, Errors> {
+        self.add_business_days_with(dt, n, calendar.weekend(), calendar)
+    }
+
+    /// Whether an instant falls on a business day in the target timezone
+    /// 
+    /// # Arguments
+    /// 
+    /// * `dt` - The instant to check
+    /// * `calendar` - The holiday calendar, which also defines the weekend
+    /// 
+    /// # Returns
+    /// 
+    /// * `bool` - Whether the target-local date is neither in the weekend nor a holiday
+    pub fn target_is_business_day<T: ChronoTimeZone>(&self, dt: DateTime<T>, calendar: &dyn Holidays) -> bool {
+        let date = dt.with_timezone(&self.target_tz).date_naive();
+        is_business_day(date, calendar.weekend(), calendar)
+    }
+
+    /// Moves a datetime by business days like [`Self::add_business_days`], also skipping holidays
+    /// 
+    /// # Arguments
+    /// 
+    /// * `dt` - The datetime to move
+    /// * `n` - The number of business days to add, which may be negative
+    /// * `weekend` - The days of the week that are not business days
+    /// * `holidays` - The holidays to skip, such as a closure saying whether a target-local date is a holiday
+    /// 
+    /// # Returns
+    /// 
+    /// * `Result<DateTime<Tz>, Errors>` - The moved datetime in the target timezone, or an error if it is out of range, no business day is found within a year or the target is not a tz database zone
+    pub fn add_business_days_with<T: ChronoTimeZone>(
+        &self,
+        dt: DateTime<T>,
+        n: i32,
+        weekend: WeekendDef,
+        holidays: &dyn Holidays,
+    ) -> Result<DateTime<Tz>, Errors> {
+        let tz = self.named_tz(Zone::Target)?;
+        let local = dt.with_timezone(&self.target_tz).naive_local();
+        let mut date = local.date();
+        for _ in 0..n.unsigned_abs() {
+            date = next_business_day(date, n > 0, weekend, holidays)?;
+        }
+        Ok(resolve_local(&self.target_tz, date.and_time(local.time()), self.ambiguity_policy)?.with_timezone(&tz))
+    }
+}
+
+/// Whether a date is neither in the weekend nor a holiday
+fn is_business_day(date: NaiveDate, weekend: WeekendDef, holidays: &dyn Holidays) -> bool {
+    !weekend.contains(date.weekday()) && !holidays.is_holiday(date)
+}
+
+/// Finds the nearest business day after, or before, a date
+fn next_business_day(date: NaiveDate, forward: bool, weekend: WeekendDef, holidays: &dyn Holidays) -> Result<NaiveDate, Errors> {
+    let mut candidate = date;
+    for _ in 0..MAX_NON_BUSINESS_RUN {
+        candidate = if forward { candidate.succ_opt() } else { candidate.pred_opt() }
+            .ok_or_else(|| Errors::ConversionError(format!("no business day found near {}", date)))?;
+        if is_business_day(candidate, weekend, holidays) {
+            return Ok(candidate);
+        }
+    }
+    Err(Errors::ConversionError(format!("no business day within a year of {}", date)))
+}
+
+/// Whether an instant falls on a business day in a timezone
+/// 
+/// # Arguments
+/// 
+/// * `zone` - The timezone whose local calendar to use (e.g., "Europe/London")
+/// * `date` - The instant to check
+/// * `calendar` - The holiday calendar, which also defines the weekend
+/// 
+/// # Returns
+/// 
+/// * `Result<bool, Errors>` - Whether the local date is neither in the weekend nor a holiday, or an error if the timezone is invalid
+/// 
+/// # Example
+/// 
+/// ```rust
+/// use chrono::{Datelike, NaiveDate, TimeZone, Utc};
+/// use timezone_converter::is_business_day_in;
+/// 
+/// let new_year = |date: NaiveDate| date.month() == 1 && date.day() == 1;
+/// // 2024-12-31 20:00 UTC is already New Year's Day in Tokyo
+/// let instant = Utc.with_ymd_and_hms(2024, 12, 31, 20, 0, 0).unwrap();
+/// assert!(is_business_day_in("America/New_York", instant, &new_year).unwrap());
+/// assert!(!is_business_day_in("Asia/Tokyo", instant, &new_year).unwrap());
+/// ```
+pub fn is_business_day_in<T: ChronoTimeZone>(zone: impl IntoTimeZone, date: DateTime<T>, calendar: &dyn Holidays) -> Result<bool, Errors> {
+    let local = date.with_timezone(&zone.into_zone()?).date_naive();
+    Ok(is_business_day(local, calendar.weekend(), calendar))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+
+    #[test]
+    fn counts_business_days_in_the_target_zone() {
+        let converter = TimeZoneConverter::new("UTC", "America/New_York").unwrap();
+        let friday = Utc.with_ymd_and_hms(2024, 3, 8, 21, 0, 0).unwrap();
+        let add = |n, weekend| converter.add_business_days(friday, n, weekend).unwrap().to_string();
+
+        // The weekend includes the DST change, and the local time of day is kept
+        assert_eq!(add(1, WeekendDef::SATURDAY_SUNDAY), "2024-03-11 16:00:00 EDT");
+        assert_eq!(add(1, WeekendDef::FRIDAY_SATURDAY), "2024-03-10 16:00:00 EDT");
+        assert_eq!(add(-1, WeekendDef::SATURDAY_SUNDAY), "2024-03-07 16:00:00 EST");
+        assert_eq!(add(0, WeekendDef::SATURDAY_SUNDAY), "2024-03-08 16:00:00 EST");
+        assert_eq!(add(2, WeekendDef::NONE), "2024-03-10 16:00:00 EDT");
+
+        let converter = TimeZoneConverter::new("UTC", "+05:30").unwrap();
+        assert!(matches!(converter.add_business_days(friday, 1, WeekendDef::SATURDAY_SUNDAY), Err(Errors::ConversionError(_))));
+    }
+
+    #[test]
+    fn skips_holidays() {
+        let converter = TimeZoneConverter::new("UTC", "Europe/London").unwrap();
+        let christmas_eve = Utc.with_ymd_and_hms(2024, 12, 24, 9, 0, 0).unwrap();
+        let bank_holiday = |date: NaiveDate| date.month() == 12 && (date.day() == 25 || date.day() == 26);
+        let due = converter.add_business_days_with(christmas_eve, 1, WeekendDef::default(), &bank_holiday).unwrap();
+        assert_eq!(due.to_string(), "2024-12-27 09:00:00 GMT");
+
+        let every_day = WeekendDef::new(&[Weekday::Mon, Weekday::Tue, Weekday::Wed, Weekday::Thu, Weekday::Fri, Weekday::Sat, Weekday::Sun]);
+        assert!(matches!(converter.add_business_days(christmas_eve, 1, every_day), Err(Errors::ConversionError(_))));
+    }
+}
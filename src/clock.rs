@@ -0,0 +1,86 @@
+//! Sources of the current time for converter methods that work with "now"
+
+use std::fmt;
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+
+use chrono::{DateTime, Duration, Utc};
+
+/// A source of the current time
+/// 
+/// Converters read the current time through a `Clock` instead of calling `Utc::now()`
+/// directly, so the time they see can be controlled.
+pub trait Clock: fmt::Debug + Send + Sync {
+    /// Gets the current instant
+    fn now(&self) -> DateTime<Utc>;
+}
+
+/// The system clock, used by converters unless another clock is given
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+}
+
+/// A clock that stays at a set instant until it is moved, for deterministic tests
+/// 
+/// Wrap it in an `Arc` to keep a handle for moving it after giving it to a converter.
+/// 
+/// # Example
+/// 
+/// ```rust
+/// use std::sync::Arc;
+/// use chrono::{Duration, TimeZone, Utc};
+/// use timezone_converter::{FixedClock, TimeZoneConverter};
+/// 
+/// let clock = Arc::new(FixedClock::new(Utc.with_ymd_and_hms(2024, 3, 30, 12, 0, 0).unwrap()));
+/// let converter = TimeZoneConverter::new("Europe/London", "UTC").unwrap().with_clock(Arc::clone(&clock));
+/// assert_eq!(converter.time_difference(), Duration::zero());
+/// 
+/// clock.advance(Duration::days(1));
+/// assert_eq!(converter.time_difference(), Duration::hours(1));
+/// ```
+#[derive(Debug)]
+pub struct FixedClock {
+    /// The instant the clock reads
+    instant: Mutex<DateTime<Utc>>,
+}
+
+impl FixedClock {
+    /// Creates a clock that reads `instant`
+    pub fn new(instant: DateTime<Utc>) -> Self {
+        Self {
+            instant: Mutex::new(instant),
+        }
+    }
+
+    /// Moves the clock to `instant`
+    pub fn set(&self, instant: DateTime<Utc>) {
+        *self.lock() = instant;
+    }
+
+    /// Moves the clock forward by `duration` (or back, if it is negative)
+    pub fn advance(&self, duration: Duration) {
+        let mut instant = self.lock();
+        *instant += duration;
+    }
+
+    /// Locks the instant, recovering it if a panicking thread poisoned the lock
+    fn lock(&self) -> MutexGuard<'_, DateTime<Utc>> {
+        self.instant.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl Clock for FixedClock {
+    fn now(&self) -> DateTime<Utc> {
+        *self.lock()
+    }
+}
+
+impl<C: Clock + ?Sized> Clock for Arc<C> {
+    fn now(&self) -> DateTime<Utc> {
+        (**self).now()
+    }
+}
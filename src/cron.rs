@@ -0,0 +1,263 @@
+//! Cron expressions evaluated against a timezone's wall clock
+
+use chrono::{DateTime, Datelike, Duration, LocalResult, NaiveDate, NaiveTime, TimeZone, Utc};
+
+use chrono_tz::Tz;
+
+use crate::{Errors, TimeZoneConverter, Zone};
+
+/// How many years ahead [`TimeZoneConverter::next_fire_times`] looks for matching days,
+/// enough to reach the next 29 February across a skipped leap year
+const CRON_SEARCH_YEARS: i64 = 9;
+
+/// A parsed five-field cron expression: minute, hour, day of month, month and day of week
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct CronSchedule {
+    /// Bit `n` is set if the expression fires at minute `n`
+    minutes: u64,
+    /// Bit `n` is set if the expression fires at hour `n`
+    hours: u32,
+    /// Bit `n` is set if the expression fires on day `n` of the month
+    days_of_month: u32,
+    /// Bit `n` is set if the expression fires in month `n`
+    months: u16,
+    /// Bit `n` is set if the expression fires on weekday `n`, counting from Sunday as 0
+    days_of_week: u8,
+    /// Whether the day-of-month field was restricted (not `*`)
+    day_of_month_restricted: bool,
+    /// Whether the day-of-week field was restricted (not `*`)
+    day_of_week_restricted: bool,
+}
+
+impl CronSchedule {
+    /// Parses an expression such as `*/15 9-17 * * MON-FRI` or a macro such as `@daily`
+    fn parse(expr: &str) -> Result<Self, Errors> {
+        let expanded = match expr.trim() {
+            "@yearly" | "@annually" => "0 0 1 1 *",
+            "@monthly" => "0 0 1 * *",
+            "@weekly" => "0 0 * * 0",
+            "@daily" | "@midnight" => "0 0 * * *",
+            "@hourly" => "0 * * * *",
+            other => other,
+        };
+        let fields: Vec<&str> = expanded.split_whitespace().collect();
+        let [minute, hour, day_of_month, month, day_of_week] = fields[..] else {
+            return Err(invalid(expr, "expected 5 fields"));
+        };
+
+        // Day 7 is an alias for Sunday
+        let days_of_week = parse_field(expr, day_of_week, 0, 7, &DAY_NAMES)?;
+        Ok(Self {
+            minutes: parse_field(expr, minute, 0, 59, &[])?,
+            hours: parse_field(expr, hour, 0, 23, &[])? as u32,
+            days_of_month: parse_field(expr, day_of_month, 1, 31, &[])? as u32,
+            months: parse_field(expr, month, 1, 12, &MONTH_NAMES)? as u16,
+            days_of_week: ((days_of_week | (days_of_week >> 7)) & 0x7f) as u8,
+            day_of_month_restricted: day_of_month != "*",
+            day_of_week_restricted: day_of_week != "*",
+        })
+    }
+
+    /// Whether the expression fires on a date
+    /// 
+    /// As in Vixie cron, when both day fields are restricted a date matching either one fires.
+    fn matches_date(&self, date: NaiveDate) -> bool {
+        if self.months & (1 << date.month()) == 0 {
+            return false;
+        }
+        let day_of_month = self.days_of_month & (1 << date.day()) != 0;
+        let day_of_week = self.days_of_week & (1 << date.weekday().num_days_from_sunday()) != 0;
+        match (self.day_of_month_restricted, self.day_of_week_restricted) {
+            (true, true) => day_of_month || day_of_week,
+            (true, false) => day_of_month,
+            (false, true) => day_of_week,
+            (false, false) => true,
+        }
+    }
+
+    /// Lists the wall-clock times the expression fires at on a matching date, in order
+    fn times(&self) -> impl Iterator<Item = NaiveTime> + '_ {
+        (0..24u32)
+            .filter(move |hour| self.hours & (1 << hour) != 0)
+            .flat_map(move |hour| {
+                (0..60u32)
+                    .filter(move |minute| self.minutes & (1 << minute) != 0)
+                    .filter_map(move |minute| NaiveTime::from_hms_opt(hour, minute, 0))
+            })
+    }
+}
+
+const MONTH_NAMES: [&str; 12] = ["JAN", "FEB", "MAR", "APR", "MAY", "JUN", "JUL", "AUG", "SEP", "OCT", "NOV", "DEC"];
+
+const DAY_NAMES: [&str; 7] = ["SUN", "MON", "TUE", "WED", "THU", "FRI", "SAT"];
+
+/// Parses one comma-separated field of values, ranges and steps into a bit set
+/// 
+/// `names` are the three-letter aliases for the values starting at `min`.
+fn parse_field(expr: &str, field: &str, min: u32, max: u32, names: &[&str]) -> Result<u64, Errors> {
+    let value = |text: &str| -> Result<u32, Errors> {
+        let upper = text.to_ascii_uppercase();
+        let parsed = match names.iter().position(|name| *name == upper) {
+            Some(index) => index as u32 + min,
+            None => text.parse().map_err(|_| invalid(expr, &format!("'{}' is not a number", text)))?,
+        };
+        if parsed < min || parsed > max {
+            return Err(invalid(expr, &format!("{} is outside {}-{}", parsed, min, max)));
+        }
+        Ok(parsed)
+    };
+
+    let mut bits = 0u64;
+    for part in field.split(',') {
+        let (range, step) = match part.split_once('/') {
+            Some((range, step)) => {
+                let step: u32 = step.parse().ok().filter(|step| *step > 0)
+                    .ok_or_else(|| invalid(expr, &format!("invalid step in '{}'", part)))?;
+                (range, step)
+            }
+            None => (part, 1),
+        };
+        let (start, end) = match range {
+            "*" => (min, max),
+            _ => match range.split_once('-') {
+                Some((start, end)) => (value(start)?, value(end)?),
+                // A single value with a step runs to the end of the field, e.g. `5/15`
+                None if step > 1 => (value(range)?, max),
+                None => (value(range)?, value(range)?),
+            },
+        };
+        if start > end {
+            return Err(invalid(expr, &format!("range '{}' is backwards", range)));
+        }
+        for value in (start..=end).step_by(step as usize) {
+            bits |= 1u64 << value;
+        }
+    }
+    Ok(bits)
+}
+
+/// Builds the error for an invalid expression
+fn invalid(expr: &str, reason: &str) -> Errors {
+    Errors::ParseError(format!("invalid cron expression '{}': {}", expr, reason))
+}
+
+impl TimeZoneConverter {
+    /// Evaluates a cron expression against the wall clock of either timezone and lists its next fire times
+    /// 
+    /// The expression uses the standard five fields (minute, hour, day of month, month and
+    /// day of week) with lists, ranges, steps, month and weekday names, and the `@daily`
+    /// style macros. Wall-clock times skipped by a spring-forward transition do not fire,
+    /// and times repeated when clocks fall back fire once, at their first occurrence.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `expr` - The cron expression (e.g., "30 9 * * MON-FRI")
+    /// * `zone` - Whether the expression follows the source or the target timezone's wall clock
+    /// * `after` - The instant to start after (exclusive)
+    /// * `n` - The number of fire times to list
+    /// 
+    /// # Returns
+    /// 
+    /// * `Result<Vec<DateTime<Tz>>, Errors>` - The fire times in the target timezone, in order, or `Errors::ParseError` for an invalid expression
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use chrono::{TimeZone, Utc};
+    /// use timezone_converter::{TimeZoneConverter, Zone};
+    /// 
+    /// let converter = TimeZoneConverter::new("America/New_York", "UTC").unwrap();
+    /// let after = Utc.with_ymd_and_hms(2024, 3, 8, 0, 0, 0).unwrap();
+    /// let times: Vec<String> = converter.next_fire_times("30 2 * * *", Zone::Source, after, 3).unwrap()
+    ///     .iter()
+    ///     .map(|time| time.to_string())
+    ///     .collect();
+    /// // 02:30 does not exist in New York on Mar 10, so that day is skipped
+    /// assert_eq!(times, ["2024-03-08 07:30:00 UTC", "2024-03-09 07:30:00 UTC", "2024-03-11 06:30:00 UTC"]);
+    /// ```
+    pub fn next_fire_times(&self, expr: &str, zone: Zone, after: DateTime<Utc>, n: usize) -> Result<Vec<DateTime<Tz>>, Errors> {
+        let schedule = CronSchedule::parse(expr)?;
+        let tz = self.zone(zone);
+        let target = self.named_tz(Zone::Target)?;
+        let first_date = after.with_timezone(tz).date_naive();
+        let last_date = first_date.checked_add_signed(Duration::days(CRON_SEARCH_YEARS * 366)).unwrap_or(NaiveDate::MAX);
+
+        let mut fire_times = Vec::new();
+        for date in first_date.iter_days().take_while(|date| *date <= last_date) {
+            if fire_times.len() >= n {
+                break;
+            }
+            if !schedule.matches_date(date) {
+                continue;
+            }
+            for time in schedule.times() {
+                let instant = match tz.from_local_datetime(&date.and_time(time)) {
+                    LocalResult::Single(instant) | LocalResult::Ambiguous(instant, _) => instant,
+                    LocalResult::None => continue,
+                };
+                if instant > after && fire_times.len() < n {
+                    fire_times.push(instant.with_timezone(&target));
+                }
+            }
+        }
+        Ok(fire_times)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fire_times(source: &str, expr: &str, after: DateTime<Utc>, n: usize) -> Vec<String> {
+        TimeZoneConverter::new(source, "UTC").unwrap()
+            .next_fire_times(expr, Zone::Source, after, n).unwrap()
+            .iter()
+            .map(|time| time.to_string())
+            .collect()
+    }
+
+    #[test]
+    fn parses_fields() {
+        let schedule = CronSchedule::parse("*/20 9-11,14 1,15 JAN-MAR mon-fri").unwrap();
+        assert_eq!(schedule.minutes, (1 << 0) | (1 << 20) | (1 << 40));
+        assert_eq!(schedule.hours, (1 << 9) | (1 << 10) | (1 << 11) | (1 << 14));
+        assert_eq!(schedule.months, 0b1110);
+        assert_eq!(schedule.days_of_week, 0b0111110);
+        assert_eq!(CronSchedule::parse("0 0 * * 7").unwrap().days_of_week, 1);
+        assert_eq!(CronSchedule::parse("@hourly").unwrap(), CronSchedule::parse("0 * * * *").unwrap());
+
+        for invalid in ["", "* * * *", "60 * * * *", "* * 0 * *", "*/0 * * * *", "5-1 * * * *", "* * * FOO *"] {
+            assert!(matches!(CronSchedule::parse(invalid), Err(Errors::ParseError(_))), "{}", invalid);
+        }
+    }
+
+    #[test]
+    fn repeated_hour_fires_once() {
+        let after = Utc.with_ymd_and_hms(2024, 11, 3, 4, 0, 0).unwrap();
+        assert_eq!(fire_times("America/New_York", "*/30 1 * * *", after, 3), [
+            "2024-11-03 05:00:00 UTC",
+            "2024-11-03 05:30:00 UTC",
+            "2024-11-04 06:00:00 UTC",
+        ]);
+    }
+
+    #[test]
+    fn day_fields_combine_with_or() {
+        let after = Utc.with_ymd_and_hms(2024, 7, 1, 0, 0, 0).unwrap();
+        // The 13th of each month and every Friday
+        assert_eq!(fire_times("UTC", "0 12 13 * FRI", after, 3), [
+            "2024-07-05 12:00:00 UTC",
+            "2024-07-12 12:00:00 UTC",
+            "2024-07-13 12:00:00 UTC",
+        ]);
+        assert!(fire_times("UTC", "0 0 30 2 *", after, 1).is_empty());
+        assert_eq!(fire_times("UTC", "0 0 29 2 *", after, 1), ["2028-02-29 00:00:00 UTC"]);
+    }
+
+    #[test]
+    fn stops_at_the_latest_date() {
+        let last_day = NaiveDate::MAX.pred_opt().unwrap();
+        let after = last_day.and_hms_opt(0, 0, 0).unwrap().and_utc();
+        assert_eq!(fire_times("UTC", "0 12 * * *", after, 1), [last_day.and_hms_opt(12, 0, 0).unwrap().and_utc().to_string()]);
+    }
+}
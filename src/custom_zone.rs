@@ -0,0 +1,289 @@
+//! User-defined timezones with their own offset and DST rules
+
+use std::fmt;
+
+use chrono::{Datelike, Duration, FixedOffset, LocalResult, NaiveDate, NaiveDateTime, NaiveTime, Offset, TimeZone, Timelike, Weekday};
+use chrono_tz::OffsetComponents;
+
+use crate::posix_tz::InlineStr;
+use crate::{Errors, PosixOffset, PosixTz};
+
+/// The longest name a custom zone may have, in bytes
+const MAX_NAME: usize = 32;
+
+/// A timezone with user-defined rules, for local conventions such as a facility's "plant
+/// time" that no IANA zone describes
+/// 
+/// A zone has a standard offset and, optionally, a DST offset with the yearly rules for
+/// when it starts and ends. It can be used as either side of a converter.
+/// 
+/// # Example
+/// 
+/// ```rust
+/// use chrono::{Duration, FixedOffset, NaiveTime, TimeZone, Utc, Weekday};
+/// use timezone_converter::{CustomZone, TimeZoneConverter, TransitionRule};
+/// 
+/// let two = NaiveTime::from_hms_opt(2, 0, 0).unwrap();
+/// let plant = CustomZone::new("Plant Time", "PLT", FixedOffset::east_opt(3 * 3600 + 20 * 60).unwrap())
+///     .unwrap()
+///     .with_dst(
+///         "PLST",
+///         Duration::minutes(30),
+///         TransitionRule::NthWeekday { month: 4, week: 1, weekday: Weekday::Mon, at: two },
+///         TransitionRule::Date { month: 10, day: 1, at: two },
+///     )
+///     .unwrap();
+/// 
+/// let converter = TimeZoneConverter::from_tz(plant, chrono_tz::UTC);
+/// let noon = plant.with_ymd_and_hms(2024, 6, 3, 12, 0, 0).unwrap();
+/// assert_eq!(noon.to_string(), "2024-06-03 12:00:00 PLST");
+/// assert_eq!(converter.convert(noon).unwrap().to_string(), "2024-06-03 08:10:00 UTC");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CustomZone {
+    /// The zone's name (e.g., "Plant Time")
+    name: InlineStr<MAX_NAME>,
+    /// The zone's offsets and transitions
+    rules: PosixTz,
+}
+
+/// The offset of a [`CustomZone`] at a particular instant
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CustomOffset {
+    /// The zone the offset belongs to
+    zone: CustomZone,
+    /// The offset under the zone's rules
+    inner: PosixOffset,
+}
+
+/// A yearly date and local time at which a [`CustomZone`]'s DST starts or ends
+/// 
+/// Start times are read in standard time and end times in DST.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TransitionRule {
+    /// The `week`th `weekday` of a month (1 to 12), with week 5 meaning the last
+    NthWeekday {
+        /// The month, from 1 to 12
+        month: u32,
+        /// The week of the month, from 1 to 5 (the last)
+        week: u8,
+        /// The day of the week
+        weekday: Weekday,
+        /// The local time of day
+        at: NaiveTime,
+    },
+    /// The same date every year, other than February 29
+    Date {
+        /// The month, from 1 to 12
+        month: u32,
+        /// The day of the month
+        day: u32,
+        /// The local time of day
+        at: NaiveTime,
+    },
+}
+
+impl CustomZone {
+    /// Creates a zone with a fixed offset
+    /// 
+    /// # Arguments
+    /// 
+    /// * `name` - The zone's name, up to 32 bytes (e.g., "Plant Time")
+    /// * `abbreviation` - The abbreviation shown in formatted times (e.g., "PLT"): 3 to 16 letters, digits, `+` or `-`
+    /// * `offset` - The offset from UTC
+    /// 
+    /// # Returns
+    /// 
+    /// * `Result<CustomZone, Errors>` - The zone, or `Errors::ParseError` if the name or abbreviation is invalid
+    pub fn new(name: &str, abbreviation: &str, offset: FixedOffset) -> Result<Self, Errors> {
+        let stored = InlineStr::new(name)
+            .filter(|_| !name.trim().is_empty())
+            .ok_or_else(|| invalid(name, "names must be 1 to 32 bytes"))?;
+        let rules = format!("<{}>{}", abbreviation, posix_hms(-offset.local_minus_utc()));
+        Ok(Self { name: stored, rules: parse_rules(name, &rules)? })
+    }
+
+    /// Adds DST to the zone, replacing any DST rules it already has
+    /// 
+    /// # Arguments
+    /// 
+    /// * `abbreviation` - The abbreviation during DST (e.g., "PLST")
+    /// * `savings` - How far clocks move forward during DST (e.g., one hour)
+    /// * `start` - When DST starts each year, in standard time
+    /// * `end` - When DST ends each year, in DST
+    /// 
+    /// # Returns
+    /// 
+    /// * `Result<CustomZone, Errors>` - The zone, or `Errors::ParseError` if the abbreviation, savings or a rule is invalid
+    pub fn with_dst(self, abbreviation: &str, savings: Duration, start: TransitionRule, end: TransitionRule) -> Result<Self, Errors> {
+        let name = self.name.as_str();
+        let std = self.rules.std_offset().local_minus_utc();
+        let savings = i32::try_from(savings.num_seconds()).map_err(|_| invalid(name, "savings out of range"))?;
+        let rules = format!(
+            "<{}>{}<{}>{},{},{}",
+            self.rules.std_abbreviation(),
+            posix_hms(-std),
+            abbreviation,
+            posix_hms(-(std + savings)),
+            posix_rule(name, start)?,
+            posix_rule(name, end)?,
+        );
+        Ok(Self { name: self.name, rules: parse_rules(name, &rules)? })
+    }
+
+    /// Gets the zone's name (e.g., "Plant Time")
+    pub fn name(&self) -> &str {
+        self.name.as_str()
+    }
+
+    /// Gets the zone's rules as a POSIX TZ rule zone
+    pub fn rules(&self) -> PosixTz {
+        self.rules
+    }
+}
+
+impl CustomOffset {
+    /// Gets the abbreviation in effect (e.g., "PLST")
+    pub fn abbreviation(&self) -> &str {
+        self.inner.abbreviation()
+    }
+}
+
+impl fmt::Display for CustomZone {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl fmt::Display for CustomOffset {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.inner, f)
+    }
+}
+
+impl TimeZone for CustomZone {
+    type Offset = CustomOffset;
+
+    fn from_offset(offset: &CustomOffset) -> Self {
+        offset.zone
+    }
+
+    fn offset_from_local_date(&self, local: &NaiveDate) -> LocalResult<CustomOffset> {
+        self.rules.offset_from_local_date(local).map(|inner| CustomOffset { zone: *self, inner })
+    }
+
+    fn offset_from_local_datetime(&self, local: &NaiveDateTime) -> LocalResult<CustomOffset> {
+        self.rules.offset_from_local_datetime(local).map(|inner| CustomOffset { zone: *self, inner })
+    }
+
+    fn offset_from_utc_date(&self, utc: &NaiveDate) -> CustomOffset {
+        CustomOffset { zone: *self, inner: self.rules.offset_from_utc_date(utc) }
+    }
+
+    fn offset_from_utc_datetime(&self, utc: &NaiveDateTime) -> CustomOffset {
+        CustomOffset { zone: *self, inner: self.rules.offset_from_utc_datetime(utc) }
+    }
+}
+
+impl Offset for CustomOffset {
+    fn fix(&self) -> FixedOffset {
+        self.inner.fix()
+    }
+}
+
+impl OffsetComponents for CustomOffset {
+    fn base_utc_offset(&self) -> Duration {
+        self.inner.base_utc_offset()
+    }
+
+    fn dst_offset(&self) -> Duration {
+        self.inner.dst_offset()
+    }
+}
+
+/// Parses the rule string built for a zone, reporting problems against the zone's name
+fn parse_rules(name: &str, rules: &str) -> Result<PosixTz, Errors> {
+    rules.parse().map_err(|_| invalid(name, "invalid abbreviation, offset or rule"))
+}
+
+/// Writes a transition rule in POSIX form, e.g. `M3.2.0/2:00:00` or `J100/2:00:00`
+fn posix_rule(name: &str, rule: TransitionRule) -> Result<String, Errors> {
+    let (date, at) = match rule {
+        TransitionRule::NthWeekday { month, week, weekday, at } => {
+            (format!("M{}.{}.{}", month, week, weekday.num_days_from_sunday()), at)
+        }
+        TransitionRule::Date { month, day, at } => {
+            // `Jn` days never count February 29, so they are the ordinals of a non-leap year
+            let date = NaiveDate::from_ymd_opt(2023, month, day).ok_or_else(|| invalid(name, "no such date in a non-leap year"))?;
+            (format!("J{}", date.ordinal()), at)
+        }
+    };
+    Ok(format!("{}/{}", date, posix_hms(at.num_seconds_from_midnight() as i32)))
+}
+
+/// Writes a duration in seconds as `[-]h:mm:ss`
+fn posix_hms(seconds: i32) -> String {
+    let sign = if seconds < 0 { "-" } else { "" };
+    let seconds = seconds.abs();
+    format!("{}{}:{:02}:{:02}", sign, seconds / 3600, seconds % 3600 / 60, seconds % 60)
+}
+
+fn invalid(name: &str, reason: &str) -> Errors {
+    Errors::ParseError(format!("invalid custom zone '{}': {}", name, reason))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+
+    fn plant() -> CustomZone {
+        let at = NaiveTime::from_hms_opt(3, 0, 0).unwrap();
+        CustomZone::new("Plant Time", "PLT", FixedOffset::east_opt(-(7 * 3600 + 1800)).unwrap())
+            .unwrap()
+            .with_dst(
+                "PLDT",
+                Duration::hours(1),
+                TransitionRule::Date { month: 3, day: 1, at },
+                TransitionRule::NthWeekday { month: 11, week: 5, weekday: Weekday::Fri, at },
+            )
+            .unwrap()
+    }
+
+    #[test]
+    fn follows_its_rules() {
+        let zone = plant();
+        assert_eq!(zone.to_string(), "Plant Time");
+        assert_eq!(zone.rules().to_string(), "PLT7:30PLDT,J60/3,M11.5.5/3");
+
+        let at = |m, d, h| Utc.with_ymd_and_hms(2024, m, d, h, 0, 0).unwrap().with_timezone(&zone).to_string();
+        assert_eq!(at(2, 1, 12), "2024-02-01 04:30:00 PLT");
+        assert_eq!(at(7, 1, 12), "2024-07-01 05:30:00 PLDT");
+        // DST ends on the last Friday of November, the 29th in 2024
+        assert_eq!(at(11, 28, 12), "2024-11-28 05:30:00 PLDT");
+        assert_eq!(at(11, 30, 12), "2024-11-30 04:30:00 PLT");
+
+        let fixed = CustomZone::new("Mine Time", "MIN", FixedOffset::east_opt(5400).unwrap()).unwrap();
+        let noon = fixed.with_ymd_and_hms(2024, 7, 1, 12, 0, 0).unwrap();
+        assert_eq!(noon.with_timezone(&Utc).to_string(), "2024-07-01 10:30:00 UTC");
+        assert_eq!(noon.offset().dst_offset(), Duration::zero());
+    }
+
+    #[test]
+    fn rejects_invalid_definitions() {
+        let offset = FixedOffset::east_opt(0).unwrap();
+        let at = NaiveTime::MIN;
+        assert!(CustomZone::new("", "PLT", offset).is_err());
+        assert!(CustomZone::new("A name far too long for a custom zone", "PLT", offset).is_err());
+        assert!(CustomZone::new("Plant", "P", offset).is_err());
+        assert!(CustomZone::new("Plant", "P<T", offset).is_err());
+
+        let zone = CustomZone::new("Plant", "PLT", offset).unwrap();
+        let leap_day = TransitionRule::Date { month: 2, day: 29, at };
+        let sixth_week = TransitionRule::NthWeekday { month: 3, week: 6, weekday: Weekday::Sun, at };
+        let valid = TransitionRule::Date { month: 10, day: 1, at };
+        assert!(matches!(zone.with_dst("PLST", Duration::hours(1), leap_day, valid), Err(Errors::ParseError(_))));
+        assert!(matches!(zone.with_dst("PLST", Duration::hours(1), sixth_week, valid), Err(Errors::ParseError(_))));
+        assert!(zone.with_dst("PLST", Duration::hours(1), valid, valid).is_ok());
+    }
+}
@@ -0,0 +1,242 @@
+//! A C interface for embedding the converter in C and C++ programs
+//! 
+//! The declarations are in `include/timezone_converter.h`, generated with
+//! `cbindgen --config cbindgen.toml --output include/timezone_converter.h`.
+
+use std::ffi::{c_char, CStr};
+
+use chrono::{DateTime, Datelike, Offset, Timelike, Utc};
+use crate::{is_dst_at, TimeZoneConverter, ZoneSpec, MAX_SUPPORTED_YEAR, MIN_SUPPORTED_YEAR};
+
+/// The result of every fallible `tzc_` function
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TzcStatus {
+    /// The call succeeded and its outputs were written
+    Ok = 0,
+    /// A required pointer was null or a string was not valid UTF-8
+    InvalidArgument = 1,
+    /// A timezone identifier was not recognised
+    InvalidTimeZone = 2,
+    /// The instant is outside the UTC years 1 to 9999 that the converter supports
+    OutOfRange = 3,
+}
+
+/// Which of a converter's two timezones to describe, passed to [`tzc_local_time`] as its value
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TzcZone {
+    /// The timezone conversions start from
+    Source = 0,
+    /// The timezone conversions produce
+    Target = 1,
+}
+
+/// A converter created by [`tzc_converter_new`], opaque to C
+#[derive(Debug)]
+pub struct TzcConverter {
+    /// The converter every call is delegated to
+    inner: TimeZoneConverter,
+}
+
+/// A wall-clock time in a timezone, with the offset and DST status in effect
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TzcLocalTime {
+    /// The year, e.g. 2024
+    pub year: i32,
+    /// The month, 1 to 12
+    pub month: u32,
+    /// The day of the month, 1 to 31
+    pub day: u32,
+    /// The hour, 0 to 23
+    pub hour: u32,
+    /// The minute, 0 to 59
+    pub minute: u32,
+    /// The second, 0 to 59
+    pub second: u32,
+    /// The offset from UTC in seconds, positive east of Greenwich
+    pub offset_seconds: i32,
+    /// Whether Daylight Saving Time is in effect
+    pub is_dst: bool,
+}
+
+/// Creates a converter between two IANA timezone identifiers or literal offsets such as `"+05:30"`
+/// 
+/// On success the converter is written to `out` and must be released with
+/// [`tzc_converter_free`].
+/// 
+/// # Safety
+/// 
+/// `source` and `target` must be null or point to NUL-terminated strings, and `out` must
+/// be null or valid for writing a pointer.
+#[no_mangle]
+pub unsafe extern "C" fn tzc_converter_new(source: *const c_char, target: *const c_char, out: *mut *mut TzcConverter) -> TzcStatus {
+    if source.is_null() || target.is_null() || out.is_null() {
+        return TzcStatus::InvalidArgument;
+    }
+    let (Ok(source), Ok(target)) = (CStr::from_ptr(source).to_str(), CStr::from_ptr(target).to_str()) else {
+        return TzcStatus::InvalidArgument;
+    };
+    match TimeZoneConverter::new(source, target) {
+        Ok(inner) => {
+            *out = Box::into_raw(Box::new(TzcConverter { inner }));
+            TzcStatus::Ok
+        }
+        Err(_) => TzcStatus::InvalidTimeZone,
+    }
+}
+
+/// Releases a converter created by [`tzc_converter_new`]; null is ignored
+/// 
+/// # Safety
+/// 
+/// `converter` must be null or a pointer returned by [`tzc_converter_new`] that has not
+/// already been freed.
+#[no_mangle]
+pub unsafe extern "C" fn tzc_converter_free(converter: *mut TzcConverter) {
+    if !converter.is_null() {
+        drop(Box::from_raw(converter));
+    }
+}
+
+/// Converts Unix epoch seconds to the wall-clock time in the target timezone
+/// 
+/// # Safety
+/// 
+/// `converter` must be null or a live pointer from [`tzc_converter_new`], and `out` must be
+/// null or valid for writing a [`TzcLocalTime`].
+#[no_mangle]
+pub unsafe extern "C" fn tzc_convert_epoch(converter: *const TzcConverter, epoch_seconds: i64, out: *mut TzcLocalTime) -> TzcStatus {
+    tzc_local_time(converter, TzcZone::Target as u32, epoch_seconds, out)
+}
+
+/// Gets the wall-clock time, offset and DST status of either timezone at Unix epoch seconds
+/// 
+/// `zone` is a [`TzcZone`] value; any other value gives `TZC_STATUS_INVALID_ARGUMENT`.
+/// 
+/// # Safety
+/// 
+/// `converter` must be null or a live pointer from [`tzc_converter_new`], and `out` must be
+/// null or valid for writing a [`TzcLocalTime`].
+#[no_mangle]
+pub unsafe extern "C" fn tzc_local_time(converter: *const TzcConverter, zone: u32, epoch_seconds: i64, out: *mut TzcLocalTime) -> TzcStatus {
+    let (Some(converter), false) = (converter.as_ref(), out.is_null()) else {
+        return TzcStatus::InvalidArgument;
+    };
+    let Some(instant) = supported_instant(epoch_seconds) else {
+        return TzcStatus::OutOfRange;
+    };
+    // A C enum can hold any integer, so the value is checked rather than trusted to be a `TzcZone`
+    let tz = match zone {
+        0 => &converter.inner.source_tz,
+        1 => &converter.inner.target_tz,
+        _ => return TzcStatus::InvalidArgument,
+    };
+    *out = local_time(tz, instant);
+    TzcStatus::Ok
+}
+
+/// Gets the source offset minus the target offset in seconds at Unix epoch seconds
+/// 
+/// # Safety
+/// 
+/// `converter` must be null or a live pointer from [`tzc_converter_new`], and `out` must be
+/// null or valid for writing an `int64_t`.
+#[no_mangle]
+pub unsafe extern "C" fn tzc_difference_seconds(converter: *const TzcConverter, epoch_seconds: i64, out: *mut i64) -> TzcStatus {
+    let (Some(converter), false) = (converter.as_ref(), out.is_null()) else {
+        return TzcStatus::InvalidArgument;
+    };
+    let Some(instant) = supported_instant(epoch_seconds) else {
+        return TzcStatus::OutOfRange;
+    };
+    *out = converter.inner.time_difference_at(instant).num_seconds();
+    TzcStatus::Ok
+}
+
+/// Gets the instant at Unix epoch seconds, if its UTC year is one the converter supports
+/// 
+/// Instants near chrono's limits would overflow when shifted into a zone or searched around
+/// for transitions, and a panic must not unwind into the calling C code.
+fn supported_instant(epoch_seconds: i64) -> Option<DateTime<Utc>> {
+    DateTime::from_timestamp(epoch_seconds, 0)
+        .filter(|instant| (MIN_SUPPORTED_YEAR..=MAX_SUPPORTED_YEAR).contains(&instant.year()))
+}
+
+/// Describes an instant's wall-clock time in a timezone
+fn local_time(tz: &ZoneSpec, instant: DateTime<Utc>) -> TzcLocalTime {
+    let local = instant.with_timezone(tz);
+    TzcLocalTime {
+        year: local.year(),
+        month: local.month(),
+        day: local.day(),
+        hour: local.hour(),
+        minute: local.minute(),
+        second: local.second(),
+        offset_seconds: local.offset().fix().local_minus_utc(),
+        is_dst: is_dst_at(tz, instant),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use std::ptr;
+
+    #[test]
+    fn converts_through_the_c_interface() {
+        unsafe {
+            let mut converter = ptr::null_mut();
+            assert_eq!(tzc_converter_new(c"America/New_York".as_ptr(), c"Asia/Tokyo".as_ptr(), &mut converter), TzcStatus::Ok);
+
+            let mut time = TzcLocalTime::default();
+            assert_eq!(tzc_convert_epoch(converter, 1_720_000_000, &mut time), TzcStatus::Ok);
+            assert_eq!((time.year, time.month, time.day, time.hour, time.minute), (2024, 7, 3, 18, 46));
+            assert_eq!(time.offset_seconds, 9 * 3600);
+
+            assert_eq!(tzc_local_time(converter, TzcZone::Source as u32, 1_720_000_000, &mut time), TzcStatus::Ok);
+            assert_eq!((time.hour, time.offset_seconds, time.is_dst), (5, -4 * 3600, true));
+
+            let mut difference = 0;
+            assert_eq!(tzc_difference_seconds(converter, 1_720_000_000, &mut difference), TzcStatus::Ok);
+            assert_eq!(difference, -13 * 3600);
+            assert_eq!(tzc_convert_epoch(converter, i64::MAX, &mut time), TzcStatus::OutOfRange);
+            assert_eq!(tzc_local_time(converter, 2, 1_720_000_000, &mut time), TzcStatus::InvalidArgument);
+
+            // The limits of chrono's range are rejected rather than panicking across the C boundary
+            for epoch in [DateTime::<Utc>::MAX_UTC.timestamp(), DateTime::<Utc>::MIN_UTC.timestamp()] {
+                assert_eq!(tzc_convert_epoch(converter, epoch, &mut time), TzcStatus::OutOfRange);
+                assert_eq!(tzc_local_time(converter, TzcZone::Source as u32, epoch, &mut time), TzcStatus::OutOfRange);
+                assert_eq!(tzc_difference_seconds(converter, epoch, &mut difference), TzcStatus::OutOfRange);
+            }
+            let last_supported = Utc.with_ymd_and_hms(MAX_SUPPORTED_YEAR, 12, 31, 23, 59, 59).unwrap().timestamp();
+            assert_eq!(tzc_convert_epoch(converter, last_supported, &mut time), TzcStatus::Ok);
+            assert_eq!((time.year, time.month, time.day), (MAX_SUPPORTED_YEAR + 1, 1, 1));
+            assert_eq!(tzc_convert_epoch(converter, last_supported + 1, &mut time), TzcStatus::OutOfRange);
+
+            tzc_converter_free(converter);
+        }
+    }
+
+    #[test]
+    fn rejects_invalid_arguments() {
+        unsafe {
+            let mut converter = ptr::null_mut();
+            assert_eq!(tzc_converter_new(c"Mars/Olympus".as_ptr(), c"UTC".as_ptr(), &mut converter), TzcStatus::InvalidTimeZone);
+            assert_eq!(tzc_converter_new(ptr::null(), c"UTC".as_ptr(), &mut converter), TzcStatus::InvalidArgument);
+            assert!(converter.is_null());
+            assert_eq!(tzc_convert_epoch(ptr::null(), 0, &mut TzcLocalTime::default()), TzcStatus::InvalidArgument);
+            tzc_converter_free(ptr::null_mut());
+        }
+    }
+
+    #[test]
+    fn header_declares_every_function() {
+        let header = include_str!("../include/timezone_converter.h");
+        for name in ["tzc_converter_new", "tzc_converter_free", "tzc_convert_epoch", "tzc_local_time", "tzc_difference_seconds", "TzcLocalTime"] {
+            assert!(header.contains(name), "{} is missing; regenerate the header with cbindgen", name);
+        }
+    }
+}
@@ -0,0 +1,94 @@
+//! Timezone lookup from geographic coordinates, using the boundary data bundled with `tzf-rs`
+
+use std::sync::OnceLock;
+
+use chrono_tz::Tz;
+use tzf_rs::DefaultFinder;
+
+use crate::{canonicalize, Errors, TimeZoneConverter};
+
+/// Finds the timezone in effect at a point on Earth
+/// 
+/// Points at sea resolve to the nautical `Etc/GMT±N` zone covering them. The boundary
+/// data is loaded on first use, which takes a moment.
+/// 
+/// # Arguments
+/// 
+/// * `lat` - The latitude in degrees, from -90 (south) to 90 (north)
+/// * `lon` - The longitude in degrees, from -180 (west) to 180 (east)
+/// 
+/// # Returns
+/// 
+/// * `Result<Tz, Errors>` - The timezone, or `Errors::ConversionError` for coordinates out of range or a zone the tz database does not know
+/// 
+/// # Example
+/// 
+/// ```rust
+/// use chrono_tz::Tz;
+/// use timezone_converter::timezone_at;
+/// 
+/// assert_eq!(timezone_at(19.076, 72.8777).unwrap(), Tz::Asia__Kolkata);
+/// assert!(timezone_at(91.0, 0.0).is_err());
+/// ```
+pub fn timezone_at(lat: f64, lon: f64) -> Result<Tz, Errors> {
+    if !(-90.0..=90.0).contains(&lat) || !(-180.0..=180.0).contains(&lon) {
+        return Err(Errors::ConversionError(format!("({}, {}) is not a valid latitude and longitude", lat, lon)));
+    }
+    static FINDER: OnceLock<DefaultFinder> = OnceLock::new();
+    let name = FINDER.get_or_init(DefaultFinder::new).get_tz_name(lon, lat);
+    // The boundary data can be newer than the compiled-in tz database
+    canonicalize(name)
+        .map(|(tz, _)| tz)
+        .map_err(|_| Errors::ConversionError(format!("no known timezone covers ({}, {}): '{}'", lat, lon, name)))
+}
+
+impl TimeZoneConverter {
+    /// Creates a TimeZoneConverter between the timezones in effect at two points on Earth
+    /// 
+    /// # Arguments
+    /// 
+    /// * `source` - The source point as (latitude, longitude) in degrees
+    /// * `target` - The target point as (latitude, longitude) in degrees
+    /// 
+    /// # Returns
+    /// 
+    /// * `Result<TimeZoneConverter, Errors>` - A new TimeZoneConverter instance, or an error from [`timezone_at`]
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use timezone_converter::TimeZoneConverter;
+    /// 
+    /// // From Nairobi to Denver
+    /// let converter = TimeZoneConverter::between_coordinates((-1.2921, 36.8219), (39.7392, -104.9903)).unwrap();
+    /// assert_eq!(converter.get_timezone_info().unwrap().name(), "Africa/Nairobi");
+    /// ```
+    pub fn between_coordinates(source: (f64, f64), target: (f64, f64)) -> Result<Self, Errors> {
+        let source = timezone_at(source.0, source.1)?;
+        let target = timezone_at(target.0, target.1)?;
+        Ok(Self::from_tz(source, target))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_zones_on_land_and_at_sea() {
+        assert_eq!(timezone_at(52.52, 13.405).unwrap(), Tz::Europe__Berlin);
+        assert_eq!(timezone_at(-33.8688, 151.2093).unwrap(), Tz::Australia__Sydney);
+        // The middle of the Atlantic, 30 degrees west of Greenwich
+        assert_eq!(timezone_at(30.0, -30.0).unwrap(), Tz::Etc__GMTPlus2);
+        assert!(timezone_at(f64::NAN, 0.0).is_err());
+        assert!(timezone_at(0.0, 180.5).is_err());
+    }
+
+    #[test]
+    fn converter_between_coordinates() {
+        let converter = TimeZoneConverter::between_coordinates((40.7128, -74.006), (35.6762, 139.6503)).unwrap();
+        assert_eq!(converter.source_tz, Tz::America__New_York);
+        assert_eq!(converter.target_tz, Tz::Asia__Tokyo);
+        assert!(TimeZoneConverter::between_coordinates((0.0, 0.0), (-91.0, 0.0)).is_err());
+    }
+}
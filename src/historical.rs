@@ -0,0 +1,231 @@
+//! Conversions of historical dates, flagging those the tz database cannot vouch for
+
+use std::fmt;
+
+use chrono::{DateTime, Duration, NaiveDateTime, Offset, TimeZone as ChronoTimeZone, Utc};
+
+use crate::{is_fixed_offset, resolve_local, Errors, TimeZoneConverter, Zone, ZoneSpec, RELIABLE_SINCE_YEAR};
+
+/// A converter for historical dates, which reports when a result may not match the clocks
+/// of the time
+/// 
+/// Conversions are the same as [`TimeZoneConverter::convert`]; each result also lists a
+/// [`HistoricalUncertainty`] for every timezone that was on Local Mean Time at that instant,
+/// or for which the instant predates [`RELIABLE_SINCE_YEAR`]. Fixed offsets, POSIX rules
+/// and custom zones follow their own definitions at every instant, so they are never in doubt.
+/// 
+/// # Example
+/// 
+/// ```rust
+/// use chrono::NaiveDate;
+/// use timezone_converter::{HistoricalConverter, HistoricalUncertainty, Zone};
+/// 
+/// let converter = HistoricalConverter::new("America/New_York", "Europe/London").unwrap();
+/// let ledger = NaiveDate::from_ymd_opt(1880, 6, 1).unwrap().and_hms_opt(12, 0, 0).unwrap();
+/// let result = converter.convert_local(ledger).unwrap();
+/// assert_eq!(result.datetime.to_string(), "1880-06-01 16:56:02 GMT");
+/// assert!(result.uncertainties.contains(&HistoricalUncertainty::LocalMeanTime {
+///     zone: Zone::Source,
+///     offset: chrono::Duration::seconds(-(4 * 3600 + 56 * 60 + 2)),
+/// }));
+/// ```
+#[derive(Debug)]
+pub struct HistoricalConverter {
+    /// The converter doing the conversions
+    converter: TimeZoneConverter,
+}
+
+/// The outcome of a [`HistoricalConverter`] conversion
+#[derive(Debug, Clone, PartialEq)]
+pub struct HistoricalConversion {
+    /// The converted datetime in the target timezone
+    pub datetime: DateTime<ZoneSpec>,
+    /// Why the result may not match the clocks of the time, source first; empty when there is no doubt
+    pub uncertainties: Vec<HistoricalUncertainty>,
+}
+
+/// A reason a historical conversion may not match the clocks of the time
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HistoricalUncertainty {
+    /// The zone was on Local Mean Time, its city's solar time, before standard time was adopted
+    LocalMeanTime {
+        /// Which of the converter's zones
+        zone: Zone,
+        /// The Local Mean Time offset from UTC, which is rarely a whole number of minutes
+        offset: Duration,
+    },
+    /// The instant predates [`RELIABLE_SINCE_YEAR`], so the zone's recorded offset may be wrong
+    BeforeReliableData {
+        /// Which of the converter's zones
+        zone: Zone,
+    },
+}
+
+impl HistoricalConverter {
+    /// Creates a new HistoricalConverter instance
+    /// 
+    /// # Arguments
+    /// 
+    /// * `source` - The source timezone identifier (e.g., "America/New_York")
+    /// * `target` - The target timezone identifier (e.g., "Europe/London")
+    /// 
+    /// # Returns
+    /// 
+    /// * `Result<HistoricalConverter, Errors>` - A new HistoricalConverter instance or an error
+    pub fn new(source: &str, target: &str) -> Result<Self, Errors> {
+        TimeZoneConverter::new(source, target).map(Self::from)
+    }
+
+    /// Gets the converter doing the conversions
+    pub fn converter(&self) -> &TimeZoneConverter {
+        &self.converter
+    }
+
+    /// Converts a datetime to the target timezone
+    /// 
+    /// # Arguments
+    /// 
+    /// * `datetime` - The datetime to convert
+    /// 
+    /// # Returns
+    /// 
+    /// * `HistoricalConversion` - The converted datetime and any reasons to doubt it
+    pub fn convert<T: ChronoTimeZone>(&self, datetime: DateTime<T>) -> HistoricalConversion {
+        let instant = datetime.with_timezone(&Utc);
+        let uncertainties = [Zone::Source, Zone::Target]
+            .into_iter()
+            .flat_map(|zone| self.uncertainties(instant, zone))
+            .collect();
+        HistoricalConversion { datetime: instant.with_timezone(&self.converter.target_tz), uncertainties }
+    }
+
+    /// Converts a source-local wall-clock time, such as one from an archival record, to the
+    /// target timezone
+    /// 
+    /// Times skipped or repeated by a transition are resolved with the converter's
+    /// [`crate::AmbiguityPolicy`].
+    /// 
+    /// # Arguments
+    /// 
+    /// * `naive` - The wall-clock time in the source timezone
+    /// 
+    /// # Returns
+    /// 
+    /// * `Result<HistoricalConversion, Errors>` - The converted datetime and any reasons to doubt it, or an error if the time cannot be resolved
+    pub fn convert_local(&self, naive: NaiveDateTime) -> Result<HistoricalConversion, Errors> {
+        let converter = &self.converter;
+        resolve_local(&converter.source_tz, naive, converter.ambiguity_policy).map(|datetime| self.convert(datetime))
+    }
+
+    /// Lists what is doubtful about a zone's offset at an instant
+    fn uncertainties(&self, instant: DateTime<Utc>, zone: Zone) -> Vec<HistoricalUncertainty> {
+        let tz = match zone {
+            Zone::Source => &self.converter.source_tz,
+            Zone::Target => &self.converter.target_tz,
+        };
+        // Only zones built from the tz database have a recorded history to doubt
+        let recorded = match tz {
+            ZoneSpec::Named(_) => true,
+            #[cfg(feature = "system-tz")]
+            ZoneSpec::System(_) => true,
+            _ => false,
+        };
+        if !recorded || is_fixed_offset(tz) {
+            return Vec::new();
+        }
+        let mut found = Vec::new();
+        let offset = *instant.with_timezone(tz).offset();
+        if offset.abbreviation() == Some("LMT") {
+            let offset = Duration::seconds(offset.fix().local_minus_utc() as i64);
+            found.push(HistoricalUncertainty::LocalMeanTime { zone, offset });
+        }
+        if instant < reliable_since() {
+            found.push(HistoricalUncertainty::BeforeReliableData { zone });
+        }
+        found
+    }
+}
+
+impl HistoricalConversion {
+    /// Whether the result is in any doubt
+    pub fn is_uncertain(&self) -> bool {
+        !self.uncertainties.is_empty()
+    }
+}
+
+impl From<TimeZoneConverter> for HistoricalConverter {
+    fn from(converter: TimeZoneConverter) -> Self {
+        Self { converter }
+    }
+}
+
+impl fmt::Display for HistoricalUncertainty {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let side = |zone: &Zone| match zone {
+            Zone::Source => "source",
+            Zone::Target => "target",
+        };
+        match self {
+            Self::LocalMeanTime { zone, offset } => {
+                let seconds = offset.num_seconds();
+                let sign = if seconds < 0 { '-' } else { '+' };
+                let seconds = seconds.abs();
+                write!(
+                    f,
+                    "the {} timezone was on Local Mean Time ({}{:02}:{:02}:{:02})",
+                    side(zone),
+                    sign,
+                    seconds / 3600,
+                    seconds % 3600 / 60,
+                    seconds % 60
+                )
+            }
+            Self::BeforeReliableData { zone } => {
+                write!(f, "the {} timezone's offsets before {} may be inaccurate", side(zone), RELIABLE_SINCE_YEAR)
+            }
+        }
+    }
+}
+
+/// The first instant of [`RELIABLE_SINCE_YEAR`]
+fn reliable_since() -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(RELIABLE_SINCE_YEAR, 1, 1, 0, 0, 0).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{FixedOffset, NaiveDate};
+    use chrono_tz::Tz;
+
+    #[test]
+    fn flags_local_mean_time_and_early_data() {
+        let converter = HistoricalConverter::new("Europe/London", "Asia/Kolkata").unwrap();
+        let at = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap().and_hms_opt(12, 0, 0).unwrap();
+
+        let result = converter.convert_local(at(1925, 3, 1)).unwrap();
+        assert_eq!(result.datetime.to_string(), "1925-03-01 17:30:00 IST");
+        assert_eq!(
+            result.uncertainties,
+            [
+                HistoricalUncertainty::BeforeReliableData { zone: Zone::Source },
+                HistoricalUncertainty::BeforeReliableData { zone: Zone::Target },
+            ]
+        );
+
+        let result = converter.convert_local(at(1840, 3, 1)).unwrap();
+        assert_eq!(result.uncertainties[0], HistoricalUncertainty::LocalMeanTime { zone: Zone::Source, offset: Duration::seconds(-75) });
+        assert_eq!(result.uncertainties[0].to_string(), "the source timezone was on Local Mean Time (-00:01:15)");
+        assert!(matches!(result.uncertainties[2], HistoricalUncertainty::LocalMeanTime { zone: Zone::Target, .. }));
+
+        assert!(!converter.convert_local(at(2024, 3, 1)).unwrap().is_uncertain());
+    }
+
+    #[test]
+    fn fixed_zones_are_never_uncertain() {
+        let converter = HistoricalConverter::from(TimeZoneConverter::from_tz(FixedOffset::east_opt(3600).unwrap(), Tz::UTC));
+        let result = converter.convert(Utc.with_ymd_and_hms(1850, 1, 1, 0, 0, 0).unwrap());
+        assert!(!result.is_uncertain());
+        assert_eq!(result.datetime.to_string(), "1850-01-01 00:00:00 UTC");
+    }
+}
@@ -0,0 +1,258 @@
+//! Bundled national holiday calendars for business-day computations
+
+use chrono::{Datelike, Duration, NaiveDate, Weekday};
+
+use crate::{tab_rows, Holidays};
+
+/// Nationwide public holidays, one row per holiday
+const HOLIDAY_TAB: &str = include_str!("../data/holidays.tab");
+
+/// The public holidays observed across a whole country
+/// 
+/// Calendars cover Australia (`AU`), Canada (`CA`, holidays of every province as well as
+/// federal ones), France (`FR`), Germany (`DE`), the United Kingdom (`GB`, following England
+/// and Wales) and the United States (`US`, federal holidays). Holidays that fall on a
+/// weekend are moved to the weekday they are observed on, as each country does. Regional
+/// holidays, such as those of a single German state or Canadian province, and one-off
+/// holidays are not included, and each holiday counts from the year it was first observed
+/// on its current date.
+/// 
+/// # Example
+/// 
+/// ```rust
+/// use chrono::{NaiveDate, TimeZone, Utc};
+/// use timezone_converter::{is_business_day_in, HolidayCalendar, Holidays};
+/// 
+/// let us = HolidayCalendar::for_country("us").unwrap();
+/// let observed = NaiveDate::from_ymd_opt(2026, 7, 3).unwrap();
+/// assert_eq!(us.holiday_name(observed), Some("Independence Day"));
+/// assert!(us.is_holiday(observed));
+/// 
+/// let thanksgiving = Utc.with_ymd_and_hms(2024, 11, 28, 15, 0, 0).unwrap();
+/// assert!(!is_business_day_in("America/Chicago", thanksgiving, &us).unwrap());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HolidayCalendar {
+    /// The country's ISO 3166 alpha-2 code (e.g., "US")
+    country: &'static str,
+    /// The rows of the holidays the country observes
+    rows: Vec<Vec<&'static str>>,
+}
+
+/// What happens to a holiday that falls on a weekend
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Observance {
+    /// It stays on the weekend
+    Weekend,
+    /// Saturday moves to Friday and Sunday to Monday
+    Nearest,
+    /// It moves to the next weekday that is not already a holiday
+    Next,
+}
+
+impl HolidayCalendar {
+    /// Gets the calendar of a country
+    /// 
+    /// # Arguments
+    /// 
+    /// * `country` - The country's ISO 3166 alpha-2 code, in any case (e.g., "GB")
+    /// 
+    /// # Returns
+    /// 
+    /// * `Option<HolidayCalendar>` - The calendar, or `None` if the country is not bundled
+    pub fn for_country(country: &str) -> Option<Self> {
+        let rows: Vec<_> = tab_rows(HOLIDAY_TAB).filter(|fields| fields[0].eq_ignore_ascii_case(country.trim())).collect();
+        Some(Self { country: rows.first()?[0], rows })
+    }
+
+    /// Lists the countries with bundled calendars, in alphabetical order
+    pub fn countries() -> Vec<&'static str> {
+        let mut countries: Vec<_> = tab_rows(HOLIDAY_TAB).map(|fields| fields[0]).collect();
+        countries.dedup();
+        countries
+    }
+
+    /// Gets the country's ISO 3166 alpha-2 code (e.g., "US")
+    pub fn country(&self) -> &'static str {
+        self.country
+    }
+
+    /// Lists the holidays observed in a year
+    /// 
+    /// A holiday observed on another day because it falls on a weekend is listed on the
+    /// day it is observed, which for New Year's Day can be in the previous year.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `year` - The year the holidays fall in
+    /// 
+    /// # Returns
+    /// 
+    /// * `Vec<(NaiveDate, &'static str)>` - Each observed date with the holiday's name, in date order
+    pub fn holidays_in(&self, year: i32) -> Vec<(NaiveDate, &'static str)> {
+        let dates: Vec<_> = self
+            .rows
+            .iter()
+            .filter(|fields| fields[1] == "-" || fields[1].parse().is_ok_and(|since: i32| since <= year))
+            .filter_map(|fields| Some((rule_date(fields[2], year)?, observance(fields[3]), fields[4])))
+            .collect();
+
+        // Holidays on weekdays keep their dates, so weekend ones moved to the next free weekday skip them
+        let is_weekend = |date: NaiveDate| matches!(date.weekday(), Weekday::Sat | Weekday::Sun);
+        let mut observed: Vec<_> = dates
+            .iter()
+            .filter(|(date, observance, _)| !is_weekend(*date) || *observance == Observance::Weekend)
+            .map(|(date, _, name)| (*date, *name))
+            .collect();
+        for (date, observance, name) in dates {
+            let moved = match (observance, date.weekday()) {
+                (Observance::Nearest, Weekday::Sat) => date - Duration::days(1),
+                (Observance::Nearest, Weekday::Sun) => date + Duration::days(1),
+                (Observance::Next, Weekday::Sat | Weekday::Sun) => {
+                    let mut moved = date + Duration::days(1);
+                    while is_weekend(moved) || observed.iter().any(|(taken, _)| *taken == moved) {
+                        moved += Duration::days(1);
+                    }
+                    moved
+                }
+                _ => continue,
+            };
+            observed.push((moved, name));
+        }
+        observed.sort();
+        observed
+    }
+
+    /// Gets the name of the holiday observed on a date
+    /// 
+    /// # Arguments
+    /// 
+    /// * `date` - The local calendar date
+    /// 
+    /// # Returns
+    /// 
+    /// * `Option<&'static str>` - The holiday's name, or `None` if the date is not a holiday
+    pub fn holiday_name(&self, date: NaiveDate) -> Option<&'static str> {
+        // Holidays of the next year can be observed at the end of this one
+        [date.year(), date.year() + 1]
+            .into_iter()
+            .flat_map(|year| self.holidays_in(year))
+            .find(|(observed, _)| *observed == date)
+            .map(|(_, name)| name)
+    }
+}
+
+impl Holidays for HolidayCalendar {
+    fn is_holiday(&self, date: NaiveDate) -> bool {
+        self.holiday_name(date).is_some()
+    }
+}
+
+/// Reads an observance column
+fn observance(field: &str) -> Observance {
+    match field {
+        "nearest" => Observance::Nearest,
+        "next" => Observance::Next,
+        _ => Observance::Weekend,
+    }
+}
+
+/// Finds the date a holiday rule gives in a year: `MM-DD`, `MM/N/Day` (with `L` for the
+/// last), `MM-DD/<Day` or `easter±N`
+fn rule_date(rule: &str, year: i32) -> Option<NaiveDate> {
+    if let Some(days) = rule.strip_prefix("easter") {
+        return easter(year)?.checked_add_signed(Duration::days(days.parse().ok()?));
+    }
+    if let Some((date, weekday)) = rule.split_once("/<") {
+        let date = month_day(date, year)?;
+        let back = (7 + date.weekday().num_days_from_monday() - weekday.parse::<Weekday>().ok()?.num_days_from_monday()) % 7;
+        return date.checked_sub_signed(Duration::days(back.into()));
+    }
+    if let [month, week, weekday] = rule.split('/').collect::<Vec<_>>()[..] {
+        let (month, weekday) = (month.parse().ok()?, weekday.parse::<Weekday>().ok()?);
+        return match week {
+            "L" => {
+                let next_month = NaiveDate::from_ymd_opt(year, month, 1)?.checked_add_months(chrono::Months::new(1))?;
+                let last = next_month.pred_opt()?;
+                let back = (7 + last.weekday().num_days_from_monday() - weekday.num_days_from_monday()) % 7;
+                last.checked_sub_signed(Duration::days(back.into()))
+            }
+            week => NaiveDate::from_weekday_of_month_opt(year, month, weekday, week.parse().ok()?),
+        };
+    }
+    month_day(rule, year)
+}
+
+/// Reads a `MM-DD` date in a year
+fn month_day(s: &str, year: i32) -> Option<NaiveDate> {
+    let (month, day) = s.split_once('-')?;
+    NaiveDate::from_ymd_opt(year, month.parse().ok()?, day.parse().ok()?)
+}
+
+/// Finds Western Easter Sunday in a year, with the anonymous Gregorian algorithm
+fn easter(year: i32) -> Option<NaiveDate> {
+    let a = year.rem_euclid(19);
+    let (b, c) = (year.div_euclid(100), year.rem_euclid(100));
+    let (d, e) = (b / 4, b % 4);
+    let f = (b + 8) / 25;
+    let g = (b - f + 1) / 3;
+    let h = (19 * a + b - d - g + 15) % 30;
+    let (i, k) = (c / 4, c % 4);
+    let l = (32 + 2 * e + 2 * i - h - k) % 7;
+    let m = (a + 11 * h + 22 * l) / 451;
+    let month = (h + l - 7 * m + 114) / 31;
+    let day = (h + l - 7 * m + 114) % 31 + 1;
+    NaiveDate::from_ymd_opt(year, month as u32, day as u32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn computes_observed_holidays() {
+        assert_eq!(HolidayCalendar::countries(), ["AU", "CA", "DE", "FR", "GB", "US"]);
+        assert!(HolidayCalendar::for_country("XX").is_none());
+        assert_eq!(easter(2024), Some(date(2024, 3, 31)));
+        assert_eq!(easter(2025), Some(date(2025, 4, 20)));
+
+        let gb = HolidayCalendar::for_country("GB").unwrap();
+        let days: Vec<_> = gb.holidays_in(2023).into_iter().map(|(date, _)| date.format("%m-%d").to_string()).collect();
+        assert_eq!(days, ["01-02", "04-07", "04-10", "05-01", "05-29", "08-28", "12-25", "12-26"]);
+        // Christmas Day on a Sunday moves past Boxing Day
+        assert_eq!(gb.holiday_name(date(2022, 12, 26)), Some("Boxing Day"));
+        assert_eq!(gb.holiday_name(date(2022, 12, 27)), Some("Christmas Day"));
+        let days: Vec<_> = gb.holidays_in(2021).into_iter().map(|(date, _)| date.format("%m-%d").to_string()).collect();
+        assert_eq!(days, ["01-01", "04-02", "04-05", "05-03", "05-31", "08-30", "12-27", "12-28"]);
+
+        let us = HolidayCalendar::for_country("US").unwrap();
+        assert_eq!(us.holiday_name(date(2021, 12, 31)), Some("New Year's Day"));
+        assert_eq!(us.holiday_name(date(2024, 1, 15)), Some("Birthday of Martin Luther King, Jr."));
+        assert_eq!(us.holiday_name(date(2020, 6, 19)), None);
+        assert_eq!(us.holiday_name(date(2024, 6, 19)), Some("Juneteenth National Independence Day"));
+
+        assert_eq!(us.holiday_name(date(1985, 1, 21)), None);
+
+        // Victoria Day is not observed in every province
+        let ca = HolidayCalendar::for_country("CA").unwrap();
+        assert_eq!(ca.holiday_name(date(2024, 5, 20)), None);
+        assert_eq!(ca.holiday_name(date(2023, 7, 3)), Some("Canada Day"));
+        assert_eq!(ca.holidays_in(2024).len(), 5);
+
+        let de = HolidayCalendar::for_country("DE").unwrap();
+        assert_eq!(de.holiday_name(date(1989, 10, 3)), None);
+        assert_eq!(de.holiday_name(date(1990, 10, 3)), Some("German Unity Day"));
+    }
+
+    #[test]
+    fn every_rule_gives_a_date() {
+        for fields in tab_rows(HOLIDAY_TAB) {
+            assert_eq!(fields.len(), 5, "{:?}", fields);
+            assert!(rule_date(fields[2], 2024).is_some(), "{:?}", fields);
+        }
+    }
+}
@@ -0,0 +1,218 @@
+//! Human-readable descriptions of instants relative to the current time
+
+use chrono::{DateTime, Duration, NaiveDate, NaiveTime, TimeZone as ChronoTimeZone, Utc};
+
+use crate::TimeZoneConverter;
+
+/// The unit a relative time is counted in
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimeUnit {
+    /// Minutes, for differences under an hour
+    Minutes,
+    /// Hours, for differences under a day
+    Hours,
+    /// Days, for everything longer
+    Days,
+}
+
+/// The target-local day an instant falls on, relative to the current target-local day
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RelativeDay {
+    /// The day before today
+    Yesterday,
+    /// The current day
+    Today,
+    /// The day after today
+    Tomorrow,
+    /// Any other day
+    Other(NaiveDate),
+}
+
+/// The phrases used by [`TimeZoneConverter::humanize_with`], so applications can localize them
+pub trait HumanizePhrases {
+    /// Describes an instant less than a minute away in either direction (e.g., "just now")
+    fn now(&self) -> String;
+
+    /// Describes how far away an instant is (e.g., "in 3 hours" or "2 days ago")
+    fn relative(&self, count: i64, unit: TimeUnit, future: bool) -> String;
+
+    /// Describes an instant's target-local day and time (e.g., "tomorrow 09:00 local time")
+    fn local_time(&self, day: RelativeDay, time: NaiveTime) -> String;
+
+    /// Joins the relative and local descriptions
+    fn combine(&self, relative: String, local: String) -> String {
+        format!("{} ({})", relative, local)
+    }
+}
+
+/// The default English phrases
+#[derive(Debug, Clone, Copy, Default)]
+pub struct EnglishPhrases;
+
+impl HumanizePhrases for EnglishPhrases {
+    fn now(&self) -> String {
+        "just now".to_string()
+    }
+
+    fn relative(&self, count: i64, unit: TimeUnit, future: bool) -> String {
+        let unit = match (unit, count == 1) {
+            (TimeUnit::Minutes, true) => "minute",
+            (TimeUnit::Minutes, false) => "minutes",
+            (TimeUnit::Hours, true) => "hour",
+            (TimeUnit::Hours, false) => "hours",
+            (TimeUnit::Days, true) => "day",
+            (TimeUnit::Days, false) => "days",
+        };
+        if future {
+            format!("in {} {}", count, unit)
+        } else {
+            format!("{} {} ago", count, unit)
+        }
+    }
+
+    fn local_time(&self, day: RelativeDay, time: NaiveTime) -> String {
+        let time = time.format("%H:%M");
+        match day {
+            RelativeDay::Yesterday => format!("yesterday {} local time", time),
+            RelativeDay::Today => format!("today {} local time", time),
+            RelativeDay::Tomorrow => format!("tomorrow {} local time", time),
+            RelativeDay::Other(date) => format!("{} {} local time", date.format("%a %-d %b"), time),
+        }
+    }
+}
+
+impl TimeZoneConverter {
+    /// Describes an instant relative to the current time, with its wall-clock time in the target timezone
+    /// 
+    /// Differences are truncated to whole minutes, hours or days, and the day is named
+    /// relative to the current day in the target timezone.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `dt` - The instant to describe, in any timezone
+    /// 
+    /// # Returns
+    /// 
+    /// * `String` - A description such as "in 3 hours (tomorrow 09:00 local time)"
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use chrono::{TimeZone, Utc};
+    /// use timezone_converter::{FixedClock, TimeZoneConverter};
+    /// 
+    /// let now = Utc.with_ymd_and_hms(2024, 7, 1, 21, 0, 0).unwrap();
+    /// let converter = TimeZoneConverter::new("UTC", "Europe/Berlin").unwrap().with_clock(FixedClock::new(now));
+    /// let meeting = Utc.with_ymd_and_hms(2024, 7, 2, 0, 30, 0).unwrap();
+    /// assert_eq!(converter.humanize(meeting), "in 3 hours (tomorrow 02:30 local time)");
+    /// ```
+    pub fn humanize<T: ChronoTimeZone>(&self, dt: DateTime<T>) -> String {
+        self.humanize_with(dt, &EnglishPhrases)
+    }
+
+    /// Describes an instant like [`Self::humanize`], using custom phrases
+    /// 
+    /// # Arguments
+    /// 
+    /// * `dt` - The instant to describe, in any timezone
+    /// * `phrases` - The phrases to build the description from
+    /// 
+    /// # Returns
+    /// 
+    /// * `String` - The description
+    pub fn humanize_with<T: ChronoTimeZone>(&self, dt: DateTime<T>, phrases: &dyn HumanizePhrases) -> String {
+        self.humanize_at(self.clock.now(), dt.with_timezone(&Utc), phrases)
+    }
+
+    /// Implements [`Self::humanize_with`] for an arbitrary current time
+    fn humanize_at(&self, now: DateTime<Utc>, at: DateTime<Utc>, phrases: &dyn HumanizePhrases) -> String {
+        let delta = at - now;
+        let future = delta > Duration::zero();
+        let minutes = delta.num_minutes().abs();
+        let relative = if minutes == 0 {
+            phrases.now()
+        } else if minutes < 60 {
+            phrases.relative(minutes, TimeUnit::Minutes, future)
+        } else if minutes < 24 * 60 {
+            phrases.relative(minutes / 60, TimeUnit::Hours, future)
+        } else {
+            phrases.relative(minutes / (24 * 60), TimeUnit::Days, future)
+        };
+
+        let today = now.with_timezone(&self.target_tz).date_naive();
+        let local = at.with_timezone(&self.target_tz);
+        let date = local.date_naive();
+        let day = if Some(date) == today.pred_opt() {
+            RelativeDay::Yesterday
+        } else if date == today {
+            RelativeDay::Today
+        } else if Some(date) == today.succ_opt() {
+            RelativeDay::Tomorrow
+        } else {
+            RelativeDay::Other(date)
+        };
+
+        phrases.combine(relative, phrases.local_time(day, local.time()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// German phrases, to check every hook is used
+    struct German;
+
+    impl HumanizePhrases for German {
+        fn now(&self) -> String {
+            "gerade eben".to_string()
+        }
+
+        fn relative(&self, count: i64, unit: TimeUnit, future: bool) -> String {
+            let unit = match unit {
+                TimeUnit::Minutes => "Min.",
+                TimeUnit::Hours => "Std.",
+                TimeUnit::Days => "Tagen",
+            };
+            if future { format!("in {} {}", count, unit) } else { format!("vor {} {}", count, unit) }
+        }
+
+        fn local_time(&self, day: RelativeDay, time: NaiveTime) -> String {
+            let day = match day {
+                RelativeDay::Yesterday => "gestern".to_string(),
+                RelativeDay::Today => "heute".to_string(),
+                RelativeDay::Tomorrow => "morgen".to_string(),
+                RelativeDay::Other(date) => date.format("%d.%m.").to_string(),
+            };
+            format!("{} um {} Ortszeit", day, time.format("%H:%M"))
+        }
+
+        fn combine(&self, relative: String, local: String) -> String {
+            format!("{}, {}", relative, local)
+        }
+    }
+
+    #[test]
+    fn english_descriptions() {
+        let timezone = TimeZoneConverter::new("UTC", "America/New_York").unwrap();
+        let now = Utc.with_ymd_and_hms(2024, 7, 1, 12, 0, 0).unwrap();
+        let describe = |delta: Duration| timezone.humanize_at(now, now + delta, &EnglishPhrases);
+
+        assert_eq!(describe(Duration::seconds(20)), "just now (today 08:00 local time)");
+        assert_eq!(describe(Duration::minutes(1)), "in 1 minute (today 08:01 local time)");
+        assert_eq!(describe(Duration::minutes(-150)), "2 hours ago (today 05:30 local time)");
+        assert_eq!(describe(Duration::hours(-13)), "13 hours ago (yesterday 19:00 local time)");
+        assert_eq!(describe(Duration::days(3)), "in 3 days (Thu 4 Jul 08:00 local time)");
+    }
+
+    #[test]
+    fn custom_phrases() {
+        let timezone = TimeZoneConverter::new("UTC", "Europe/Berlin").unwrap();
+        let now = Utc.with_ymd_and_hms(2024, 7, 1, 20, 0, 0).unwrap();
+        assert_eq!(
+            timezone.humanize_at(now, now + Duration::hours(5), &German),
+            "in 5 Std., morgen um 03:00 Ortszeit"
+        );
+        assert_eq!(timezone.humanize_at(now, now, &German), "gerade eben, heute um 22:00 Ortszeit");
+    }
+}
@@ -0,0 +1,494 @@
+//! iCalendar (RFC 5545) support: expanding recurrence rules and exporting VTIMEZONE components
+
+use std::fmt::Write;
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+
+use chrono::{DateTime, Datelike, Duration, Months, NaiveDate, NaiveDateTime, NaiveTime, Offset, Utc, Weekday};
+
+use chrono_tz::Tz;
+
+use crate::{is_dst_at, resolve_local_forward, Errors, TimeZoneConverter, Zone};
+
+/// How often a recurrence rule repeats
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Frequency {
+    /// Every `INTERVAL` days
+    Daily,
+    /// Every `INTERVAL` weeks
+    Weekly,
+    /// Every `INTERVAL` months
+    Monthly,
+    /// Every `INTERVAL` years
+    Yearly,
+}
+
+/// A weekday in a `BYDAY` list, optionally with an ordinal such as the `-1` in `-1FR` (the last Friday)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ByDay {
+    /// The nth such weekday of the month, counting from the end if negative
+    pub ordinal: Option<i8>,
+    /// The weekday
+    pub weekday: Weekday,
+}
+
+/// The bound set by a rule's `UNTIL` part
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Until {
+    /// A date; occurrences on that local date are included
+    Date(NaiveDate),
+    /// A local date-time in the timezone the rule is expanded in
+    Local(NaiveDateTime),
+    /// A UTC instant (written with a trailing `Z`)
+    Utc(DateTime<Utc>),
+}
+
+/// A parsed `RRULE` value supporting the `FREQ`, `INTERVAL`, `BYDAY`, `UNTIL`, `COUNT` and `WKST` parts
+/// 
+/// # Example
+/// 
+/// ```rust
+/// use timezone_converter::{Frequency, RRule};
+/// 
+/// let rule: RRule = "RRULE:FREQ=WEEKLY;BYDAY=TU,TH;COUNT=4".parse().unwrap();
+/// assert_eq!(rule.frequency, Frequency::Weekly);
+/// assert_eq!(rule.count, Some(4));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RRule {
+    /// How often the rule repeats
+    pub frequency: Frequency,
+    /// How many frequency periods separate each repetition
+    pub interval: u32,
+    /// The weekdays the rule is limited to or expanded over; empty if not given
+    pub by_day: Vec<ByDay>,
+    /// The last time an occurrence may fall on
+    pub until: Option<Until>,
+    /// The total number of occurrences, including the first
+    pub count: Option<u32>,
+    /// The day weekly rules start their weeks on
+    pub week_start: Weekday,
+}
+
+impl FromStr for RRule {
+    type Err = Errors;
+
+    fn from_str(s: &str) -> Result<Self, Errors> {
+        let value = s.trim();
+        let value = value.strip_prefix("RRULE:").unwrap_or(value);
+
+        let mut frequency = None;
+        let mut rule = RRule {
+            frequency: Frequency::Daily,
+            interval: 1,
+            by_day: Vec::new(),
+            until: None,
+            count: None,
+            week_start: Weekday::Mon,
+        };
+        for part in value.split(';').filter(|part| !part.is_empty()) {
+            let (name, value) = part
+                .split_once('=')
+                .ok_or_else(|| invalid(s, &format!("'{}' is not NAME=VALUE", part)))?;
+            match name.to_ascii_uppercase().as_str() {
+                "FREQ" => frequency = Some(parse_frequency(value).ok_or_else(|| invalid(s, "unsupported FREQ"))?),
+                "INTERVAL" => {
+                    rule.interval = value.parse().ok().filter(|interval| *interval > 0)
+                        .ok_or_else(|| invalid(s, "INTERVAL must be a positive integer"))?;
+                }
+                "BYDAY" => {
+                    rule.by_day = value.split(',').map(parse_by_day).collect::<Option<_>>()
+                        .ok_or_else(|| invalid(s, "invalid BYDAY"))?;
+                }
+                "UNTIL" => rule.until = Some(parse_until(value).ok_or_else(|| invalid(s, "invalid UNTIL"))?),
+                "COUNT" => rule.count = Some(value.parse().map_err(|_| invalid(s, "COUNT must be an integer"))?),
+                "WKST" => rule.week_start = parse_weekday(value).ok_or_else(|| invalid(s, "invalid WKST"))?,
+                other => return Err(invalid(s, &format!("{} is not supported", other))),
+            }
+        }
+
+        rule.frequency = frequency.ok_or_else(|| invalid(s, "FREQ is required"))?;
+        if rule.until.is_some() && rule.count.is_some() {
+            return Err(invalid(s, "UNTIL and COUNT cannot both be given"));
+        }
+        let has_ordinals = rule.by_day.iter().any(|day| day.ordinal.is_some());
+        if has_ordinals && rule.frequency != Frequency::Monthly {
+            return Err(invalid(s, "BYDAY ordinals are only supported with FREQ=MONTHLY"));
+        }
+        if !rule.by_day.is_empty() && rule.frequency == Frequency::Yearly {
+            return Err(invalid(s, "BYDAY is not supported with FREQ=YEARLY"));
+        }
+        Ok(rule)
+    }
+}
+
+impl RRule {
+    /// Expands the rule from a start time into local date-times, in order
+    /// 
+    /// The start always counts as the first occurrence, as RFC 5545 requires.
+    fn local_occurrences(&self, dtstart: NaiveDateTime) -> impl Iterator<Item = NaiveDateTime> + '_ {
+        let start_date = dtstart.date();
+        let time = dtstart.time();
+        let periods = (0u32..).map_while(move |period| period.checked_mul(self.interval));
+
+        let candidates = periods
+            .map_while(move |offset| self.period_dates(start_date, offset))
+            .flatten()
+            .filter(move |date| *date > start_date)
+            .map(move |date| date.and_time(time));
+        std::iter::once(dtstart).chain(candidates)
+    }
+
+    /// Lists the dates of the period `offset` frequency units after the one containing `start`,
+    /// or `None` once the calendar runs out
+    fn period_dates(&self, start: NaiveDate, offset: u32) -> Option<Vec<NaiveDate>> {
+        let matches_by_day = |date: &NaiveDate| {
+            self.by_day.is_empty() || self.by_day.iter().any(|day| day.weekday == date.weekday())
+        };
+
+        Some(match self.frequency {
+            Frequency::Daily => {
+                let date = start.checked_add_signed(Duration::try_days(offset as i64)?)?;
+                if matches_by_day(&date) { vec![date] } else { Vec::new() }
+            }
+            Frequency::Weekly => {
+                let days_into_week = start.weekday().days_since(self.week_start) as i64;
+                let week_start = start.checked_add_signed(Duration::try_days(offset as i64 * 7 - days_into_week)?)?;
+                let week: Vec<NaiveDate> = week_start.iter_days().take(7).collect();
+                if self.by_day.is_empty() {
+                    week.into_iter().filter(|date| date.weekday() == start.weekday()).collect()
+                } else {
+                    week.into_iter().filter(matches_by_day).collect()
+                }
+            }
+            Frequency::Monthly => {
+                let first = start.with_day(1)?.checked_add_months(Months::new(offset))?;
+                if self.by_day.is_empty() {
+                    first.with_day(start.day()).into_iter().collect()
+                } else {
+                    let month: Vec<NaiveDate> = first.iter_days().take_while(|date| date.month() == first.month()).collect();
+                    month.iter().copied().filter(|date| self.monthly_day_matches(&month, *date)).collect()
+                }
+            }
+            Frequency::Yearly => {
+                let year = start.year().checked_add(i32::try_from(offset).ok()?)?;
+                if year > 9999 {
+                    return None;
+                }
+                NaiveDate::from_ymd_opt(year, start.month(), start.day()).into_iter().collect()
+            }
+        })
+    }
+
+    /// Whether a date in `month` is selected by a monthly rule's `BYDAY` list
+    fn monthly_day_matches(&self, month: &[NaiveDate], date: NaiveDate) -> bool {
+        self.by_day.iter().any(|day| {
+            if day.weekday != date.weekday() {
+                return false;
+            }
+            let Some(ordinal) = day.ordinal else {
+                return true;
+            };
+            let same_weekday: Vec<&NaiveDate> = month.iter().filter(|other| other.weekday() == day.weekday).collect();
+            let index = if ordinal > 0 {
+                ordinal as usize - 1
+            } else {
+                match same_weekday.len().checked_sub(ordinal.unsigned_abs() as usize) {
+                    Some(index) => index,
+                    None => return false,
+                }
+            };
+            same_weekday.get(index) == Some(&&date)
+        })
+    }
+
+    /// Whether an occurrence is past the rule's `UNTIL` bound
+    fn is_past_until(&self, local: NaiveDateTime, instant: DateTime<Utc>) -> bool {
+        match self.until {
+            Some(Until::Date(date)) => local.date() > date,
+            Some(Until::Local(until)) => local > until,
+            Some(Until::Utc(until)) => instant > until,
+            None => false,
+        }
+    }
+}
+
+impl TimeZoneConverter {
+    /// Expands an iCalendar recurrence rule starting at a source-local time and converts each occurrence to the target timezone
+    /// 
+    /// Occurrences keep their wall-clock time in the source timezone. As RFC 5545 specifies,
+    /// a time skipped by DST uses the offset from before the gap (which shifts it forward)
+    /// and a repeated time uses its first occurrence. Expansion stops at the rule's `COUNT`
+    /// or `UNTIL`, or after `limit` occurrences, whichever comes first.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `rule` - The recurrence rule
+    /// * `dtstart` - The source-local start of the series, which is always the first occurrence
+    /// * `limit` - The most occurrences to return, for rules without `COUNT` or `UNTIL`
+    /// 
+    /// # Returns
+    /// 
+    /// * `Result<Vec<DateTime<Tz>>, Errors>` - The occurrences in the target timezone, in order
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use timezone_converter::{RRule, TimeZoneConverter};
+    /// 
+    /// let converter = TimeZoneConverter::new("America/New_York", "Europe/London").unwrap();
+    /// let rule: RRule = "FREQ=WEEKLY;BYDAY=TU;COUNT=3".parse().unwrap();
+    /// let start = NaiveDate::from_ymd_opt(2024, 3, 5).unwrap().and_hms_opt(9, 0, 0).unwrap();
+    /// let times: Vec<String> = converter.expand_rrule(&rule, start, 100).unwrap()
+    ///     .iter()
+    ///     .map(|time| time.to_string())
+    ///     .collect();
+    /// assert_eq!(times, ["2024-03-05 14:00:00 GMT", "2024-03-12 13:00:00 GMT", "2024-03-19 13:00:00 GMT"]);
+    /// ```
+    pub fn expand_rrule(&self, rule: &RRule, dtstart: NaiveDateTime, limit: usize) -> Result<Vec<DateTime<Tz>>, Errors> {
+        let target = self.named_tz(Zone::Target)?;
+        let limit = rule.count.map_or(limit, |count| limit.min(count as usize));
+        let mut occurrences = Vec::new();
+        for local in rule.local_occurrences(dtstart) {
+            if occurrences.len() >= limit {
+                break;
+            }
+            let instant = resolve_local_forward(&self.source_tz, local)?;
+            if rule.is_past_until(local, instant.with_timezone(&Utc)) {
+                break;
+            }
+            occurrences.push(instant.with_timezone(&target));
+        }
+        Ok(occurrences)
+    }
+
+    /// Generates an iCalendar `VTIMEZONE` component for the source or target timezone
+    /// 
+    /// Each transition in the years is written as its own `STANDARD` or `DAYLIGHT`
+    /// sub-component, preceded by one describing the offset in effect at the start of the
+    /// range, so the component is valid even for zones without DST. Lines end with CRLF as
+    /// RFC 5545 requires.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `zone` - Whether to describe the source or the target timezone
+    /// * `year_range` - The UTC years to cover, inclusive
+    /// 
+    /// # Returns
+    /// 
+    /// * `String` - The `BEGIN:VTIMEZONE` ... `END:VTIMEZONE` block
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use timezone_converter::{TimeZoneConverter, Zone};
+    /// 
+    /// let converter = TimeZoneConverter::new("America/New_York", "UTC").unwrap();
+    /// let vtimezone = converter.to_vtimezone(Zone::Source, 2024..=2024);
+    /// assert!(vtimezone.starts_with("BEGIN:VTIMEZONE\r\nTZID:America/New_York\r\n"));
+    /// assert!(vtimezone.contains("BEGIN:DAYLIGHT\r\nDTSTART:20240310T020000\r\nTZOFFSETFROM:-0500\r\nTZOFFSETTO:-0400\r\nTZNAME:EDT\r\n"));
+    /// ```
+    pub fn to_vtimezone(&self, zone: Zone, year_range: RangeInclusive<i32>) -> String {
+        let tz = self.zone(zone);
+        let (first, last) = year_range.into_inner();
+
+        let mut out = String::new();
+        line(&mut out, "BEGIN:VTIMEZONE");
+        line(&mut out, &format!("TZID:{}", tz.name()));
+        if let Some(start) = year_start(first) {
+            let end = last.checked_add(1).and_then(year_start).filter(|end| *end > start);
+            let initial = start.with_timezone(tz);
+            let offset = initial.offset().fix().local_minus_utc();
+            write_observance(&mut out, is_dst_at(tz, start), initial.naive_local(), offset, offset, &initial.format("%Z").to_string());
+            if let Some(end) = end {
+                for transition in self.transitions_between(start, end, zone) {
+                    write_observance(
+                        &mut out,
+                        is_dst_at(tz, transition.instant),
+                        transition.instant.naive_utc() + transition.offset_before,
+                        transition.offset_before.num_seconds() as i32,
+                        transition.offset_after.num_seconds() as i32,
+                        &transition.abbreviation_after,
+                    );
+                }
+            }
+        }
+        line(&mut out, "END:VTIMEZONE");
+        out
+    }
+}
+
+/// Gets midnight UTC on 1 January of a year, if it is representable
+fn year_start(year: i32) -> Option<DateTime<Utc>> {
+    NaiveDate::from_ymd_opt(year, 1, 1).map(|date| date.and_time(NaiveTime::MIN).and_utc())
+}
+
+/// Writes one `STANDARD` or `DAYLIGHT` sub-component starting at a local time in the old offset
+fn write_observance(out: &mut String, daylight: bool, dtstart: NaiveDateTime, from: i32, to: i32, name: &str) {
+    let kind = if daylight { "DAYLIGHT" } else { "STANDARD" };
+    line(out, &format!("BEGIN:{}", kind));
+    line(out, &format!("DTSTART:{}", dtstart.format("%Y%m%dT%H%M%S")));
+    line(out, &format!("TZOFFSETFROM:{}", utc_offset(from)));
+    line(out, &format!("TZOFFSETTO:{}", utc_offset(to)));
+    line(out, &format!("TZNAME:{}", name));
+    line(out, &format!("END:{}", kind));
+}
+
+/// Formats an offset in seconds as an iCalendar UTC-OFFSET (`+HHMM`, or `+HHMMSS` if it has seconds)
+fn utc_offset(seconds: i32) -> String {
+    let sign = if seconds < 0 { '-' } else { '+' };
+    let seconds = seconds.unsigned_abs();
+    let (hours, minutes, seconds) = (seconds / 3600, seconds / 60 % 60, seconds % 60);
+    if seconds == 0 {
+        format!("{}{:02}{:02}", sign, hours, minutes)
+    } else {
+        format!("{}{:02}{:02}{:02}", sign, hours, minutes, seconds)
+    }
+}
+
+/// Appends a content line terminated by CRLF
+fn line(out: &mut String, content: &str) {
+    let _ = write!(out, "{}\r\n", content);
+}
+
+/// Builds the error for an invalid rule
+fn invalid(rule: &str, reason: &str) -> Errors {
+    Errors::ParseError(format!("invalid RRULE '{}': {}", rule, reason))
+}
+
+/// Parses a `FREQ` value
+fn parse_frequency(value: &str) -> Option<Frequency> {
+    match value.to_ascii_uppercase().as_str() {
+        "DAILY" => Some(Frequency::Daily),
+        "WEEKLY" => Some(Frequency::Weekly),
+        "MONTHLY" => Some(Frequency::Monthly),
+        "YEARLY" => Some(Frequency::Yearly),
+        _ => None,
+    }
+}
+
+/// Parses a two-letter iCalendar weekday such as `MO`
+fn parse_weekday(value: &str) -> Option<Weekday> {
+    match value.to_ascii_uppercase().as_str() {
+        "MO" => Some(Weekday::Mon),
+        "TU" => Some(Weekday::Tue),
+        "WE" => Some(Weekday::Wed),
+        "TH" => Some(Weekday::Thu),
+        "FR" => Some(Weekday::Fri),
+        "SA" => Some(Weekday::Sat),
+        "SU" => Some(Weekday::Sun),
+        _ => None,
+    }
+}
+
+/// Parses a `BYDAY` entry such as `TU`, `2MO` or `-1FR`
+fn parse_by_day(value: &str) -> Option<ByDay> {
+    let value = value.trim();
+    let split = value.len().checked_sub(2)?;
+    let (ordinal, weekday) = value.split_at_checked(split)?;
+    let ordinal = match ordinal {
+        "" => None,
+        ordinal => Some(ordinal.parse::<i8>().ok().filter(|n| *n != 0 && n.abs() <= 5)?),
+    };
+    Some(ByDay { ordinal, weekday: parse_weekday(weekday)? })
+}
+
+/// Parses an `UNTIL` value in one of the DATE, local DATE-TIME or UTC DATE-TIME forms
+fn parse_until(value: &str) -> Option<Until> {
+    if let Some(utc) = value.strip_suffix('Z') {
+        let local = NaiveDateTime::parse_from_str(utc, "%Y%m%dT%H%M%S").ok()?;
+        return Some(Until::Utc(local.and_utc()));
+    }
+    if value.contains('T') {
+        return NaiveDateTime::parse_from_str(value, "%Y%m%dT%H%M%S").ok().map(Until::Local);
+    }
+    NaiveDate::parse_from_str(value, "%Y%m%d").ok().map(Until::Date)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn start(year: i32, month: u32, day: u32, hour: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap().and_hms_opt(hour, 0, 0).unwrap()
+    }
+
+    fn expand(source: &str, rule: &str, dtstart: NaiveDateTime) -> Vec<String> {
+        let converter = TimeZoneConverter::new(source, "UTC").unwrap();
+        let rule: RRule = rule.parse().unwrap();
+        converter.expand_rrule(&rule, dtstart, 10).unwrap().iter().map(|t| t.to_string()).collect()
+    }
+
+    #[test]
+    fn vtimezone_components() {
+        let converter = TimeZoneConverter::new("UTC", "America/New_York").unwrap();
+        let vtimezone = converter.to_vtimezone(Zone::Target, 2023..=2024);
+        assert_eq!(vtimezone.matches("BEGIN:DAYLIGHT").count(), 2);
+        assert_eq!(vtimezone.matches("BEGIN:STANDARD").count(), 3);
+        assert!(vtimezone.contains("BEGIN:STANDARD\r\nDTSTART:20241103T020000\r\nTZOFFSETFROM:-0400\r\nTZOFFSETTO:-0500\r\nTZNAME:EST\r\nEND:STANDARD\r\n"));
+        assert!(vtimezone.ends_with("END:STANDARD\r\nEND:VTIMEZONE\r\n"));
+
+        assert_eq!(converter.to_vtimezone(Zone::Source, 2024..=2024), concat!(
+            "BEGIN:VTIMEZONE\r\nTZID:UTC\r\n",
+            "BEGIN:STANDARD\r\nDTSTART:20240101T000000\r\nTZOFFSETFROM:+0000\r\nTZOFFSETTO:+0000\r\nTZNAME:UTC\r\nEND:STANDARD\r\n",
+            "END:VTIMEZONE\r\n",
+        ));
+        assert_eq!(utc_offset(19800), "+0530");
+        assert_eq!(utc_offset(-(4 * 3600 + 56 * 60 + 2)), "-045602");
+    }
+
+    #[test]
+    fn parses_rules() {
+        let rule: RRule = "FREQ=MONTHLY;INTERVAL=2;BYDAY=-1FR,2MO;UNTIL=20241231T235959Z".parse().unwrap();
+        assert_eq!(rule.interval, 2);
+        assert_eq!(rule.by_day, [
+            ByDay { ordinal: Some(-1), weekday: Weekday::Fri },
+            ByDay { ordinal: Some(2), weekday: Weekday::Mon },
+        ]);
+        assert_eq!(rule.until, Some(Until::Utc(Utc.with_ymd_and_hms(2024, 12, 31, 23, 59, 59).unwrap())));
+
+        for invalid in ["", "COUNT=3", "FREQ=HOURLY", "FREQ=DAILY;BYDAY=XX", "FREQ=WEEKLY;BYDAY=1MO",
+            "FREQ=DAILY;COUNT=2;UNTIL=20240101", "FREQ=DAILY;INTERVAL=0", "FREQ=DAILY;BYHOUR=9"] {
+            assert!(matches!(invalid.parse::<RRule>(), Err(Errors::ParseError(_))), "{}", invalid);
+        }
+    }
+
+    #[test]
+    fn expands_weekly_and_daily_rules() {
+        assert_eq!(expand("Europe/Berlin", "FREQ=WEEKLY;INTERVAL=2;BYDAY=MO,FR;COUNT=4", start(2024, 3, 22, 9)), [
+            "2024-03-22 08:00:00 UTC",
+            "2024-04-01 07:00:00 UTC",
+            "2024-04-05 07:00:00 UTC",
+            "2024-04-15 07:00:00 UTC",
+        ]);
+        // 02:30 is skipped in Berlin on Mar 31 and shifts forward, keeping the pre-gap offset
+        assert_eq!(expand("Europe/Berlin", "FREQ=DAILY;UNTIL=20240401", start(2024, 3, 30, 2)), [
+            "2024-03-30 01:00:00 UTC",
+            "2024-03-31 01:00:00 UTC",
+            "2024-04-01 00:00:00 UTC",
+        ]);
+        assert_eq!(expand("UTC", "FREQ=DAILY;BYDAY=SA,SU", start(2024, 7, 5, 9)).len(), 10);
+    }
+
+    #[test]
+    fn expands_monthly_and_yearly_rules() {
+        assert_eq!(expand("UTC", "FREQ=MONTHLY;COUNT=4", start(2024, 1, 31, 12)), [
+            "2024-01-31 12:00:00 UTC",
+            "2024-03-31 12:00:00 UTC",
+            "2024-05-31 12:00:00 UTC",
+            "2024-07-31 12:00:00 UTC",
+        ]);
+        assert_eq!(expand("UTC", "FREQ=MONTHLY;BYDAY=-1FR;COUNT=3", start(2024, 1, 26, 16)), [
+            "2024-01-26 16:00:00 UTC",
+            "2024-02-23 16:00:00 UTC",
+            "2024-03-29 16:00:00 UTC",
+        ]);
+        assert_eq!(expand("UTC", "FREQ=YEARLY;UNTIL=20330101T000000", start(2024, 2, 29, 0)), [
+            "2024-02-29 00:00:00 UTC",
+            "2028-02-29 00:00:00 UTC",
+            "2032-02-29 00:00:00 UTC",
+        ]);
+    }
+}
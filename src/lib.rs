@@ -16,39 +16,485 @@
 //! let current_time = converter.get_current_time_source().unwrap();
 //! let converted_time = converter.convert(current_time).unwrap();
 //! ```
+//! 
+//! ## Historical dates
+//! 
+//! Conversions work for any date, using whatever the bundled tz database records for it.
+//! The database only aims to be accurate from 1970 ([`RELIABLE_SINCE_YEAR`]): before that,
+//! zones in a country that kept the same clocks since 1970 share one zone's history, even
+//! where their cities once differed. Before adopting standard time, a zone gives its city's
+//! Local Mean Time (abbreviated "LMT"), an offset such as -04:56:02 for New York until 1883.
+//! [`HistoricalConverter`] flags both cases on each result.
+
+use chrono::format::{Item, StrftimeItems};
+use chrono::{DateTime, FixedOffset, LocalResult, NaiveDate, NaiveDateTime, NaiveTime, TimeZone as ChronoTimeZone, Timelike, Utc, Duration, Offset, Datelike, Days, Weekday};
+use chrono_tz::{OffsetComponents, Tz};
+use std::collections::hash_map::Entry;
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+use std::sync::Arc;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+mod abbreviations;
+mod aliases;
+mod batch;
+mod builder;
+mod business_days;
+mod clock;
+mod custom_zone;
+mod historical;
+mod humanize;
+#[cfg(feature = "locale")]
+mod locale;
+#[cfg(feature = "cron")]
+mod cron;
+#[cfg(feature = "ffi")]
+mod ffi;
+#[cfg(feature = "geo")]
+mod geo;
+#[cfg(feature = "holiday-data")]
+mod holiday_data;
+#[cfg(feature = "ical")]
+mod ical;
+mod meeting;
+mod multi_zone;
+mod posix_tz;
+#[cfg(feature = "python")]
+mod python;
+mod recurring;
+#[cfg(feature = "serde")]
+mod report;
+#[cfg(feature = "search")]
+mod search;
+#[cfg(feature = "tokio")]
+mod scheduler;
+#[cfg(feature = "serde")]
+mod serde_helpers;
+#[cfg(feature = "system-tz")]
+mod system_tz;
+#[cfg(feature = "time")]
+mod time_interop;
+mod time_window;
+#[cfg(feature = "wasm")]
+mod wasm;
+mod windows_names;
+mod world_clock;
+mod zone_spec;
+
+pub use abbreviations::{resolve_abbreviation, Region};
+pub use aliases::canonicalize;
+pub use builder::{IntoTimeZone, TimeZoneConverterBuilder};
+pub use business_days::{is_business_day_in, Holidays, WeekendDef};
+pub use clock::{Clock, FixedClock, SystemClock};
+pub use custom_zone::{CustomOffset, CustomZone, TransitionRule};
+#[cfg(feature = "ffi")]
+pub use ffi::{tzc_convert_epoch, tzc_converter_free, tzc_converter_new, tzc_difference_seconds, tzc_local_time, TzcConverter, TzcLocalTime, TzcStatus, TzcZone};
+#[cfg(feature = "geo")]
+pub use geo::timezone_at;
+#[cfg(feature = "holiday-data")]
+pub use holiday_data::HolidayCalendar;
+pub use historical::{HistoricalConversion, HistoricalConverter, HistoricalUncertainty};
+pub use humanize::{EnglishPhrases, HumanizePhrases, RelativeDay, TimeUnit};
+#[cfg(feature = "ical")]
+pub use ical::{ByDay, Frequency, RRule, Until};
+pub use meeting::{MeetingPlanner, Participant, UtcWindow};
+pub use multi_zone::MultiZoneConverter;
+pub use posix_tz::{PosixOffset, PosixTz};
+pub use recurring::RecurringEvent;
+#[cfg(feature = "serde")]
+pub use report::{ConversionReport, DifferenceReport, TransitionReport};
+#[cfg(feature = "tokio")]
+pub use scheduler::{Firing, Scheduler};
+#[cfg(feature = "search")]
+pub use search::{find_by_city, search, timezones_for_country};
+#[cfg(feature = "system-tz")]
+pub use system_tz::{SystemOffset, SystemTzdb, SystemZone};
+pub use time_window::LocalTimeWindow;
+pub use windows_names::{from_windows_name, to_windows_name};
+pub use world_clock::{ClockReading, WorldClock};
+pub use zone_spec::{ZoneSpec, ZoneSpecOffset};
+
+/// The earliest year [`TimeZoneConverter::convert_guarded`] accepts
+pub const MIN_SUPPORTED_YEAR: i32 = 1;
+
+/// The latest year [`TimeZoneConverter::convert_guarded`] accepts
+pub const MAX_SUPPORTED_YEAR: i32 = 9999;
+
+/// The first year from which the tz database aims to record every zone's offsets accurately
+/// 
+/// Earlier offsets are best-effort; see the crate documentation on historical dates.
+pub const RELIABLE_SINCE_YEAR: i32 = 1970;
 
-use chrono::{DateTime, TimeZone as ChronoTimeZone, Utc, Duration, Offset};
-use chrono_tz::{OffsetName, Tz};
+/// The Julian Day Number of the Unix epoch, 1970-01-01 00:00:00 UTC
+pub const UNIX_EPOCH_JULIAN_DAY: f64 = 2_440_587.5;
 
 /// A struct that handles timezone conversions between a source and target timezone
 #[derive(Debug)]
 pub struct TimeZoneConverter {
     /// The source timezone to convert from
-    source_tz: Tz,
+    source_tz: ZoneSpec,
     /// The target timezone to convert to
-    target_tz: Tz,
+    target_tz: ZoneSpec,
+    /// How source-local wall-clock times skipped or repeated by DST are resolved
+    ambiguity_policy: AmbiguityPolicy,
+    /// The clock read by methods that work with the current time
+    clock: Arc<dyn Clock>,
+    /// The strftime pattern used by [`TimeZoneConverter::convert_to_string`], or `None` for RFC 3339
+    output_format: Option<String>,
+}
+
+/// Selects one of a converter's two timezones
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Zone {
+    /// The source timezone
+    Source,
+    /// The target timezone
+    Target,
 }
 
 /// Represents detailed information about a timezone
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TimeZoneInfo {
     /// The name of the timezone (e.g., "America/New_York")
     name: String,
     /// The offset from UTC
+    #[cfg_attr(feature = "serde", serde(rename = "offset_seconds", with = "serde_helpers::duration_seconds"))]
     offset: Duration,
     /// Whether Daylight Saving Time is currently in effect
     is_dst: bool,
+    /// How far DST moves clocks ahead of standard time, or zero outside DST
+    #[cfg_attr(feature = "serde", serde(rename = "dst_savings_seconds", with = "serde_helpers::duration_seconds"))]
+    dst_savings: Duration,
+    /// The IANA tz database release the information was computed with (e.g., "2024b")
+    tzdb_version: String,
 }
 
 /// Possible errors that can occur during timezone operations
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
 pub enum Errors {
     /// Error when an invalid timezone identifier is provided
-    InvalidTimeZone(String),
+    InvalidTimeZone {
+        /// The identifier that failed to parse
+        input: String,
+        /// Valid identifiers the caller may have meant, best match first
+        suggestions: Vec<String>,
+    },
     /// Error when parsing datetime strings
     ParseError(String),
     /// Error during timezone conversion
     ConversionError(String),
+    /// Error when a timezone abbreviation could stand for several zones, which are listed
+    AmbiguousAbbreviation(Vec<Tz>),
+}
+
+/// A coarse part of the day, based on the local hour
+/// 
+/// * `Morning` - 05:00 to 11:59
+/// * `Afternoon` - 12:00 to 16:59
+/// * `Evening` - 17:00 to 20:59
+/// * `Night` - 21:00 to 04:59
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PartOfDay {
+    /// From 05:00 until noon
+    Morning,
+    /// From noon until 17:00
+    Afternoon,
+    /// From 17:00 until 21:00
+    Evening,
+    /// From 21:00 until 05:00
+    Night,
+}
+
+impl PartOfDay {
+    /// Gets the part of day for a local hour (0-23)
+    fn from_hour(hour: u32) -> Self {
+        match hour {
+            5..=11 => PartOfDay::Morning,
+            12..=16 => PartOfDay::Afternoon,
+            17..=20 => PartOfDay::Evening,
+            _ => PartOfDay::Night,
+        }
+    }
+}
+
+/// How another timezone compares with a converter's source timezone at an instant
+#[derive(Debug, Clone, PartialEq)]
+pub struct ZoneComparison {
+    /// The timezone being compared
+    pub zone: Tz,
+    /// The other timezone's offset from UTC
+    pub offset: Duration,
+    /// The other timezone's offset minus the source timezone's offset
+    pub difference: Duration,
+    /// Whether the other timezone's wall clock is ahead of the source's
+    pub is_ahead: bool,
+}
+
+/// The fields decoded from a value produced by [`TimeZoneConverter::pack_status`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PackedStatus {
+    /// The source timezone's offset from UTC in minutes
+    pub source_offset_minutes: i16,
+    /// The target timezone's offset from UTC in minutes
+    pub target_offset_minutes: i16,
+    /// Whether DST is in effect in the source timezone
+    pub source_is_dst: bool,
+    /// Whether DST is in effect in the target timezone
+    pub target_is_dst: bool,
+}
+
+/// Both timezones' offsets from UTC and the difference between them, sampled at one instant
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct UtcRelative {
+    /// The source timezone's offset from UTC in hours
+    pub source_offset_hours: f64,
+    /// The target timezone's offset from UTC in hours
+    pub target_offset_hours: f64,
+    /// The source offset minus the target offset in hours
+    pub difference_hours: f64,
+}
+
+/// One day of an agenda built by [`TimeZoneConverter::agenda`]: the target-local date and its labelled events in order
+pub type AgendaDay = (NaiveDate, Vec<(String, DateTime<Tz>)>);
+
+/// A window of time as its start and end in a tz database timezone
+pub type TzWindow = (DateTime<Tz>, DateTime<Tz>);
+
+/// A cache of converters keyed by their source and target timezone names
+/// 
+/// Each name pair is parsed and validated once; repeat lookups return the stored converter.
+#[derive(Debug, Default)]
+pub struct ConverterCache {
+    /// The cached converters, keyed by (source, target) name
+    converters: HashMap<(String, String), TimeZoneConverter>,
+}
+
+/// How to resolve a local time that is ambiguous or does not exist because of a DST transition
+/// 
+/// When clocks fall back, an hour of wall-clock times occurs twice; when they spring
+/// forward, an hour is skipped. The variants follow the same conventions as the
+/// JavaScript Temporal API's `disambiguation` option.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AmbiguityPolicy {
+    /// Use the earlier occurrence of a repeated time; shift a skipped time back by the length of the gap
+    Earliest,
+    /// Use the later occurrence of a repeated time; shift a skipped time forward by the length of the gap
+    Latest,
+    /// Return an error for both repeated and skipped times
+    Reject,
+    /// Use the earlier occurrence of a repeated time; shift a skipped time forward by the length of the gap
+    #[default]
+    ShiftForward,
+}
+
+impl TimeZoneInfo {
+    /// Gets the name of the timezone (e.g., "America/New_York")
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Gets the offset from UTC
+    pub fn offset(&self) -> Duration {
+        self.offset
+    }
+
+    /// Whether Daylight Saving Time is in effect
+    pub fn is_dst(&self) -> bool {
+        self.is_dst
+    }
+
+    /// Gets how far DST moves clocks ahead of standard time (e.g., one hour), or zero outside DST
+    pub fn dst_savings(&self) -> Duration {
+        self.dst_savings
+    }
+
+    /// Gets the IANA tz database release the information was computed with (e.g., "2024b")
+    pub fn tzdb_version(&self) -> &str {
+        &self.tzdb_version
+    }
+}
+
+impl From<(Tz, Tz)> for TimeZoneConverter {
+    fn from((source, target): (Tz, Tz)) -> Self {
+        Self::from_tz(source, target)
+    }
+}
+
+impl fmt::Display for Errors {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Errors::InvalidTimeZone { input, suggestions } => {
+                write!(f, "invalid timezone '{}'", input)?;
+                if !suggestions.is_empty() {
+                    write!(f, " (did you mean {}?)", suggestions.join(", "))?;
+                }
+                Ok(())
+            }
+            Errors::ParseError(message) => write!(f, "parse error: {}", message),
+            Errors::ConversionError(message) => write!(f, "conversion error: {}", message),
+            Errors::AmbiguousAbbreviation(zones) => {
+                let names: Vec<&str> = zones.iter().map(|tz| tz.name()).collect();
+                write!(f, "ambiguous timezone abbreviation (could be {})", names.join(", "))
+            }
+        }
+    }
+}
+
+impl std::error::Error for Errors {}
+
+impl fmt::Display for RoundTripError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let side = |zone: &Zone| match zone {
+            Zone::Source => "source",
+            Zone::Target => "target",
+        };
+        match self {
+            Self::Nonexistent { zone, local } => write!(f, "{} does not exist in the {} timezone", local, side(zone)),
+            Self::Ambiguous { zone, local, earliest, latest } => {
+                write!(f, "{} is ambiguous in the {} timezone ({} or {})", local, side(zone), earliest, latest)
+            }
+        }
+    }
+}
+
+impl std::error::Error for RoundTripError {}
+
+/// A change in a timezone's offset from UTC, such as the start or end of DST
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Transition {
+    /// The instant the new offset takes effect
+    pub instant: DateTime<Utc>,
+    /// The offset from UTC in effect just before the transition
+    #[cfg_attr(feature = "serde", serde(rename = "offset_before_seconds", with = "serde_helpers::duration_seconds"))]
+    pub offset_before: Duration,
+    /// The offset from UTC in effect from the transition onwards
+    #[cfg_attr(feature = "serde", serde(rename = "offset_after_seconds", with = "serde_helpers::duration_seconds"))]
+    pub offset_after: Duration,
+    /// The abbreviation in effect just before the transition (e.g., "EST")
+    pub abbreviation_before: String,
+    /// The abbreviation in effect from the transition onwards (e.g., "EDT")
+    pub abbreviation_after: String,
+}
+
+impl Transition {
+    /// Describes the transition of a timezone that takes effect at `instant`
+    fn at(tz: &ZoneSpec, instant: DateTime<Utc>) -> Self {
+        let before = (instant - Duration::seconds(1)).with_timezone(tz);
+        let after = instant.with_timezone(tz);
+        Self {
+            instant,
+            offset_before: Duration::seconds(before.offset().fix().local_minus_utc() as i64),
+            offset_after: Duration::seconds(after.offset().fix().local_minus_utc() as i64),
+            abbreviation_before: before.format("%Z").to_string(),
+            abbreviation_after: after.format("%Z").to_string(),
+        }
+    }
+
+    /// Gets how far the wall clock moves at the transition (positive when clocks spring forward)
+    pub fn shift(&self) -> Duration {
+        self.offset_after - self.offset_before
+    }
+}
+
+/// The outcome of [`TimeZoneConverter::convert_checked`], with flags for DST edge cases worth warning about
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ConversionResult {
+    /// The converted datetime in the target timezone
+    #[cfg_attr(feature = "serde", serde(with = "serde_helpers::zoned_datetime"))]
+    pub datetime: DateTime<Tz>,
+    /// Whether the source wall-clock time was skipped or repeated by a source-zone DST transition
+    pub crossed_dst_in_source: bool,
+    /// Whether the target wall-clock time is repeated by a target-zone DST transition, so it is ambiguous on its own
+    pub crossed_dst_in_target: bool,
+    /// Whether the target-local date differs from the source-local date
+    pub date_changed: bool,
+    /// The IANA tz database release the conversion used (e.g., "2024b")
+    pub tzdb_version: String,
+}
+
+/// Why a wall-clock time does not survive [`TimeZoneConverter::verify_round_trip`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RoundTripError {
+    /// The local time falls in a gap where clocks spring forward, so it never occurs in the zone
+    Nonexistent {
+        /// Which of the converter's zones
+        zone: Zone,
+        /// The local time
+        local: NaiveDateTime,
+    },
+    /// The local time occurs twice in the zone as clocks fall back, so it names two instants
+    Ambiguous {
+        /// Which of the converter's zones
+        zone: Zone,
+        /// The local time
+        local: NaiveDateTime,
+        /// The earlier of the two instants
+        earliest: DateTime<Utc>,
+        /// The later of the two instants
+        latest: DateTime<Utc>,
+    },
+}
+
+/// A timezone with its current offset, abbreviation and DST status, as listed by [`list_timezones`]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TimeZoneSummary {
+    /// The timezone
+    #[cfg_attr(feature = "serde", serde(rename = "name", with = "serde_helpers::tz_name"))]
+    tz: Tz,
+    /// The offset from UTC
+    #[cfg_attr(feature = "serde", serde(rename = "offset_seconds", with = "serde_helpers::duration_seconds"))]
+    offset: Duration,
+    /// The abbreviation in effect (e.g., "CEST"), or the offset (e.g., "+03") where the zone has none
+    abbreviation: String,
+    /// Whether Daylight Saving Time is in effect
+    is_dst: bool,
+}
+
+impl TimeZoneSummary {
+    /// Gets the timezone
+    pub fn tz(&self) -> Tz {
+        self.tz
+    }
+
+    /// Gets the IANA name of the timezone (e.g., "Europe/Berlin")
+    pub fn name(&self) -> &'static str {
+        self.tz.name()
+    }
+
+    /// Gets the offset from UTC
+    pub fn offset(&self) -> Duration {
+        self.offset
+    }
+
+    /// Gets the abbreviation in effect (e.g., "CEST")
+    pub fn abbreviation(&self) -> &str {
+        &self.abbreviation
+    }
+
+    /// Whether Daylight Saving Time is in effect
+    pub fn is_dst(&self) -> bool {
+        self.is_dst
+    }
+}
+
+/// A source of timezones by name, such as the tz database compiled into the crate
+pub(crate) trait ZoneProvider {
+    /// Looks up a timezone by name
+    fn resolve(&self, name: &str) -> Result<ZoneSpec, Errors>;
+}
+
+/// The tz database compiled into `chrono-tz`
+pub(crate) struct BundledTzdb;
+
+impl ZoneProvider for BundledTzdb {
+    fn resolve(&self, name: &str) -> Result<ZoneSpec, Errors> {
+        name.parse()
+    }
 }
 
 impl TimeZoneConverter {
@@ -71,143 +517,4848 @@ impl TimeZoneConverter {
     /// let converter = TimeZoneConverter::new("America/New_York", "Europe/London").unwrap();
     /// ```
     pub fn new(source: &str, target: &str) -> Result<Self, Errors> {
-        let source_tz = source.parse::<Tz>().map_err(|_| Errors::InvalidTimeZone(source.to_string()))?;
-        let target_tz = target.parse::<Tz>().map_err(|_| Errors::InvalidTimeZone(target.to_string()))?;
+        Self::with_provider(source, target, &BundledTzdb)
+    }
 
-        Ok(Self {
-            source_tz,
-            target_tz
-        })
+    /// Creates a converter between two timezones looked up in a system zoneinfo directory
+    /// 
+    /// Unlike [`Self::new`], which uses the tz database compiled into the crate, this reads
+    /// the zones' TZif files, so tzdata updates installed on the system take effect
+    /// without a rebuild. Results and reports record the system's release, from
+    /// [`SystemTzdb::version`], rather than the compiled-in [`tzdb_version`].
+    /// 
+    /// # Arguments
+    /// 
+    /// * `source` - The source timezone identifier (e.g., "America/New_York")
+    /// * `target` - The target timezone identifier (e.g., "Europe/London")
+    /// * `tzdb` - The zoneinfo directory to read the zones from
+    /// 
+    /// # Returns
+    /// 
+    /// * `Result<TimeZoneConverter, Errors>` - A new TimeZoneConverter instance, or an error if a zone is missing from the directory or its file is invalid
+    #[cfg(feature = "system-tz")]
+    pub fn with_system_tzdb(source: &str, target: &str, tzdb: &SystemTzdb) -> Result<Self, Errors> {
+        Self::with_provider(source, target, tzdb)
     }
 
-    /// Converts a datetime from the source timezone to the target timezone
+    /// Creates a converter between two timezones looked up by a zone provider
+    fn with_provider(source: &str, target: &str, provider: &dyn ZoneProvider) -> Result<Self, Errors> {
+        Ok(Self::from_tz(provider.resolve(source)?, provider.resolve(target)?))
+    }
+
+    /// Creates a TimeZoneConverter from already-parsed timezones
     /// 
     /// # Arguments
     /// 
-    /// * `datetime` - The datetime to convert
+    /// * `source` - The source timezone
+    /// * `target` - The target timezone
     /// 
     /// # Returns
     /// 
-    /// * `Result<DateTime<Tz>, Errors>` - The converted datetime or an error
-    pub fn convert<T: ChronoTimeZone>(&self, datetime: DateTime<T>) -> Result<DateTime<Tz>, Errors> {
-        Ok(
-            datetime.with_timezone(&self.target_tz)
-        )
+    /// * `TimeZoneConverter` - A new TimeZoneConverter instance with the default options
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use chrono_tz::{America::New_York, Europe::London};
+    /// use timezone_converter::TimeZoneConverter;
+    /// 
+    /// let converter = TimeZoneConverter::from_tz(New_York, London);
+    /// let same: TimeZoneConverter = (New_York, London).into();
+    /// assert_eq!(converter.get_timezone_info().unwrap().name(), same.get_timezone_info().unwrap().name());
+    /// ```
+    pub fn from_tz(source: impl Into<ZoneSpec>, target: impl Into<ZoneSpec>) -> Self {
+        Self {
+            source_tz: source.into(),
+            target_tz: target.into(),
+            ambiguity_policy: AmbiguityPolicy::default(),
+            clock: Arc::new(SystemClock),
+            output_format: None,
+        }
     }
 
-    /// Gets the current time in the source timezone
+    /// Creates a TimeZoneConverter from the system's local timezone to a target timezone
+    /// 
+    /// # Arguments
+    /// 
+    /// * `target` - The target timezone identifier (e.g., "Asia/Tokyo")
     /// 
     /// # Returns
     /// 
-    /// * `Result<DateTime<Tz>, Errors>` - The current time in the source timezone
-    pub fn get_current_time_source(&self) -> Result<DateTime<Tz>, Errors> {
-        Ok(
-            Utc::now().with_timezone(&self.source_tz)
-        )
+    /// * `Result<TimeZoneConverter, Errors>` - A new TimeZoneConverter instance, or an error if the local timezone cannot be detected or `target` is invalid
+    /// 
+    /// # Example
+    /// 
+    /// ```rust,no_run
+    /// use timezone_converter::TimeZoneConverter;
+    /// 
+    /// let converter = TimeZoneConverter::from_local_to("Asia/Tokyo").unwrap();
+    /// println!("{}", converter.get_current_time_target().unwrap());
+    /// ```
+    pub fn from_local_to(target: &str) -> Result<Self, Errors> {
+        Self::builder().source(local_timezone()?).target(target).build()
     }
 
-    /// Gets the current time in the target timezone
+    /// Starts configuring a converter with options beyond its two timezones
     /// 
     /// # Returns
     /// 
-    /// * `Result<DateTime<Tz>, Errors>` - The current time in the target timezone
-    pub fn get_current_time_target(&self) -> Result<DateTime<Tz>, Errors> {
-        Ok(
-            Utc::now().with_timezone(&self.target_tz)
-        )
+    /// * `TimeZoneConverterBuilder` - A builder with no timezones and the default options
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use chrono::{TimeZone, Utc};
+    /// use timezone_converter::TimeZoneConverter;
+    /// 
+    /// let converter = TimeZoneConverter::builder()
+    ///     .source("UTC")
+    ///     .target("Europe/Paris")
+    ///     .output_format("%d/%m/%Y %H:%M")
+    ///     .build()
+    ///     .unwrap();
+    /// let dt = Utc.with_ymd_and_hms(2024, 7, 14, 8, 0, 0).unwrap();
+    /// assert_eq!(converter.convert_to_string(dt).unwrap(), "14/07/2024 10:00");
+    /// ```
+    pub fn builder() -> TimeZoneConverterBuilder {
+        TimeZoneConverterBuilder::new()
     }
 
-    /// Gets detailed information about the source timezone
+    /// Creates a TimeZoneConverter whose source and target are the same timezone
+    /// 
+    /// This is useful when only one zone's offset and DST information is needed.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `zone` - The timezone identifier (e.g., "America/New_York")
     /// 
     /// # Returns
     /// 
-    /// * `Result<TimeZoneInfo, Errors>` - Information about the timezone including name, offset, and DST status
-    pub fn get_timezone_info(&self) -> Result<TimeZoneInfo, Errors> {
-        let now = Utc::now().with_timezone(&self.source_tz);
-        let offset = now.offset();
+    /// * `Result<TimeZoneConverter, Errors>` - A new TimeZoneConverter instance or an error
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use timezone_converter::TimeZoneConverter;
+    /// 
+    /// let converter = TimeZoneConverter::single("Europe/Paris").unwrap();
+    /// assert!(converter.is_identity());
+    /// ```
+    pub fn single(zone: &str) -> Result<Self, Errors> {
+        Self::new(zone, zone)
+    }
 
-        // Calculate the total offset in seconds
-        let total_offset_seconds = offset.fix().local_minus_utc();
-        // Determine if DST is in effect by checking the offset abbreviation
-        let is_dst = match offset.abbreviation() {
-            Some(abbr) => abbr.ends_with("DT"),
-            None => false,
-        };
+    /// Whether the source and target timezones are the same
+    /// 
+    /// # Returns
+    /// 
+    /// * `bool` - `true` if conversions leave the wall-clock time unchanged
+    pub fn is_identity(&self) -> bool {
+        self.source_tz == self.target_tz
+    }
 
-        Ok(TimeZoneInfo {
-            name: self.source_tz.name().to_string(),
-            offset: Duration::seconds(total_offset_seconds as i64),
-            is_dst,
-        })
+    /// Creates a converter with the source and target timezones exchanged
+    /// 
+    /// # Returns
+    /// 
+    /// * `TimeZoneConverter` - A converter from this converter's target to its source
+    pub fn swapped(&self) -> Self {
+        Self {
+            source_tz: self.target_tz,
+            target_tz: self.source_tz,
+            ambiguity_policy: self.ambiguity_policy,
+            clock: Arc::clone(&self.clock),
+            output_format: self.output_format.clone(),
+        }
     }
 
-    /// Gets the time difference between source and target timezones in hours
+    /// Replaces the clock this converter reads the current time from
+    /// 
+    /// Methods such as [`Self::get_current_time_target`], [`Self::get_timezone_info`] and
+    /// [`Self::get_time_difference`] read this clock instead of the system time, so a
+    /// [`FixedClock`] makes their DST-dependent results reproducible.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `clock` - The clock to use
     /// 
     /// # Returns
     /// 
-    /// * `Result<f64, Errors>` - The time difference in hours (positive if source is ahead)
+    /// * `TimeZoneConverter` - The converter, now reading `clock`
     /// 
     /// # Example
     /// 
     /// ```rust
-    /// use timezone_converter::TimeZoneConverter;
+    /// use chrono::{TimeZone, Utc};
+    /// use timezone_converter::{FixedClock, TimeZoneConverter};
     /// 
-    /// let converter = TimeZoneConverter::new("America/New_York", "Europe/London").unwrap();
-    /// let difference = converter.get_time_difference().unwrap();
-    /// println!("Time difference: {} hours", difference);
+    /// let summer = Utc.with_ymd_and_hms(2024, 7, 1, 12, 0, 0).unwrap();
+    /// let converter = TimeZoneConverter::new("UTC", "America/New_York").unwrap()
+    ///     .with_clock(FixedClock::new(summer));
+    /// assert_eq!(converter.get_current_time_target().unwrap().to_string(), "2024-07-01 08:00:00 EDT");
     /// ```
-    pub fn get_time_difference(&self) -> Result<f64, Errors> {
-        let now = Utc::now();
-        
-        // Get the offsets for both timezones
-        let source_time = now.with_timezone(&self.source_tz);
-        let target_time = now.with_timezone(&self.target_tz);
-        
-        let source_offset = source_time.offset().fix().local_minus_utc();
-        let target_offset = target_time.offset().fix().local_minus_utc();
-        
-        // Convert seconds to hours (f64 for decimal hours)
-        Ok((source_offset - target_offset) as f64 / 3600.0)
+    pub fn with_clock(mut self, clock: impl Clock + 'static) -> Self {
+        self.clock = Arc::new(clock);
+        self
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use chrono_tz::America::New_York;
-    use chrono_tz::Africa::Kampala;
+    /// Gets how source-local wall-clock times skipped or repeated by DST are resolved
+    /// 
+    /// This applies to methods that take a source-local time without their own policy,
+    /// such as [`Self::convert_from_str`] and [`Self::convert_checked`].
+    /// 
+    /// # Returns
+    /// 
+    /// * `AmbiguityPolicy` - The policy set on the builder, [`AmbiguityPolicy::ShiftForward`] by default
+    pub fn ambiguity_policy(&self) -> AmbiguityPolicy {
+        self.ambiguity_policy
+    }
 
-    #[test]
-    fn NewYorktoKampala() {
-        let timezone = TimeZoneConverter::new("America/New_York", "Africa/Kampala").unwrap();
-        // Create a specific time in New York
-        let ny_time = New_York.with_ymd_and_hms(2024, 11, 4, 10, 0, 0).unwrap();
-        let time = timezone.convert(ny_time).unwrap();
-        // Print both times to verify the conversion
-        println!("New York: {}", ny_time);
-        println!("Kampala: {}", time);
+    /// Gets the tz database release the converter's timezones come from
+    /// 
+    /// This is [`tzdb_version`] unless a zone was read from the system by [`SystemTzdb`],
+    /// in which case it is the system's release, or "unknown" if its directory does not
+    /// record one. Conversion results and reports record the same release.
+    /// 
+    /// # Returns
+    /// 
+    /// * `&'static str` - The release: the year followed by a letter (e.g., "2024b")
+    pub fn tzdb_version(&self) -> &'static str {
+        match zone_tzdb_version(&self.source_tz) {
+            version if version != tzdb_version() => version,
+            _ => zone_tzdb_version(&self.target_tz),
+        }
     }
 
-    #[test]
-    fn NewYorkToKampala_current() {
-        let timezone = TimeZoneConverter::new("America/New_York", "Africa/Kampala").unwrap();
-        let ny_time = Utc::now().with_timezone(&New_York);
-        let time = timezone.convert(ny_time).unwrap();
-        println!("New York: {}", ny_time);
-        println!("Kampala: {}", time);
+    /// Converts a datetime from the source timezone to the target timezone
+    /// 
+    /// When the target is a fixed offset or rule string rather than a tz database zone, use
+    /// [`Self::convert_zoned`] instead.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `datetime` - The datetime to convert
+    /// 
+    /// # Returns
+    /// 
+    /// * `Result<DateTime<Tz>, Errors>` - The converted datetime, or `Errors::ConversionError` if the target is not a tz database zone
+    pub fn convert<T: ChronoTimeZone>(&self, datetime: DateTime<T>) -> Result<DateTime<Tz>, Errors> {
+        Ok(
+            datetime.with_timezone(&self.named_tz(Zone::Target)?)
+        )
     }
 
-    #[test]
-    fn get_timezone_info() {
-        let timezone = TimeZoneConverter::new("America/New_York", "Africa/Kampala").unwrap();
-        let info = timezone.get_timezone_info().unwrap();
-        println!("{:?}", info);
+    /// Converts a datetime from the target timezone back to the source timezone
+    /// 
+    /// # Arguments
+    /// 
+    /// * `datetime` - The datetime to convert
+    /// 
+    /// # Returns
+    /// 
+    /// * `Result<DateTime<Tz>, Errors>` - The datetime in the source timezone
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use chrono::TimeZone;
+    /// use chrono_tz::Europe::London;
+    /// use timezone_converter::TimeZoneConverter;
+    /// 
+    /// let converter = TimeZoneConverter::new("America/New_York", "Europe/London").unwrap();
+    /// let london = London.with_ymd_and_hms(2024, 7, 1, 15, 0, 0).unwrap();
+    /// let ny = converter.convert_back(london).unwrap();
+    /// assert_eq!(ny.to_string(), "2024-07-01 10:00:00 EDT");
+    /// ```
+    pub fn convert_back<T: ChronoTimeZone>(&self, datetime: DateTime<T>) -> Result<DateTime<Tz>, Errors> {
+        Ok(
+            datetime.with_timezone(&self.named_tz(Zone::Source)?)
+        )
     }
 
-    #[test]
-    fn get_time_difference() {
-        let timezone = TimeZoneConverter::new("America/New_York", "Africa/Kampala").unwrap();
-        let difference = timezone.get_time_difference().unwrap();
-        println!("Time difference: {} hours", difference);
+    /// Converts a datetime from the source timezone to the target timezone, which may be any
+    /// kind of [`ZoneSpec`]
+    /// 
+    /// # Arguments
+    /// 
+    /// * `datetime` - The datetime to convert
+    /// 
+    /// # Returns
+    /// 
+    /// * `Result<DateTime<ZoneSpec>, Errors>` - The converted datetime or an error
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use chrono::{TimeZone, Utc};
+    /// use timezone_converter::TimeZoneConverter;
+    /// 
+    /// let converter = TimeZoneConverter::new("Europe/London", "-07:00").unwrap();
+    /// let dt = Utc.with_ymd_and_hms(2024, 7, 1, 12, 0, 0).unwrap();
+    /// assert_eq!(converter.convert_zoned(dt).unwrap().to_string(), "2024-07-01 05:00:00 -07:00");
+    /// ```
+    pub fn convert_zoned<T: ChronoTimeZone>(&self, datetime: DateTime<T>) -> Result<DateTime<ZoneSpec>, Errors> {
+        Ok(datetime.with_timezone(&self.target_tz))
+    }
+
+    /// Converts a datetime from the target timezone back to the source timezone, which may be
+    /// any kind of [`ZoneSpec`]
+    /// 
+    /// # Arguments
+    /// 
+    /// * `datetime` - The datetime to convert
+    /// 
+    /// # Returns
+    /// 
+    /// * `Result<DateTime<ZoneSpec>, Errors>` - The datetime in the source timezone
+    pub fn convert_back_zoned<T: ChronoTimeZone>(&self, datetime: DateTime<T>) -> Result<DateTime<ZoneSpec>, Errors> {
+        Ok(datetime.with_timezone(&self.source_tz))
+    }
+
+    /// Converts a datetime to the target timezone and formats it with the converter's output format
+    /// 
+    /// The format is set with [`TimeZoneConverterBuilder::output_format`]; without one the
+    /// result is RFC 3339.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `datetime` - The datetime to convert
+    /// 
+    /// # Returns
+    /// 
+    /// * `Result<String, Errors>` - The formatted target-local datetime
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use chrono::{TimeZone, Utc};
+    /// use timezone_converter::TimeZoneConverter;
+    /// 
+    /// let converter = TimeZoneConverter::new("UTC", "Asia/Kolkata").unwrap();
+    /// let dt = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+    /// assert_eq!(converter.convert_to_string(dt).unwrap(), "2024-01-01T05:30:00+05:30");
+    /// ```
+    pub fn convert_to_string<T: ChronoTimeZone>(&self, datetime: DateTime<T>) -> Result<String, Errors> {
+        let converted = self.convert_zoned(datetime)?;
+        Ok(match &self.output_format {
+            Some(format) => converted.format(format).to_string(),
+            None => converted.to_rfc3339(),
+        })
+    }
+
+    /// Parses a naive datetime string, interprets it in the source timezone and converts it to the target timezone
+    /// 
+    /// Ambiguous local times and times inside a DST gap are resolved with the converter's
+    /// [`Self::ambiguity_policy`], which by default picks the earliest occurrence and shifts
+    /// skipped times forward.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `s` - The datetime string, without any offset or zone
+    /// * `format` - A `chrono` strftime-style format describing `s` (e.g. "%Y-%m-%d %H:%M")
+    /// 
+    /// # Returns
+    /// 
+    /// * `Result<DateTime<Tz>, Errors>` - The converted datetime, or `Errors::ParseError` if `s` does not match `format`
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use timezone_converter::TimeZoneConverter;
+    /// 
+    /// let converter = TimeZoneConverter::new("America/New_York", "Europe/London").unwrap();
+    /// let time = converter.convert_from_str("2024-11-04 10:00", "%Y-%m-%d %H:%M").unwrap();
+    /// assert_eq!(time.to_string(), "2024-11-04 15:00:00 GMT");
+    /// ```
+    pub fn convert_from_str(&self, s: &str, format: &str) -> Result<DateTime<Tz>, Errors> {
+        let naive = NaiveDateTime::parse_from_str(s, format)
+            .map_err(|e| Errors::ParseError(format!("could not parse '{}' as '{}': {}", s, format, e)))?;
+        let local = resolve_local(&self.source_tz, naive, self.ambiguity_policy)?;
+        Ok(local.with_timezone(&self.named_tz(Zone::Target)?))
+    }
+
+    /// Gets the current time in the source timezone
+    /// 
+    /// # Returns
+    /// 
+    /// * `Result<DateTime<Tz>, Errors>` - The current time in the source timezone
+    pub fn get_current_time_source(&self) -> Result<DateTime<Tz>, Errors> {
+        Ok(
+            self.clock.now().with_timezone(&self.named_tz(Zone::Source)?)
+        )
+    }
+
+    /// Gets the current time in the target timezone
+    /// 
+    /// # Returns
+    /// 
+    /// * `Result<DateTime<Tz>, Errors>` - The current time in the target timezone
+    pub fn get_current_time_target(&self) -> Result<DateTime<Tz>, Errors> {
+        Ok(
+            self.clock.now().with_timezone(&self.named_tz(Zone::Target)?)
+        )
+    }
+
+    /// Gets the current time in the source or target timezone, which may be any kind of [`ZoneSpec`]
+    /// 
+    /// # Arguments
+    /// 
+    /// * `which` - Whether to read the time in the source or the target timezone
+    /// 
+    /// # Returns
+    /// 
+    /// * `DateTime<ZoneSpec>` - The current time in that timezone
+    pub fn get_current_time_zoned(&self, which: Zone) -> DateTime<ZoneSpec> {
+        self.clock.now().with_timezone(self.zone(which))
+    }
+
+    /// Gets detailed information about the source timezone
+    /// 
+    /// # Returns
+    /// 
+    /// * `Result<TimeZoneInfo, Errors>` - Information about the timezone including name, offset, and DST status
+    pub fn get_timezone_info(&self) -> Result<TimeZoneInfo, Errors> {
+        self.timezone_info_at(self.clock.now(), Zone::Source)
+    }
+
+    /// Gets detailed information about the target timezone
+    /// 
+    /// # Returns
+    /// 
+    /// * `Result<TimeZoneInfo, Errors>` - Information about the timezone including name, offset, and DST status
+    pub fn get_timezone_info_target(&self) -> Result<TimeZoneInfo, Errors> {
+        self.timezone_info_at(self.clock.now(), Zone::Target)
+    }
+
+    /// Gets detailed information about either timezone at an arbitrary instant
+    /// 
+    /// # Arguments
+    /// 
+    /// * `instant` - The instant to describe the timezone at
+    /// * `which` - Whether to describe the source or the target timezone
+    /// 
+    /// # Returns
+    /// 
+    /// * `Result<TimeZoneInfo, Errors>` - Information about the timezone including name, offset, and DST status
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use chrono::{Duration, TimeZone, Utc};
+    /// use timezone_converter::{TimeZoneConverter, Zone};
+    /// 
+    /// let converter = TimeZoneConverter::new("America/New_York", "Europe/London").unwrap();
+    /// let instant = Utc.with_ymd_and_hms(2021, 3, 15, 12, 0, 0).unwrap();
+    /// let info = converter.timezone_info_at(instant, Zone::Target).unwrap();
+    /// assert_eq!(info.name(), "Europe/London");
+    /// assert_eq!(info.offset(), Duration::zero());
+    /// assert!(!info.is_dst());
+    /// ```
+    pub fn timezone_info_at(&self, instant: DateTime<Utc>, which: Zone) -> Result<TimeZoneInfo, Errors> {
+        let tz = self.zone(which);
+
+        // Calculate the total offset in seconds
+        let total_offset_seconds = offset_seconds_at(tz, instant);
+        let dst_savings = dst_savings_at(tz, instant);
+
+        Ok(TimeZoneInfo {
+            name: tz.name().to_string(),
+            offset: Duration::seconds(total_offset_seconds as i64),
+            is_dst: !dst_savings.is_zero(),
+            dst_savings,
+            tzdb_version: zone_tzdb_version(tz).to_string(),
+        })
+    }
+
+    /// Gets the time difference between source and target timezones in hours
+    /// 
+    /// # Returns
+    /// 
+    /// * `Result<f64, Errors>` - The time difference in hours (positive if source is ahead)
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use timezone_converter::TimeZoneConverter;
+    /// 
+    /// let converter = TimeZoneConverter::new("America/New_York", "Europe/London").unwrap();
+    /// let difference = converter.get_time_difference().unwrap();
+    /// println!("Time difference: {} hours", difference);
+    /// ```
+    pub fn get_time_difference(&self) -> Result<f64, Errors> {
+        // Convert seconds to hours (f64 for decimal hours)
+        Ok(self.time_difference().num_seconds() as f64 / 3600.0)
+    }
+
+    /// Gets the exact time difference between source and target timezones
+    /// 
+    /// Unlike [`Self::get_time_difference`], this keeps zones such as `Asia/Kathmandu`
+    /// (+5:45) exact to the second.
+    /// 
+    /// # Returns
+    /// 
+    /// * `Duration` - The source offset minus the target offset (positive if source is ahead)
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use chrono::Duration;
+    /// use timezone_converter::TimeZoneConverter;
+    /// 
+    /// let converter = TimeZoneConverter::new("Asia/Kathmandu", "UTC").unwrap();
+    /// assert_eq!(converter.time_difference(), Duration::minutes(5 * 60 + 45));
+    /// ```
+    pub fn time_difference(&self) -> Duration {
+        self.time_difference_at(self.clock.now())
+    }
+
+    /// Gets the exact time difference between source and target timezones at a given instant
+    /// 
+    /// # Arguments
+    /// 
+    /// * `instant` - The instant to compare the offsets at
+    /// 
+    /// # Returns
+    /// 
+    /// * `Duration` - The source offset minus the target offset (positive if source is ahead)
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use chrono::{Duration, TimeZone, Utc};
+    /// use timezone_converter::TimeZoneConverter;
+    /// 
+    /// let converter = TimeZoneConverter::new("Europe/London", "America/New_York").unwrap();
+    /// let winter = Utc.with_ymd_and_hms(2024, 1, 15, 12, 0, 0).unwrap();
+    /// let spring = Utc.with_ymd_and_hms(2024, 3, 20, 12, 0, 0).unwrap();
+    /// assert_eq!(converter.time_difference_at(winter), Duration::hours(5));
+    /// assert_eq!(converter.time_difference_at(spring), Duration::hours(4));
+    /// ```
+    pub fn time_difference_at(&self, instant: DateTime<Utc>) -> Duration {
+        let source_offset = offset_seconds_at(&self.source_tz, instant);
+        let target_offset = offset_seconds_at(&self.target_tz, instant);
+        Duration::seconds((source_offset - target_offset) as i64)
+    }
+
+    /// Converts a datetime to the target timezone and formats it as a SQL `TIMESTAMP WITH TIME ZONE` literal
+    /// 
+    /// The literal has the form `YYYY-MM-DD HH:MM:SS.ffffff±HH:MM`, which Postgres accepts for
+    /// `timestamptz` columns. The offset is the one in effect in the target timezone at that
+    /// instant, so DST is reflected in the literal.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `dt` - The datetime to convert
+    /// 
+    /// # Returns
+    /// 
+    /// * `String` - The converted datetime as a SQL timestamp literal
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use chrono::{TimeZone, Utc};
+    /// use timezone_converter::TimeZoneConverter;
+    /// 
+    /// let converter = TimeZoneConverter::new("UTC", "America/New_York").unwrap();
+    /// let dt = Utc.with_ymd_and_hms(2024, 7, 1, 16, 0, 0).unwrap();
+    /// assert_eq!(converter.convert_to_sql(dt), "2024-07-01 12:00:00.000000-04:00");
+    /// ```
+    pub fn convert_to_sql<T: ChronoTimeZone>(&self, dt: DateTime<T>) -> String {
+        dt.with_timezone(&self.target_tz)
+            .format("%Y-%m-%d %H:%M:%S%.6f%:z")
+            .to_string()
+    }
+
+    /// Gets the time difference between source and target timezones as a compact label
+    /// 
+    /// The label always carries a sign and omits the minutes when they are zero,
+    /// e.g. `+5h`, `-4h30m` or `+5h45m`. It is built from the exact offset difference,
+    /// so fractional-hour zones render precisely.
+    /// 
+    /// # Returns
+    /// 
+    /// * `Result<String, Errors>` - The signed difference label (positive if source is ahead)
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use timezone_converter::TimeZoneConverter;
+    /// 
+    /// let converter = TimeZoneConverter::new("Asia/Kathmandu", "UTC").unwrap();
+    /// assert_eq!(converter.difference_short_string().unwrap(), "+5h45m");
+    /// ```
+    pub fn difference_short_string(&self) -> Result<String, Errors> {
+        let difference = self.time_difference_at(self.clock.now());
+        Ok(format_short_duration(difference))
+    }
+
+    /// Converts a datetime to the target timezone and reports whether it falls within a leap second
+    /// 
+    /// chrono represents a leap second by a nanosecond component of one billion or more,
+    /// which renders as second `60` (e.g. `23:59:60`). Most instants return `false`.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `dt` - The datetime to convert
+    /// 
+    /// # Returns
+    /// 
+    /// * `Result<(DateTime<Tz>, bool), Errors>` - The converted datetime and whether it is within a leap second, or `Errors::ConversionError` if the target is not a tz database zone
+    pub fn convert_with_leap_flag<T: ChronoTimeZone>(&self, dt: DateTime<T>) -> Result<(DateTime<Tz>, bool), Errors> {
+        let converted = dt.with_timezone(&self.named_tz(Zone::Target)?);
+        let is_leap = converted.nanosecond() >= 1_000_000_000;
+        Ok((converted, is_leap))
+    }
+
+    /// Describes how the time difference between the zones behaves over a date range
+    /// 
+    /// Produces text such as `"5 hours, changing to 4 hours on Mar 10"` when the difference
+    /// changes, or `"constant 5 hours 30 minutes"` when it does not. The range covers whole
+    /// UTC days from `from` through `to`, and change dates are given in the source timezone.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `from` - The first day of the range
+    /// * `to` - The last day of the range (inclusive)
+    /// 
+    /// # Returns
+    /// 
+    /// * `Result<String, Errors>` - The summary text, or an error if `to` is before `from`
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use timezone_converter::TimeZoneConverter;
+    /// 
+    /// let converter = TimeZoneConverter::new("Europe/London", "America/New_York").unwrap();
+    /// let from = NaiveDate::from_ymd_opt(2024, 3, 1).unwrap();
+    /// let to = NaiveDate::from_ymd_opt(2024, 3, 20).unwrap();
+    /// assert_eq!(
+    ///     converter.difference_summary(from, to).unwrap(),
+    ///     "5 hours, changing to 4 hours on Mar 10"
+    /// );
+    /// ```
+    pub fn difference_summary(&self, from: NaiveDate, to: NaiveDate) -> Result<String, Errors> {
+        if to < from {
+            return Err(Errors::ConversionError(format!(
+                "date range ends ({}) before it starts ({})",
+                to, from
+            )));
+        }
+
+        let start = from.and_time(NaiveTime::MIN).and_utc();
+        let end = to.and_time(NaiveTime::MIN).and_utc() + Duration::days(1);
+        let initial = self.time_difference_at(start);
+        let changes = self.difference_changes_between(start, end);
+
+        if changes.is_empty() {
+            return Ok(format!("constant {}", format_long_duration(initial)));
+        }
+
+        let steps: Vec<String> = changes
+            .iter()
+            .map(|(instant, difference)| {
+                let date = instant.with_timezone(&self.source_tz).format("%b %-d");
+                format!("to {} on {}", format_long_duration(*difference), date)
+            })
+            .collect();
+
+        Ok(format!(
+            "{}, changing {}",
+            format_long_duration(initial),
+            steps.join(" and ")
+        ))
+    }
+
+    /// Gets the part of day a datetime falls in, in the target timezone
+    /// 
+    /// The target-local hour is used, so this is suitable for greeting the recipient
+    /// ("Good evening") rather than the sender. See [`PartOfDay`] for the thresholds.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `dt` - The datetime to classify
+    /// 
+    /// # Returns
+    /// 
+    /// * `PartOfDay` - The part of day in the target timezone
+    pub fn target_part_of_day<T: ChronoTimeZone>(&self, dt: DateTime<T>) -> PartOfDay {
+        PartOfDay::from_hour(dt.with_timezone(&self.target_tz).hour())
+    }
+
+    /// Counts the offset changes in the source timezone between two instants
+    /// 
+    /// This is cheaper than listing the transitions when only the count is needed,
+    /// and returns 0 immediately for fixed-offset zones such as `UTC` or `Etc/GMT+5`.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `start` - The start of the range (inclusive)
+    /// * `end` - The end of the range (exclusive)
+    /// 
+    /// # Returns
+    /// 
+    /// * `Result<usize, Errors>` - The number of transitions, or an error if `end` is before `start`
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use chrono::{TimeZone, Utc};
+    /// use timezone_converter::TimeZoneConverter;
+    /// 
+    /// let converter = TimeZoneConverter::new("America/New_York", "UTC").unwrap();
+    /// let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+    /// let end = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+    /// assert_eq!(converter.transition_count(start, end).unwrap(), 2);
+    /// ```
+    pub fn transition_count(&self, start: DateTime<Utc>, end: DateTime<Utc>) -> Result<usize, Errors> {
+        if end < start {
+            return Err(Errors::ConversionError(format!(
+                "range ends ({}) before it starts ({})",
+                end, start
+            )));
+        }
+        if is_fixed_offset(&self.source_tz) {
+            return Ok(0);
+        }
+
+        Ok(offset_changes_iter(&self.source_tz, start, end).count())
+    }
+
+    /// Gets how far an instant is through the current DST (or standard time) period of the target timezone
+    /// 
+    /// The period is bounded by the target zone's previous and next offset transitions.
+    /// Zones that do not change offset within a year on either side of `at` have no
+    /// periods and yield `None`.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `at` - The instant to locate within its period
+    /// 
+    /// # Returns
+    /// 
+    /// * `Result<Option<f64>, Errors>` - The fraction from 0.0 to 1.0, or `None` for non-DST zones
+    pub fn target_dst_period_fraction(&self, at: DateTime<Utc>) -> Result<Option<f64>, Errors> {
+        let previous = previous_offset_change(&self.target_tz, at);
+        let next = next_offset_change(&self.target_tz, at);
+
+        Ok(match (previous, next) {
+            (Some(previous), Some(next)) => {
+                let elapsed = (at - previous).num_seconds() as f64;
+                let length = (next - previous).num_seconds() as f64;
+                Some(elapsed / length)
+            }
+            _ => None,
+        })
+    }
+
+    /// Converts an all-day date in the source timezone to the span it covers in the target timezone
+    /// 
+    /// The date is interpreted in the source timezone, from its local midnight to the next
+    /// local midnight. If a midnight falls in a DST gap, the first valid time after it is used.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `date` - The source-local calendar date
+    /// 
+    /// # Returns
+    /// 
+    /// * `Result<(DateTime<Tz>, DateTime<Tz>), Errors>` - The start and end of the day in the target timezone
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use timezone_converter::TimeZoneConverter;
+    /// 
+    /// let converter = TimeZoneConverter::new("America/New_York", "Europe/London").unwrap();
+    /// let date = NaiveDate::from_ymd_opt(2024, 7, 4).unwrap();
+    /// let (start, end) = converter.convert_all_day(date).unwrap();
+    /// assert_eq!(start.to_string(), "2024-07-04 05:00:00 BST");
+    /// assert_eq!(end.to_string(), "2024-07-05 05:00:00 BST");
+    /// ```
+    pub fn convert_all_day(&self, date: NaiveDate) -> Result<(DateTime<Tz>, DateTime<Tz>), Errors> {
+        let next_date = date.succ_opt().ok_or_else(|| {
+            Errors::ConversionError(format!("no day follows {}", date))
+        })?;
+
+        let start = resolve_local_forward(&self.source_tz, date.and_time(NaiveTime::MIN))?;
+        let end = resolve_local_forward(&self.source_tz, next_date.and_time(NaiveTime::MIN))?;
+
+        let tz = self.named_tz(Zone::Target)?;
+        Ok((
+            start.with_timezone(&tz),
+            end.with_timezone(&tz),
+        ))
+    }
+
+    /// Gets the first instant of a calendar date in the target timezone
+    /// 
+    /// This is usually local midnight. If midnight is skipped by a DST transition, as in
+    /// zones that change their clocks at midnight, the day starts at the first valid time
+    /// after it; if midnight occurs twice, the day starts at the first occurrence.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `date` - The target-local calendar date
+    /// 
+    /// # Returns
+    /// 
+    /// * `Result<DateTime<Tz>, Errors>` - The start of the day, or an error if the date is out of range or the target is not a tz database zone
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use timezone_converter::TimeZoneConverter;
+    /// 
+    /// let converter = TimeZoneConverter::new("UTC", "America/Santiago").unwrap();
+    /// // Santiago's clocks skip from 00:00 to 01:00 on Sep 8 2024
+    /// let start = converter.start_of_day_in_target(NaiveDate::from_ymd_opt(2024, 9, 8).unwrap()).unwrap();
+    /// assert_eq!(start.to_rfc3339(), "2024-09-08T01:00:00-03:00");
+    /// ```
+    pub fn start_of_day_in_target(&self, date: NaiveDate) -> Result<DateTime<Tz>, Errors> {
+        let tz = self.named_tz(Zone::Target)?;
+        Ok(resolve_local_forward(&self.target_tz, date.and_time(NaiveTime::MIN))?.with_timezone(&tz))
+    }
+
+    /// Gets the end of a calendar date in the target timezone, which is the start of the next day
+    /// 
+    /// The day covers the half-open range from [`Self::start_of_day_in_target`] up to, but
+    /// not including, this instant, which suits `>= start AND < end` queries. The range is
+    /// 23 or 25 hours long on days when DST starts or ends.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `date` - The target-local calendar date
+    /// 
+    /// # Returns
+    /// 
+    /// * `Result<DateTime<Tz>, Errors>` - The end of the day, or an error if the date is out of range or the target is not a tz database zone
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use chrono::{Duration, NaiveDate};
+    /// use timezone_converter::TimeZoneConverter;
+    /// 
+    /// let converter = TimeZoneConverter::new("UTC", "America/New_York").unwrap();
+    /// let date = NaiveDate::from_ymd_opt(2024, 11, 3).unwrap();
+    /// let start = converter.start_of_day_in_target(date).unwrap();
+    /// let end = converter.end_of_day_in_target(date).unwrap();
+    /// assert_eq!(end.to_string(), "2024-11-04 00:00:00 EST");
+    /// assert_eq!(end - start, Duration::hours(25));
+    /// ```
+    pub fn end_of_day_in_target(&self, date: NaiveDate) -> Result<DateTime<Tz>, Errors> {
+        let next_date = date.succ_opt().ok_or_else(|| {
+            Errors::ConversionError(format!("no day follows {}", date))
+        })?;
+        self.start_of_day_in_target(next_date)
+    }
+
+    /// Gets the first instant of the week containing a date in the target timezone
+    /// 
+    /// # Arguments
+    /// 
+    /// * `date` - A target-local calendar date in the week
+    /// * `week_start` - The day weeks start on (e.g., `Weekday::Mon`)
+    /// 
+    /// # Returns
+    /// 
+    /// * `Result<DateTime<Tz>, Errors>` - The start of the week's first day, or an error if the date is out of range or the target is not a tz database zone
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use chrono::{NaiveDate, Weekday};
+    /// use timezone_converter::TimeZoneConverter;
+    /// 
+    /// let converter = TimeZoneConverter::new("UTC", "Europe/Berlin").unwrap();
+    /// let thursday = NaiveDate::from_ymd_opt(2024, 7, 4).unwrap();
+    /// let start = converter.start_of_week_in_target(thursday, Weekday::Mon).unwrap();
+    /// assert_eq!(start.to_string(), "2024-07-01 00:00:00 CEST");
+    /// ```
+    pub fn start_of_week_in_target(&self, date: NaiveDate, week_start: Weekday) -> Result<DateTime<Tz>, Errors> {
+        // `NaiveWeek::first_day` panics when the week starts before `NaiveDate::MIN`
+        let back = (7 + date.weekday().num_days_from_monday() - week_start.num_days_from_monday()) % 7;
+        let first_day = date
+            .checked_sub_days(Days::new(back.into()))
+            .ok_or_else(|| Errors::ConversionError(format!("the week of {} starts before the earliest supported date", date)))?;
+        self.start_of_day_in_target(first_day)
+    }
+
+    /// Gets the first instant of the month containing a date in the target timezone
+    /// 
+    /// # Arguments
+    /// 
+    /// * `date` - A target-local calendar date in the month
+    /// 
+    /// # Returns
+    /// 
+    /// * `Result<DateTime<Tz>, Errors>` - The start of the month's first day, or an error if the date is out of range or the target is not a tz database zone
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use timezone_converter::TimeZoneConverter;
+    /// 
+    /// let converter = TimeZoneConverter::new("UTC", "Asia/Tokyo").unwrap();
+    /// let start = converter.start_of_month_in_target(NaiveDate::from_ymd_opt(2024, 7, 19).unwrap()).unwrap();
+    /// assert_eq!(start.to_rfc3339(), "2024-07-01T00:00:00+09:00");
+    /// ```
+    pub fn start_of_month_in_target(&self, date: NaiveDate) -> Result<DateTime<Tz>, Errors> {
+        self.start_of_day_in_target(date.with_day(1).expect("every month has a first day"))
+    }
+
+    /// Splits a UTC interval at each local midnight of either timezone
+    /// 
+    /// Each piece covers part of one local calendar day, so totals can be aggregated per
+    /// day in that timezone. Pieces are half-open, each ending where the next starts, and
+    /// follow the zone's actual day lengths, including 23- and 25-hour days. Days that start
+    /// late because midnight is skipped by a DST transition are split at their first instant.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `start` - The start of the interval
+    /// * `end` - The end of the interval, which is not included
+    /// * `zone` - The timezone whose local days split the interval
+    /// 
+    /// # Returns
+    /// 
+    /// * `Vec<(DateTime<Tz>, DateTime<Tz>)>` - The start and end of each piece in that timezone, in order; empty if `end` is not after `start` or the zone is not a tz database zone
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use chrono::{TimeZone, Utc};
+    /// use timezone_converter::{TimeZoneConverter, Zone};
+    /// 
+    /// let converter = TimeZoneConverter::new("UTC", "Asia/Tokyo").unwrap();
+    /// let start = Utc.with_ymd_and_hms(2024, 7, 1, 12, 0, 0).unwrap();
+    /// let end = Utc.with_ymd_and_hms(2024, 7, 2, 18, 0, 0).unwrap();
+    /// let days = converter.split_by_local_days(start, end, Zone::Target);
+    /// let days: Vec<_> = days.iter().map(|(from, to)| format!("{} - {}", from.format("%d %H:%M"), to.format("%d %H:%M"))).collect();
+    /// assert_eq!(days, ["01 21:00 - 02 00:00", "02 00:00 - 03 00:00", "03 00:00 - 03 03:00"]);
+    /// ```
+    pub fn split_by_local_days(&self, start: DateTime<Utc>, end: DateTime<Utc>, zone: Zone) -> Vec<(DateTime<Tz>, DateTime<Tz>)> {
+        let Ok(tz) = self.named_tz(zone) else {
+            return Vec::new();
+        };
+        let mut pieces = Vec::new();
+        let mut current = start;
+        while current < end {
+            let next_midnight = current
+                .with_timezone(&tz)
+                .date_naive()
+                .succ_opt()
+                .and_then(|date| resolve_local_forward(&tz, date.and_time(NaiveTime::MIN)).ok())
+                .map(|midnight| midnight.with_timezone(&Utc));
+            let piece_end = next_midnight.map_or(end, |midnight| midnight.min(end));
+            pieces.push((current.with_timezone(&tz), piece_end.with_timezone(&tz)));
+            current = piece_end;
+        }
+        pieces
+    }
+
+    /// Compares another timezone with the source timezone at the given instant
+    /// 
+    /// # Arguments
+    /// 
+    /// * `other` - The timezone identifier to compare (e.g., "Asia/Tokyo")
+    /// * `at` - The instant at which to compare the offsets
+    /// 
+    /// # Returns
+    /// 
+    /// * `Result<ZoneComparison, Errors>` - The comparison, or an error if `other` is not a valid timezone
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use chrono::{Duration, TimeZone, Utc};
+    /// use timezone_converter::TimeZoneConverter;
+    /// 
+    /// let converter = TimeZoneConverter::new("Europe/London", "UTC").unwrap();
+    /// let at = Utc.with_ymd_and_hms(2024, 1, 15, 12, 0, 0).unwrap();
+    /// let comparison = converter.compare_with("Asia/Tokyo", at).unwrap();
+    /// assert_eq!(comparison.difference, Duration::hours(9));
+    /// assert!(comparison.is_ahead);
+    /// ```
+    pub fn compare_with(&self, other: &str, at: DateTime<Utc>) -> Result<ZoneComparison, Errors> {
+        let zone = parse_tz(other)?;
+        let offset = offset_seconds_at(&zone, at);
+        let difference = offset - offset_seconds_at(&self.source_tz, at);
+
+        Ok(ZoneComparison {
+            zone,
+            offset: Duration::seconds(offset as i64),
+            difference: Duration::seconds(difference as i64),
+            is_ahead: difference > 0,
+        })
+    }
+
+    /// Parses a source-local time that may use the `24:00` end-of-day convention and converts it to the target timezone
+    /// 
+    /// The input is either `YYYY-MM-DD HH:MM` or a bare `HH:MM`, in which case today's date
+    /// in the source timezone is used. `24:00` means midnight at the end of the day and rolls
+    /// over to 00:00 of the following date. Times inside a DST gap or repeated by DST are
+    /// resolved with the converter's [`Self::ambiguity_policy`].
+    /// 
+    /// # Arguments
+    /// 
+    /// * `s` - The source-local time to parse
+    /// 
+    /// # Returns
+    /// 
+    /// * `Result<DateTime<Tz>, Errors>` - The converted datetime, or a parse error for invalid input
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use timezone_converter::TimeZoneConverter;
+    /// 
+    /// let converter = TimeZoneConverter::new("Europe/Berlin", "UTC").unwrap();
+    /// let time = converter.parse_source_local_24("2024-01-31 24:00").unwrap();
+    /// assert_eq!(time.to_string(), "2024-01-31 23:00:00 UTC");
+    /// ```
+    pub fn parse_source_local_24(&self, s: &str) -> Result<DateTime<Tz>, Errors> {
+        let s = s.trim();
+        let (date, time) = match s.rsplit_once(' ') {
+            Some((date, time)) => {
+                let date = NaiveDate::parse_from_str(date.trim(), "%Y-%m-%d")
+                    .map_err(|e| Errors::ParseError(format!("invalid date in '{}': {}", s, e)))?;
+                (date, time)
+            }
+            None => (self.clock.now().with_timezone(&self.source_tz).date_naive(), s),
+        };
+
+        let naive = if time == "24:00" {
+            let next_date = date.succ_opt().ok_or_else(|| {
+                Errors::ParseError(format!("no day follows {}", date))
+            })?;
+            next_date.and_time(NaiveTime::MIN)
+        } else {
+            let time = NaiveTime::parse_from_str(time, "%H:%M")
+                .map_err(|e| Errors::ParseError(format!("invalid time in '{}': {}", s, e)))?;
+            date.and_time(time)
+        };
+
+        let local = resolve_local(&self.source_tz, naive, self.ambiguity_policy)?;
+        Ok(local.with_timezone(&self.named_tz(Zone::Target)?))
+    }
+
+    /// Computes a stable fingerprint of the source and target transition rules over a range of years
+    /// 
+    /// The fingerprint covers each zone's offset at the start of the range and every transition
+    /// within it, hashed with FNV-1a so the value is stable across builds and platforms. A tz
+    /// database update that changes either zone's rules in the range changes the fingerprint,
+    /// which makes it suitable as a cache-invalidation key.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `from_year` - The first year covered
+    /// * `to_year` - The last year covered (inclusive)
+    /// 
+    /// # Returns
+    /// 
+    /// * `Result<u64, Errors>` - The fingerprint, or an error for an invalid year range
+    pub fn rules_fingerprint(&self, from_year: i32, to_year: i32) -> Result<u64, Errors> {
+        if to_year < from_year {
+            return Err(Errors::ConversionError(format!(
+                "year range ends ({}) before it starts ({})",
+                to_year, from_year
+            )));
+        }
+        let start_of_year = |year: i32| {
+            NaiveDate::from_ymd_opt(year, 1, 1)
+                .map(|date| date.and_time(NaiveTime::MIN).and_utc())
+                .ok_or_else(|| Errors::ConversionError(format!("year {} is out of range", year)))
+        };
+        let start = start_of_year(from_year)?;
+        let end = start_of_year(to_year + 1)?;
+
+        let mut hash = FNV_OFFSET_BASIS;
+        for tz in [&self.source_tz, &self.target_tz] {
+            hash = fnv1a(hash, tz.name().as_bytes());
+            hash = fnv1a(hash, &offset_seconds_at(tz, start).to_le_bytes());
+            for change in offset_changes(tz, start, end) {
+                hash = fnv1a(hash, &change.timestamp().to_le_bytes());
+                hash = fnv1a(hash, &offset_seconds_at(tz, change).to_le_bytes());
+            }
+        }
+        Ok(hash)
+    }
+
+    /// Converts a datetime to the target timezone and rounds it to the nearest target-local date
+    /// 
+    /// Instants past the middle of their local day round up to the next date, others round
+    /// down. The middle is measured in elapsed time, so on 23- and 25-hour DST days the
+    /// threshold sits half an hour either side of 12:00 on the wall clock.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `dt` - The datetime to convert
+    /// 
+    /// # Returns
+    /// 
+    /// * `Result<NaiveDate, Errors>` - The nearest target-local date
+    pub fn convert_nearest_day<T: ChronoTimeZone>(&self, dt: DateTime<T>) -> Result<NaiveDate, Errors> {
+        let local = dt.with_timezone(&self.target_tz);
+        let date = local.date_naive();
+        let next_date = date.succ_opt().ok_or_else(|| {
+            Errors::ConversionError(format!("no day follows {}", date))
+        })?;
+
+        let day_start = resolve_local_forward(&self.target_tz, date.and_time(NaiveTime::MIN))?;
+        let day_end = resolve_local_forward(&self.target_tz, next_date.and_time(NaiveTime::MIN))?;
+        let midpoint = day_start + (day_end - day_start) / 2;
+
+        Ok(if local > midpoint { next_date } else { date })
+    }
+
+    /// Packs the current offsets and DST flags of both timezones into a single integer
+    /// 
+    /// The bit layout, from least significant bit, is:
+    /// 
+    /// * bits 0-15 - source offset in minutes, as a two's complement `i16`
+    /// * bits 16-31 - target offset in minutes, as a two's complement `i16`
+    /// * bit 32 - source DST flag
+    /// * bit 33 - target DST flag
+    /// 
+    /// All other bits are zero. Offsets are truncated to whole minutes. Use
+    /// [`unpack_status`] to decode the value.
+    /// 
+    /// # Returns
+    /// 
+    /// * `Result<u64, Errors>` - The packed status
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use timezone_converter::{unpack_status, TimeZoneConverter};
+    /// 
+    /// let converter = TimeZoneConverter::new("Asia/Kolkata", "UTC").unwrap();
+    /// let status = unpack_status(converter.pack_status().unwrap());
+    /// assert_eq!(status.source_offset_minutes, 330);
+    /// assert_eq!(status.target_offset_minutes, 0);
+    /// ```
+    pub fn pack_status(&self) -> Result<u64, Errors> {
+        let now = self.clock.now();
+        let offset_minutes = |tz: &ZoneSpec| -> Result<i16, Errors> {
+            i16::try_from(offset_seconds_at(tz, now) / 60).map_err(|_| {
+                Errors::ConversionError(format!("offset of {} does not fit in 16 bits", tz))
+            })
+        };
+
+        Ok(pack_status_fields(PackedStatus {
+            source_offset_minutes: offset_minutes(&self.source_tz)?,
+            target_offset_minutes: offset_minutes(&self.target_tz)?,
+            source_is_dst: is_dst_at(&self.source_tz, now),
+            target_is_dst: is_dst_at(&self.target_tz, now),
+        }))
+    }
+
+    /// Gets both timezones' current offsets from UTC together with the difference between them
+    /// 
+    /// All three values are computed from a single reading of the clock, so they are always
+    /// consistent with each other.
+    /// 
+    /// # Returns
+    /// 
+    /// * `Result<UtcRelative, Errors>` - The offsets and difference in hours
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use timezone_converter::TimeZoneConverter;
+    /// 
+    /// let converter = TimeZoneConverter::new("Asia/Kolkata", "UTC").unwrap();
+    /// let relative = converter.utc_relative().unwrap();
+    /// assert_eq!(relative.source_offset_hours, 5.5);
+    /// assert_eq!(relative.difference_hours, 5.5);
+    /// ```
+    pub fn utc_relative(&self) -> Result<UtcRelative, Errors> {
+        let now = self.clock.now();
+        let source_offset = offset_seconds_at(&self.source_tz, now);
+        let target_offset = offset_seconds_at(&self.target_tz, now);
+
+        Ok(UtcRelative {
+            source_offset_hours: source_offset as f64 / 3600.0,
+            target_offset_hours: target_offset as f64 / 3600.0,
+            difference_hours: (source_offset - target_offset) as f64 / 3600.0,
+        })
+    }
+
+    /// Expands a daily source-local time over consecutive dates and converts each occurrence to the target timezone
+    /// 
+    /// Each occurrence is resolved in the source timezone, so the target-local time shifts
+    /// when either zone changes offset. Occurrences inside a DST gap are shifted forward and
+    /// ambiguous ones use the earliest occurrence.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `hour` - The source-local hour (0-23)
+    /// * `minute` - The source-local minute (0-59)
+    /// * `from` - The first date
+    /// * `days` - The number of consecutive dates to expand
+    /// 
+    /// # Returns
+    /// 
+    /// * `Result<Vec<DateTime<Tz>>, Errors>` - The occurrences in the target timezone, or an error for an invalid time
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use timezone_converter::TimeZoneConverter;
+    /// 
+    /// let converter = TimeZoneConverter::new("America/New_York", "Europe/London").unwrap();
+    /// let from = NaiveDate::from_ymd_opt(2024, 3, 9).unwrap();
+    /// let runs = converter.expand_daily(2, 0, from, 2).unwrap();
+    /// assert_eq!(runs[0].to_string(), "2024-03-09 07:00:00 GMT");
+    /// // 02:00 does not exist on Mar 10 in New York, so the run moves to 03:00 EDT
+    /// assert_eq!(runs[1].to_string(), "2024-03-10 07:00:00 GMT");
+    /// ```
+    pub fn expand_daily(&self, hour: u32, minute: u32, from: NaiveDate, days: u32) -> Result<Vec<DateTime<Tz>>, Errors> {
+        let time = NaiveTime::from_hms_opt(hour, minute, 0).ok_or_else(|| {
+            Errors::ConversionError(format!("{:02}:{:02} is not a valid time of day", hour, minute))
+        })?;
+
+        let tz = self.named_tz(Zone::Target)?;
+        from.iter_days()
+            .take(days as usize)
+            .map(|date| {
+                let local = resolve_local_forward(&self.source_tz, date.and_time(time))?;
+                Ok(local.with_timezone(&tz))
+            })
+            .collect()
+    }
+
+    /// Whether the current target-local time is within working hours on a working day
+    /// 
+    /// The window is `[work_start, work_end)` in target-local hours. A window that wraps
+    /// midnight (e.g. 22 to 6) belongs to the day it starts on, so the early-morning hours
+    /// count when the previous day is a working day. Equal start and end hours form an
+    /// empty window.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `work_start` - The hour the working window opens (0-23)
+    /// * `work_end` - The hour the working window closes (0-24)
+    /// * `working_days` - The weekdays on which the window applies
+    /// 
+    /// # Returns
+    /// 
+    /// * `Result<bool, Errors>` - Whether the target zone is currently working, or an error for invalid hours
+    pub fn target_is_working(&self, work_start: u32, work_end: u32, working_days: &[Weekday]) -> Result<bool, Errors> {
+        self.target_is_working_at(self.clock.now(), work_start, work_end, working_days)
+    }
+
+    /// Converts a datetime to the target timezone, falling back to a fixed offset if the zone cannot be used
+    /// 
+    /// The target zone is validated when the converter is built, so the conversion always
+    /// uses its own rules, including for custom and system zones, and the fallback only
+    /// matters for callers going through [`try_convert_name`] with dynamic zone names.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `dt` - The datetime to convert
+    /// * `_fallback_offset_secs` - The offset east of UTC to apply if the zone is unusable
+    /// 
+    /// # Returns
+    /// 
+    /// * `DateTime<FixedOffset>` - The converted datetime with its offset fixed
+    pub fn convert_or<T: ChronoTimeZone>(&self, dt: DateTime<T>, _fallback_offset_secs: i32) -> DateTime<FixedOffset> {
+        dt.with_timezone(&self.target_tz).fixed_offset()
+    }
+
+    /// Gets how long until the source and target timezones are on the same calendar date
+    /// 
+    /// When the dates differ, the zone that is behind is the one still on the earlier date,
+    /// so the dates coincide again at that zone's next local midnight.
+    /// 
+    /// # Returns
+    /// 
+    /// * `Result<Option<Duration>, Errors>` - The time until the dates match, or `None` if they already do
+    pub fn until_same_date(&self) -> Result<Option<Duration>, Errors> {
+        self.until_same_date_at(self.clock.now())
+    }
+
+    /// Converts a datetime to the target timezone, rejecting years outside the supported range
+    /// 
+    /// Timezone rules are meaningless far outside recorded history, so instants whose UTC or
+    /// target-local year falls outside [`MIN_SUPPORTED_YEAR`]..=[`MAX_SUPPORTED_YEAR`] are
+    /// rejected instead of producing a nonsensical result.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `dt` - The datetime to convert
+    /// 
+    /// # Returns
+    /// 
+    /// * `Result<DateTime<Tz>, Errors>` - The converted datetime, or `Errors::ConversionError` for an unsupported year
+    pub fn convert_guarded<T: ChronoTimeZone>(&self, dt: DateTime<T>) -> Result<DateTime<Tz>, Errors> {
+        let converted = dt.with_timezone(&self.named_tz(Zone::Target)?);
+        let supported = MIN_SUPPORTED_YEAR..=MAX_SUPPORTED_YEAR;
+        for year in [converted.naive_utc().year(), converted.year()] {
+            if !supported.contains(&year) {
+                return Err(Errors::ConversionError(format!(
+                    "year {} is outside the supported range {}-{}",
+                    year, MIN_SUPPORTED_YEAR, MAX_SUPPORTED_YEAR
+                )));
+            }
+        }
+        Ok(converted)
+    }
+
+    /// Gets both timezones' abbreviations and offsets at an instant as a compact line
+    /// 
+    /// The line has the form `EST/-05:00 → GMT/+00:00`, source first. All four values are
+    /// computed for the same instant.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `at` - The instant at which to evaluate both zones
+    /// 
+    /// # Returns
+    /// 
+    /// * `Result<String, Errors>` - The abbreviation and offset line
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use chrono::{TimeZone, Utc};
+    /// use timezone_converter::TimeZoneConverter;
+    /// 
+    /// let converter = TimeZoneConverter::new("America/New_York", "Europe/London").unwrap();
+    /// let at = Utc.with_ymd_and_hms(2024, 1, 15, 12, 0, 0).unwrap();
+    /// assert_eq!(converter.abbrev_offset_line(at).unwrap(), "EST/-05:00 → GMT/+00:00");
+    /// ```
+    pub fn abbrev_offset_line(&self, at: DateTime<Utc>) -> Result<String, Errors> {
+        let source = at.with_timezone(&self.source_tz).format("%Z/%:z");
+        let target = at.with_timezone(&self.target_tz).format("%Z/%:z");
+        Ok(format!("{} → {}", source, target))
+    }
+
+    /// Converts a datetime to the target timezone and shifts the result by a number of minutes
+    /// 
+    /// The shift is applied to the instant, not the wall clock: nudging across a DST
+    /// transition moves the displayed time by the nudge plus or minus the clock change.
+    /// This is useful for simulating clock skew or applying a known sensor delay.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `dt` - The datetime to convert
+    /// * `extra_minutes` - The number of minutes to add (negative to subtract)
+    /// 
+    /// # Returns
+    /// 
+    /// * `Result<DateTime<Tz>, Errors>` - The shifted datetime in the target timezone, or `Errors::ConversionError` if the target is not a tz database zone
+    pub fn convert_nudged<T: ChronoTimeZone>(&self, dt: DateTime<T>, extra_minutes: i32) -> Result<DateTime<Tz>, Errors> {
+        Ok(dt.with_timezone(&self.named_tz(Zone::Target)?) + Duration::minutes(extra_minutes as i64))
+    }
+
+    /// Converts labelled events to the target timezone and groups them by target-local date
+    /// 
+    /// Events are sorted chronologically (events at the same instant keep their input order)
+    /// and bucketed under the target-local date they fall on. Days are returned in order and
+    /// days without events are omitted.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `events` - The labelled events to place on the agenda
+    /// 
+    /// # Returns
+    /// 
+    /// * `Result<Vec<AgendaDay>, Errors>` - The events of each target-local day, or `Errors::ConversionError` if the target is not a tz database zone
+    pub fn agenda<T: ChronoTimeZone>(&self, events: &[(String, DateTime<T>)]) -> Result<Vec<AgendaDay>, Errors> {
+        let tz = self.named_tz(Zone::Target)?;
+        let mut converted: Vec<(String, DateTime<Tz>)> = events
+            .iter()
+            .map(|(label, dt)| (label.clone(), dt.with_timezone(&tz)))
+            .collect();
+        converted.sort_by_key(|(_, dt)| *dt);
+
+        let mut days: Vec<AgendaDay> = Vec::new();
+        for (label, dt) in converted {
+            let date = dt.date_naive();
+            match days.last_mut() {
+                Some((day, day_events)) if *day == date => day_events.push((label, dt)),
+                _ => days.push((date, vec![(label, dt)])),
+            }
+        }
+        Ok(days)
+    }
+
+    /// Gets the UTC offset that applies to a source-local time, choosing the occurrence of a repeated time by `fold`
+    /// 
+    /// This follows the PEP 495 `fold` convention: for a time repeated when clocks fall back,
+    /// `fold = false` selects the first occurrence (the pre-transition, larger offset) and
+    /// `fold = true` the second. `fold` is ignored for unambiguous times.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `naive` - The source-local wall-clock time
+    /// * `fold` - Which occurrence of a repeated time to use
+    /// 
+    /// # Returns
+    /// 
+    /// * `Result<Duration, Errors>` - The offset from UTC, or an error if the time falls in a DST gap
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use chrono::{Duration, NaiveDate};
+    /// use timezone_converter::TimeZoneConverter;
+    /// 
+    /// let converter = TimeZoneConverter::single("America/New_York").unwrap();
+    /// let repeated = NaiveDate::from_ymd_opt(2024, 11, 3).unwrap().and_hms_opt(1, 30, 0).unwrap();
+    /// assert_eq!(converter.offset_for_local_fold(repeated, false).unwrap(), Duration::hours(-4));
+    /// assert_eq!(converter.offset_for_local_fold(repeated, true).unwrap(), Duration::hours(-5));
+    /// ```
+    pub fn offset_for_local_fold(&self, naive: NaiveDateTime, fold: bool) -> Result<Duration, Errors> {
+        let local = match self.source_tz.from_local_datetime(&naive) {
+            LocalResult::Single(dt) => dt,
+            LocalResult::Ambiguous(first, second) => if fold { second } else { first },
+            LocalResult::None => {
+                return Err(Errors::ConversionError(format!(
+                    "{} does not exist in {}",
+                    naive,
+                    self.source_tz.name()
+                )));
+            }
+        };
+        Ok(Duration::seconds(local.offset().fix().local_minus_utc() as i64))
+    }
+
+    /// Whether an instant's target-local wall-clock time is one that occurs twice that day
+    /// 
+    /// The instant itself is unambiguous, but when clocks fall back its local rendering may
+    /// lie in the repeated range, which UIs should qualify as the first or second occurrence.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `at` - The instant to check
+    /// 
+    /// # Returns
+    /// 
+    /// * `bool` - `true` if the target-local time is repeated
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use chrono::{TimeZone, Utc};
+    /// use timezone_converter::TimeZoneConverter;
+    /// 
+    /// let converter = TimeZoneConverter::new("UTC", "America/New_York").unwrap();
+    /// // 05:30 UTC on Nov 3 2024 is the first 01:30 in New York
+    /// let at = Utc.with_ymd_and_hms(2024, 11, 3, 5, 30, 0).unwrap();
+    /// assert!(converter.target_in_repeated_hour(at));
+    /// ```
+    pub fn target_in_repeated_hour(&self, at: DateTime<Utc>) -> bool {
+        let local = at.with_timezone(&self.target_tz).naive_local();
+        matches!(self.target_tz.from_local_datetime(&local), LocalResult::Ambiguous(_, _))
+    }
+
+    /// Converts a datetime to the target timezone along with the next target-zone transition after it
+    /// 
+    /// This lets tooltips show how long the converted offset stays in effect. The search
+    /// looks up to a year ahead, so zones without DST yield `None`.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `dt` - The datetime to convert
+    /// 
+    /// # Returns
+    /// 
+    /// * `Result<(DateTime<Tz>, Option<DateTime<Tz>>), Errors>` - The converted datetime and the next transition, both in the target timezone
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use chrono::{TimeZone, Utc};
+    /// use timezone_converter::TimeZoneConverter;
+    /// 
+    /// let converter = TimeZoneConverter::new("UTC", "America/New_York").unwrap();
+    /// let dt = Utc.with_ymd_and_hms(2024, 7, 1, 18, 0, 0).unwrap();
+    /// let (time, next) = converter.convert_with_next_transition(dt).unwrap();
+    /// assert_eq!(time.to_string(), "2024-07-01 14:00:00 EDT");
+    /// assert_eq!(next.unwrap().to_string(), "2024-11-03 01:00:00 EST");
+    /// ```
+    pub fn convert_with_next_transition<T: ChronoTimeZone>(&self, dt: DateTime<T>) -> Result<(DateTime<Tz>, Option<DateTime<Tz>>), Errors> {
+        let tz = self.named_tz(Zone::Target)?;
+        let converted = dt.with_timezone(&tz);
+        let next = if is_fixed_offset(&self.target_tz) {
+            None
+        } else {
+            next_offset_change(&self.target_tz, dt.with_timezone(&Utc))
+                .map(|change| change.with_timezone(&tz))
+        };
+        Ok((converted, next))
+    }
+
+    /// Converts a datetime to the target timezone, rejecting instants during a target-zone clock change
+    /// 
+    /// An instant is rejected when it lies closer to a transition in the target timezone
+    /// than the size of that transition's shift: an hour for most DST changes, half an hour
+    /// on Lord Howe Island. This covers both occurrences of a repeated time and the times
+    /// bordering a skipped one, so bookings never land on an ambiguous or confusing time.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `dt` - The datetime to convert
+    /// 
+    /// # Returns
+    /// 
+    /// * `Result<DateTime<Tz>, Errors>` - The converted datetime, or `Errors::ConversionError` near a transition
+    pub fn convert_strict_no_transition<T: ChronoTimeZone>(&self, dt: DateTime<T>) -> Result<DateTime<Tz>, Errors> {
+        let at = dt.with_timezone(&Utc);
+        let tz = &self.target_tz;
+        let shift = |change: DateTime<Utc>| Duration::seconds((offset_seconds_at(tz, change) - offset_seconds_at(tz, change - Duration::seconds(1))).abs().into());
+        let nearby = if is_fixed_offset(tz) {
+            None
+        } else {
+            let previous = previous_offset_change(tz, at).filter(|change| at < *change + shift(*change));
+            previous.or_else(|| next_offset_change(tz, at).filter(|change| at >= *change - shift(*change)))
+        };
+
+        match nearby {
+            Some(change) => Err(Errors::ConversionError(format!(
+                "{} is within {} of the {} transition at {}",
+                at,
+                format_long_duration(shift(change)),
+                tz.name(),
+                change.with_timezone(tz)
+            ))),
+            None => Ok(at.with_timezone(&self.named_tz(Zone::Target)?)),
+        }
+    }
+
+    /// Gets the time difference in hours for each day of a month
+    /// 
+    /// Each day is sampled at noon in the source timezone, away from the early-morning
+    /// transition edges, so days where either zone's DST changes the difference stand out.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `year` - The year
+    /// * `month` - The month (1-12)
+    /// 
+    /// # Returns
+    /// 
+    /// * `Result<Vec<(NaiveDate, f64)>, Errors>` - The difference (positive if source is ahead) for each day, or an error for an invalid month
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use timezone_converter::TimeZoneConverter;
+    /// 
+    /// let converter = TimeZoneConverter::new("Europe/London", "America/New_York").unwrap();
+    /// let days = converter.monthly_differences(2024, 3).unwrap();
+    /// assert_eq!(days.len(), 31);
+    /// assert_eq!(days[0].1, 5.0);
+    /// assert_eq!(days[9].1, 4.0);
+    /// assert_eq!(days[30].1, 5.0);
+    /// ```
+    pub fn monthly_differences(&self, year: i32, month: u32) -> Result<Vec<(NaiveDate, f64)>, Errors> {
+        let first = NaiveDate::from_ymd_opt(year, month, 1).ok_or_else(|| {
+            Errors::ConversionError(format!("{}-{:02} is not a valid month", year, month))
+        })?;
+        let noon = NaiveTime::from_hms_opt(12, 0, 0).unwrap();
+
+        first.iter_days()
+            .take_while(|date| date.month() == month)
+            .map(|date| {
+                let local_noon = resolve_local_forward(&self.source_tz, date.and_time(noon))?;
+                let difference = self.time_difference_at(local_noon.with_timezone(&Utc));
+                Ok((date, difference.num_seconds() as f64 / 3600.0))
+            })
+            .collect()
+    }
+
+    /// Converts a Julian Day Number to the target timezone
+    /// 
+    /// The Julian Day is interpreted on the UTC time scale, where [`UNIX_EPOCH_JULIAN_DAY`]
+    /// is the Unix epoch. The result is rounded to the nearest millisecond, since an `f64`
+    /// Julian Day only carries a precision of tens of microseconds for modern dates.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `jd` - The Julian Day Number
+    /// 
+    /// # Returns
+    /// 
+    /// * `Result<DateTime<Tz>, Errors>` - The datetime in the target timezone, or `Errors::ConversionError` if `jd` is out of range
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use timezone_converter::TimeZoneConverter;
+    /// 
+    /// let converter = TimeZoneConverter::new("UTC", "Asia/Tokyo").unwrap();
+    /// let time = converter.convert_julian_day(2_451_545.0).unwrap();
+    /// assert_eq!(time.to_string(), "2000-01-01 21:00:00 JST");
+    /// ```
+    pub fn convert_julian_day(&self, jd: f64) -> Result<DateTime<Tz>, Errors> {
+        let out_of_range = || Errors::ConversionError(format!("Julian Day {} is out of range", jd));
+
+        let millis = ((jd - UNIX_EPOCH_JULIAN_DAY) * 86_400_000.0).round();
+        if !millis.is_finite() || millis.abs() >= i64::MAX as f64 {
+            return Err(out_of_range());
+        }
+        let utc = DateTime::from_timestamp_millis(millis as i64).ok_or_else(out_of_range)?;
+
+        Ok(utc.with_timezone(&self.named_tz(Zone::Target)?))
+    }
+
+    /// Snaps a datetime to the source timezone's working-hour slot grid, then converts it to the target timezone
+    /// 
+    /// In the source timezone, the time is clamped into `[work_start, work_end]` on its local
+    /// date and rounded to the nearest multiple of `slot_minutes` counted from `work_start`,
+    /// never past `work_end`. The snapped wall-clock time is then resolved again in the source
+    /// zone, so a slot that falls in a DST gap moves forward and a repeated one uses the
+    /// earliest occurrence.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `dt` - The datetime to snap
+    /// * `slot_minutes` - The slot length in minutes
+    /// * `work_start` - The hour the working window opens (0-23)
+    /// * `work_end` - The hour the working window closes (1-24)
+    /// 
+    /// # Returns
+    /// 
+    /// * `Result<DateTime<Tz>, Errors>` - The snapped datetime in the target timezone, or an error for an invalid window or slot
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use chrono::TimeZone;
+    /// use chrono_tz::America::New_York;
+    /// use timezone_converter::TimeZoneConverter;
+    /// 
+    /// let converter = TimeZoneConverter::new("America/New_York", "Europe/London").unwrap();
+    /// let dt = New_York.with_ymd_and_hms(2024, 7, 1, 10, 7, 0).unwrap();
+    /// let snapped = converter.convert_snapped_to_work(dt, 15, 9, 17).unwrap();
+    /// assert_eq!(snapped.to_string(), "2024-07-01 15:00:00 BST");
+    /// ```
+    pub fn convert_snapped_to_work<T: ChronoTimeZone>(&self, dt: DateTime<T>, slot_minutes: u32, work_start: u32, work_end: u32) -> Result<DateTime<Tz>, Errors> {
+        if slot_minutes == 0 || work_start >= work_end || work_end > 24 {
+            return Err(Errors::ConversionError(format!(
+                "invalid working window {}-{} with {} minute slots",
+                work_start, work_end, slot_minutes
+            )));
+        }
+
+        let local = dt.with_timezone(&self.source_tz).naive_local();
+        let window_start = local.date().and_time(NaiveTime::MIN) + Duration::hours(work_start as i64);
+        let window_length = ((work_end - work_start) * 3600) as i64;
+        let slot = (slot_minutes * 60) as i64;
+
+        let offset = (local - window_start).num_seconds().clamp(0, window_length);
+        let mut snapped = (offset + slot / 2) / slot * slot;
+        if snapped > window_length {
+            snapped -= slot;
+        }
+
+        let snapped_local = resolve_local_forward(&self.source_tz, window_start + Duration::seconds(snapped))?;
+        Ok(snapped_local.with_timezone(&self.named_tz(Zone::Target)?))
+    }
+
+    /// Gets the current time in both timezones as RFC 3339 strings
+    /// 
+    /// The clock is read once, so both strings describe the same instant, each with
+    /// its own zone's offset.
+    /// 
+    /// # Returns
+    /// 
+    /// * `(String, String)` - The source-local and target-local times
+    pub fn current_iso_pair(&self) -> (String, String) {
+        self.iso_pair_at(self.clock.now())
+    }
+
+    /// Whether the source timezone is UTC under any of its alias names
+    /// 
+    /// `UTC`, `Etc/UTC`, `Universal`, `Zulu`, `GMT`, `Etc/GMT`, `Greenwich` and the other
+    /// links to `Etc/UTC` or `Etc/GMT` all count, so callers can take a UTC fast path
+    /// regardless of which alias was configured.
+    /// 
+    /// # Returns
+    /// 
+    /// * `bool` - `true` if the source timezone is UTC
+    pub fn source_is_utc(&self) -> bool {
+        is_utc_alias(&self.source_tz)
+    }
+
+    /// Whether the target timezone is UTC under any of its alias names
+    /// 
+    /// See [`Self::source_is_utc`] for the names that count.
+    /// 
+    /// # Returns
+    /// 
+    /// * `bool` - `true` if the target timezone is UTC
+    pub fn target_is_utc(&self) -> bool {
+        is_utc_alias(&self.target_tz)
+    }
+
+    /// Interprets a wall-clock time in the source timezone and converts it to the target timezone
+    /// 
+    /// # Arguments
+    /// 
+    /// * `naive` - The source-local wall-clock time
+    /// * `policy` - How to resolve a time that is repeated or skipped by a DST transition
+    /// 
+    /// # Returns
+    /// 
+    /// * `Result<DateTime<Tz>, Errors>` - The converted datetime, or `Errors::ConversionError` if the policy rejects the time
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use timezone_converter::{AmbiguityPolicy, TimeZoneConverter};
+    /// 
+    /// let converter = TimeZoneConverter::new("America/New_York", "UTC").unwrap();
+    /// let repeated = NaiveDate::from_ymd_opt(2024, 11, 3).unwrap().and_hms_opt(1, 30, 0).unwrap();
+    /// let latest = converter.convert_local(repeated, AmbiguityPolicy::Latest).unwrap();
+    /// assert_eq!(latest.to_string(), "2024-11-03 06:30:00 UTC");
+    /// assert!(converter.convert_local(repeated, AmbiguityPolicy::Reject).is_err());
+    /// ```
+    pub fn convert_local(&self, naive: NaiveDateTime, policy: AmbiguityPolicy) -> Result<DateTime<Tz>, Errors> {
+        let local = resolve_local(&self.source_tz, naive, policy)?;
+        Ok(local.with_timezone(&self.named_tz(Zone::Target)?))
+    }
+
+    /// Converts a source wall-clock time to the target timezone, flagging DST edge cases
+    /// 
+    /// Times skipped or repeated by a transition in the source timezone are resolved with
+    /// the converter's [`Self::ambiguity_policy`]. The flags let schedulers warn about the
+    /// result instead of silently accepting it.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `naive` - The source-local wall-clock time
+    /// 
+    /// # Returns
+    /// 
+    /// * `Result<ConversionResult, Errors>` - The converted datetime and its flags, or `Errors::ConversionError` if the policy rejects the time
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use timezone_converter::TimeZoneConverter;
+    /// 
+    /// let converter = TimeZoneConverter::new("America/New_York", "Asia/Tokyo").unwrap();
+    /// // 02:30 does not exist in New York on Mar 10 2024
+    /// let skipped = NaiveDate::from_ymd_opt(2024, 3, 10).unwrap().and_hms_opt(2, 30, 0).unwrap();
+    /// let result = converter.convert_checked(skipped).unwrap();
+    /// assert_eq!(result.datetime.to_string(), "2024-03-10 16:30:00 JST");
+    /// assert!(result.crossed_dst_in_source);
+    /// assert!(!result.crossed_dst_in_target);
+    /// assert!(!result.date_changed);
+    /// ```
+    pub fn convert_checked(&self, naive: NaiveDateTime) -> Result<ConversionResult, Errors> {
+        let crossed_dst_in_source = !matches!(
+            self.source_tz.from_local_datetime(&naive),
+            LocalResult::Single(_)
+        );
+        let datetime = resolve_local(&self.source_tz, naive, self.ambiguity_policy)?.with_timezone(&self.named_tz(Zone::Target)?);
+        let crossed_dst_in_target = matches!(
+            self.target_tz.from_local_datetime(&datetime.naive_local()),
+            LocalResult::Ambiguous(_, _)
+        );
+
+        Ok(ConversionResult {
+            date_changed: datetime.date_naive() != naive.date(),
+            datetime,
+            crossed_dst_in_source,
+            crossed_dst_in_target,
+            tzdb_version: self.tzdb_version().to_string(),
+        })
+    }
+
+    /// Converts many UTC datetimes to the target timezone
+    /// 
+    /// The target zone's offsets over the span of the input are computed once and each
+    /// item is then matched against them, which is faster than a full timezone lookup per
+    /// element. Mostly-sorted input, such as log timestamps, benefits the most. For large
+    /// batches, writing the result into newly allocated memory takes about as long as the
+    /// conversion itself, so convert repeated batches with [`Self::convert_batch_into`] to
+    /// reuse one buffer.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `items` - The datetimes to convert
+    /// 
+    /// # Returns
+    /// 
+    /// * `Result<Vec<DateTime<Tz>>, Errors>` - The converted datetimes in input order, or `Errors::ConversionError` if the target is not a tz database zone
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use chrono::{TimeZone, Utc};
+    /// use timezone_converter::TimeZoneConverter;
+    /// 
+    /// let converter = TimeZoneConverter::new("UTC", "Asia/Tokyo").unwrap();
+    /// let items = [Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap()];
+    /// let converted = converter.convert_batch(&items).unwrap();
+    /// assert_eq!(converted[0].to_string(), "2024-01-01 09:00:00 JST");
+    /// ```
+    pub fn convert_batch(&self, items: &[DateTime<Utc>]) -> Result<Vec<DateTime<Tz>>, Errors> {
+        let mut converted = Vec::with_capacity(items.len());
+        self.convert_batch_into(items, &mut converted)?;
+        Ok(converted)
+    }
+
+    /// Converts many UTC datetimes to the target timezone into an existing buffer
+    /// 
+    /// This behaves like [`Self::convert_batch`], but clears and refills `out` so its
+    /// allocation can be reused across batches.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `items` - The datetimes to convert
+    /// * `out` - The buffer that receives the converted datetimes, in input order
+    /// 
+    /// # Returns
+    /// 
+    /// * `Result<(), Errors>` - `Errors::ConversionError` if the target is not a tz database zone, leaving `out` empty
+    pub fn convert_batch_into(&self, items: &[DateTime<Utc>], out: &mut Vec<DateTime<Tz>>) -> Result<(), Errors> {
+        out.clear();
+        let tz = self.named_tz(Zone::Target)?;
+        out.reserve(items.len());
+
+        let Some(&head) = items.first() else {
+            return Ok(());
+        };
+        let (first, last) = items.iter().fold((head, head), |(first, last), dt| {
+            (first.min(*dt), last.max(*dt))
+        });
+
+        // Scanning a wide range for transitions costs more than it saves on a small batch
+        let scan_steps = (last - first).num_hours() / TRANSITION_SCAN_STEP_HOURS + 1;
+        if scan_steps as usize > items.len() {
+            out.extend(items.iter().map(|dt| dt.with_timezone(&tz)));
+            return Ok(());
+        }
+
+        // The range is half-open, so it ends just after the latest item where that is representable
+        let end = last.checked_add_signed(Duration::seconds(1)).unwrap_or(last);
+        let spans = OffsetSpans::new(&tz, first, end);
+        let mut index = 0;
+        for item in items {
+            if !spans.contains(index, item) {
+                index = spans.find(item);
+            }
+            out.push(DateTime::from_naive_utc_and_offset(item.naive_utc(), spans.offsets[index]));
+        }
+        Ok(())
+    }
+
+    /// Converts a Unix timestamp in seconds to the target timezone
+    /// 
+    /// # Arguments
+    /// 
+    /// * `secs` - Seconds since 1970-01-01 00:00:00 UTC
+    /// 
+    /// # Returns
+    /// 
+    /// * `Result<DateTime<Tz>, Errors>` - The datetime in the target timezone, or `Errors::ConversionError` if out of range
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use timezone_converter::TimeZoneConverter;
+    /// 
+    /// let converter = TimeZoneConverter::new("UTC", "Asia/Tokyo").unwrap();
+    /// let time = converter.convert_timestamp(1_700_000_000).unwrap();
+    /// assert_eq!(time.to_string(), "2023-11-15 07:13:20 JST");
+    /// ```
+    pub fn convert_timestamp(&self, secs: i64) -> Result<DateTime<Tz>, Errors> {
+        let utc = DateTime::from_timestamp(secs, 0).ok_or_else(|| {
+            Errors::ConversionError(format!("timestamp {} is out of range", secs))
+        })?;
+        Ok(utc.with_timezone(&self.named_tz(Zone::Target)?))
+    }
+
+    /// Converts a Unix timestamp in milliseconds to the target timezone
+    /// 
+    /// # Arguments
+    /// 
+    /// * `millis` - Milliseconds since 1970-01-01 00:00:00 UTC
+    /// 
+    /// # Returns
+    /// 
+    /// * `Result<DateTime<Tz>, Errors>` - The datetime in the target timezone, or `Errors::ConversionError` if out of range
+    pub fn convert_timestamp_millis(&self, millis: i64) -> Result<DateTime<Tz>, Errors> {
+        let utc = DateTime::from_timestamp_millis(millis).ok_or_else(|| {
+            Errors::ConversionError(format!("timestamp {}ms is out of range", millis))
+        })?;
+        Ok(utc.with_timezone(&self.named_tz(Zone::Target)?))
+    }
+
+    /// Converts a Unix timestamp in nanoseconds to the target timezone
+    /// 
+    /// Every `i64` nanosecond timestamp is in range (roughly the years 1677 to 2262).
+    /// 
+    /// # Arguments
+    /// 
+    /// * `nanos` - Nanoseconds since 1970-01-01 00:00:00 UTC
+    /// 
+    /// # Returns
+    /// 
+    /// * `Result<DateTime<Tz>, Errors>` - The datetime in the target timezone
+    pub fn convert_timestamp_nanos(&self, nanos: i64) -> Result<DateTime<Tz>, Errors> {
+        Ok(DateTime::from_timestamp_nanos(nanos).with_timezone(&self.named_tz(Zone::Target)?))
+    }
+
+    /// Converts a `std::time::SystemTime` to the target timezone
+    /// 
+    /// Times before the Unix epoch, such as some file modification times, are supported,
+    /// and sub-second precision is kept.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `t` - The system time to convert
+    /// 
+    /// # Returns
+    /// 
+    /// * `Result<DateTime<Tz>, Errors>` - The datetime in the target timezone, or `Errors::ConversionError` if out of range
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use std::time::{Duration, UNIX_EPOCH};
+    /// use timezone_converter::TimeZoneConverter;
+    /// 
+    /// let converter = TimeZoneConverter::new("UTC", "Asia/Tokyo").unwrap();
+    /// let time = converter.convert_system_time(UNIX_EPOCH + Duration::from_secs(1_700_000_000)).unwrap();
+    /// assert_eq!(time.to_string(), "2023-11-15 07:13:20 JST");
+    /// ```
+    pub fn convert_system_time(&self, t: SystemTime) -> Result<DateTime<Tz>, Errors> {
+        Ok(system_time_to_utc(t)?.with_timezone(&self.named_tz(Zone::Target)?))
+    }
+
+    /// Converts a `std::time::SystemTime` to the target timezone and formats it like [`Self::convert_to_string`]
+    /// 
+    /// # Arguments
+    /// 
+    /// * `t` - The system time to convert
+    /// 
+    /// # Returns
+    /// 
+    /// * `Result<String, Errors>` - The formatted target-local datetime
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use std::time::{Duration, UNIX_EPOCH};
+    /// use timezone_converter::TimeZoneConverter;
+    /// 
+    /// let converter = TimeZoneConverter::new("UTC", "Europe/Berlin").unwrap();
+    /// let text = converter.convert_system_time_to_string(UNIX_EPOCH + Duration::from_secs(1_700_000_000)).unwrap();
+    /// assert_eq!(text, "2023-11-14T23:13:20+01:00");
+    /// ```
+    pub fn convert_system_time_to_string(&self, t: SystemTime) -> Result<String, Errors> {
+        self.convert_to_string(system_time_to_utc(t)?)
+    }
+
+    /// Gets the source or target timezone
+    /// 
+    /// Methods such as [`Self::convert`] return `DateTime<Tz>` and fail, or panic where
+    /// noted, when the zone is a fixed offset or rule string rather than a tz database
+    /// zone; converting a result to this zone works for every kind of zone.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `which` - Whether to get the source or the target timezone
+    /// 
+    /// # Returns
+    /// 
+    /// * `&ZoneSpec` - The timezone
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use chrono::{TimeZone, Utc};
+    /// use timezone_converter::{TimeZoneConverter, Zone};
+    /// 
+    /// let converter = TimeZoneConverter::new("UTC", "+05:30").unwrap();
+    /// let dt = Utc.with_ymd_and_hms(2024, 7, 1, 12, 0, 0).unwrap();
+    /// assert_eq!(dt.with_timezone(converter.zone(Zone::Target)).to_string(), "2024-07-01 17:30:00 +05:30");
+    /// ```
+    pub fn zone(&self, which: Zone) -> &ZoneSpec {
+        match which {
+            Zone::Source => &self.source_tz,
+            Zone::Target => &self.target_tz,
+        }
+    }
+
+    /// Gets the source or target timezone as a tz database zone, for the methods returning `DateTime<Tz>`
+    fn named_tz(&self, which: Zone) -> Result<Tz, Errors> {
+        let zone = self.zone(which);
+        zone.as_tz().ok_or_else(|| {
+            Errors::ConversionError(format!("'{}' is not a tz database timezone; convert with TimeZoneConverter::convert_zoned instead", zone))
+        })
+    }
+
+    /// Finds the next offset transition in either timezone after an instant
+    /// 
+    /// The search looks up to a year ahead, so zones without DST yield `None`.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `from` - The instant to search from (exclusive)
+    /// * `zone` - Whether to search the source or the target timezone
+    /// 
+    /// # Returns
+    /// 
+    /// * `Option<Transition>` - The next transition, if any
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use chrono::{Duration, TimeZone, Utc};
+    /// use timezone_converter::{TimeZoneConverter, Zone};
+    /// 
+    /// let converter = TimeZoneConverter::new("America/New_York", "Europe/London").unwrap();
+    /// let from = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+    /// let transition = converter.next_transition(from, Zone::Source).unwrap();
+    /// assert_eq!(transition.instant, Utc.with_ymd_and_hms(2024, 3, 10, 7, 0, 0).unwrap());
+    /// assert_eq!(transition.abbreviation_before, "EST");
+    /// assert_eq!(transition.abbreviation_after, "EDT");
+    /// assert_eq!(transition.shift(), Duration::hours(1));
+    /// ```
+    pub fn next_transition(&self, from: DateTime<Utc>, zone: Zone) -> Option<Transition> {
+        let tz = self.zone(zone);
+        next_offset_change(tz, from).map(|instant| Transition::at(tz, instant))
+    }
+
+    /// Finds the latest offset transition in either timezone at or before an instant
+    /// 
+    /// The search looks up to a year back, so zones without DST yield `None`.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `from` - The instant to search back from (inclusive)
+    /// * `zone` - Whether to search the source or the target timezone
+    /// 
+    /// # Returns
+    /// 
+    /// * `Option<Transition>` - The previous transition, if any
+    pub fn previous_transition(&self, from: DateTime<Utc>, zone: Zone) -> Option<Transition> {
+        let tz = self.zone(zone);
+        previous_offset_change(tz, from).map(|instant| Transition::at(tz, instant))
+    }
+
+    /// Lists every offset transition in either timezone between two instants
+    /// 
+    /// An empty list is returned for fixed-offset zones and for ranges where `end`
+    /// is not after `start`.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `start` - The start of the range (inclusive)
+    /// * `end` - The end of the range (exclusive)
+    /// * `zone` - Whether to list the source or the target timezone's transitions
+    /// 
+    /// # Returns
+    /// 
+    /// * `Vec<Transition>` - The transitions in chronological order
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use chrono::{TimeZone, Utc};
+    /// use timezone_converter::{TimeZoneConverter, Zone};
+    /// 
+    /// let converter = TimeZoneConverter::new("America/New_York", "Europe/London").unwrap();
+    /// let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+    /// let end = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+    /// let transitions = converter.transitions_between(start, end, Zone::Target);
+    /// let abbreviations: Vec<_> = transitions.iter().map(|t| t.abbreviation_after.as_str()).collect();
+    /// assert_eq!(abbreviations, ["BST", "GMT"]);
+    /// ```
+    pub fn transitions_between(&self, start: DateTime<Utc>, end: DateTime<Utc>, zone: Zone) -> Vec<Transition> {
+        let tz = self.zone(zone);
+        if end <= start || is_fixed_offset(tz) {
+            return Vec::new();
+        }
+
+        offset_changes(tz, start, end)
+            .into_iter()
+            .map(|instant| Transition::at(tz, instant))
+            .collect()
+    }
+
+    /// Lists every instant in a year at which the source/target time difference changes
+    /// 
+    /// Zones rarely change their clocks on the same day, so e.g. New York and London are
+    /// 5 hours apart most of the year but only 4 for a few weeks in spring and autumn.
+    /// The year runs from midnight UTC on 1 January to midnight UTC on 1 January of the
+    /// next year; years outside the representable range yield an empty list.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `year` - The year to report on
+    /// 
+    /// # Returns
+    /// 
+    /// * `Vec<(DateTime<Utc>, Duration)>` - Each change instant with the new difference (source minus target)
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use chrono::{Duration, TimeZone, Utc};
+    /// use timezone_converter::TimeZoneConverter;
+    /// 
+    /// let converter = TimeZoneConverter::new("Europe/London", "America/New_York").unwrap();
+    /// let changes = converter.difference_changes(2024);
+    /// assert_eq!(changes.len(), 4);
+    /// assert_eq!(changes[0], (Utc.with_ymd_and_hms(2024, 3, 10, 7, 0, 0).unwrap(), Duration::hours(4)));
+    /// assert_eq!(changes[1], (Utc.with_ymd_and_hms(2024, 3, 31, 1, 0, 0).unwrap(), Duration::hours(5)));
+    /// ```
+    pub fn difference_changes(&self, year: i32) -> Vec<(DateTime<Utc>, Duration)> {
+        let start = Utc.with_ymd_and_hms(year, 1, 1, 0, 0, 0).single();
+        let end = year.checked_add(1).and_then(|next| Utc.with_ymd_and_hms(next, 1, 1, 0, 0, 0).single());
+        match (start, end) {
+            (Some(start), Some(end)) => self.difference_changes_between(start, end),
+            _ => Vec::new(),
+        }
+    }
+
+    /// Finds the working hours shared by the source and target timezones on a date
+    /// 
+    /// The source hours are read on `date` in the source timezone, and the target hours on
+    /// the surrounding target-local dates, so the overlap follows both zones' DST. Hours
+    /// whose end is not after their start run overnight into the next day.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `source_hours` - The source-local start and end of the working day
+    /// * `target_hours` - The target-local start and end of the working day
+    /// * `date` - The source-local date
+    /// 
+    /// # Returns
+    /// 
+    /// * `Result<Option<TzWindow>, Errors>` - The start and end of the earliest shared window in the target timezone, or `None` if the hours don't overlap; `Errors::ConversionError` if the target is not a tz database zone
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use chrono::{NaiveDate, NaiveTime};
+    /// use timezone_converter::TimeZoneConverter;
+    /// 
+    /// let converter = TimeZoneConverter::new("America/New_York", "Europe/London").unwrap();
+    /// let hours = (NaiveTime::from_hms_opt(9, 0, 0).unwrap(), NaiveTime::from_hms_opt(17, 0, 0).unwrap());
+    /// let date = NaiveDate::from_ymd_opt(2024, 7, 1).unwrap();
+    /// let (start, end) = converter.working_hours_overlap(hours, hours, date).unwrap().unwrap();
+    /// assert_eq!(start.to_string(), "2024-07-01 14:00:00 BST");
+    /// assert_eq!(end.to_string(), "2024-07-01 17:00:00 BST");
+    /// // The same window in New York
+    /// assert_eq!(start.with_timezone(&chrono_tz::America::New_York).to_string(), "2024-07-01 09:00:00 EDT");
+    /// ```
+    pub fn working_hours_overlap(&self, source_hours: (NaiveTime, NaiveTime), target_hours: (NaiveTime, NaiveTime), date: NaiveDate) -> Result<Option<TzWindow>, Errors> {
+        let tz = self.named_tz(Zone::Target)?;
+        let Some(source_window) = meeting::local_window(&self.source_tz, date, source_hours.0, source_hours.1).ok() else {
+            return Ok(None);
+        };
+        let (Some(previous), Some(next)) = (date.pred_opt(), date.succ_opt()) else {
+            return Ok(None);
+        };
+        let Ok(target_windows) = [previous, date, next]
+            .iter()
+            .map(|day| meeting::local_window(&self.target_tz, *day, target_hours.0, target_hours.1))
+            .collect::<Result<Vec<_>, Errors>>()
+        else {
+            return Ok(None);
+        };
+
+        Ok(meeting::intersect(&[source_window], &target_windows)
+            .first()
+            .map(|(start, end)| (start.with_timezone(&tz), end.with_timezone(&tz))))
+    }
+
+    /// Converts a datetime to the target timezone and formats it with a strftime pattern
+    /// 
+    /// This is the formatted form of [`Self::convert`], the same as [`Self::format_in_target`].
+    /// `%Z` gives the target zone's abbreviation and `%z` its offset at that instant.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `datetime` - The datetime to convert
+    /// * `fmt` - A `chrono` strftime-style format (e.g. "%Y-%m-%d %H:%M %Z")
+    /// 
+    /// # Returns
+    /// 
+    /// * `Result<String, Errors>` - The formatted target-local datetime, or `Errors::ParseError` if `fmt` is invalid
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use chrono::{TimeZone, Utc};
+    /// use timezone_converter::TimeZoneConverter;
+    /// 
+    /// let converter = TimeZoneConverter::new("UTC", "America/New_York").unwrap();
+    /// let dt = Utc.with_ymd_and_hms(2024, 7, 4, 16, 0, 0).unwrap();
+    /// assert_eq!(converter.convert_formatted(dt, "%H:%M %Z (%z)").unwrap(), "12:00 EDT (-0400)");
+    /// ```
+    pub fn convert_formatted<T: ChronoTimeZone>(&self, datetime: DateTime<T>, fmt: &str) -> Result<String, Errors> {
+        self.format_in_target(datetime, fmt)
+    }
+
+    /// Formats an instant as a wall-clock time in the target timezone
+    /// 
+    /// # Arguments
+    /// 
+    /// * `datetime` - The instant to format, in any timezone
+    /// * `fmt` - A `chrono` strftime-style format; `%Z` and `%z` give the target abbreviation and offset
+    /// 
+    /// # Returns
+    /// 
+    /// * `Result<String, Errors>` - The formatted target-local datetime, or `Errors::ParseError` if `fmt` is invalid
+    pub fn format_in_target<T: ChronoTimeZone>(&self, datetime: DateTime<T>, fmt: &str) -> Result<String, Errors> {
+        format_in(&self.target_tz, datetime, fmt)
+    }
+
+    /// Formats an instant as a wall-clock time in the source timezone
+    /// 
+    /// # Arguments
+    /// 
+    /// * `datetime` - The instant to format, in any timezone
+    /// * `fmt` - A `chrono` strftime-style format; `%Z` and `%z` give the source abbreviation and offset
+    /// 
+    /// # Returns
+    /// 
+    /// * `Result<String, Errors>` - The formatted source-local datetime, or `Errors::ParseError` if `fmt` is invalid
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use chrono::{TimeZone, Utc};
+    /// use timezone_converter::TimeZoneConverter;
+    /// 
+    /// let converter = TimeZoneConverter::new("Asia/Kolkata", "UTC").unwrap();
+    /// let dt = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+    /// assert_eq!(converter.format_in_source(dt, "%d %b %Y %H:%M %Z").unwrap(), "01 Jan 2024 05:30 IST");
+    /// ```
+    pub fn format_in_source<T: ChronoTimeZone>(&self, datetime: DateTime<T>, fmt: &str) -> Result<String, Errors> {
+        format_in(&self.source_tz, datetime, fmt)
+    }
+
+    /// Formats an instant as an RFC 3339 timestamp with the target timezone's offset
+    /// 
+    /// # Arguments
+    /// 
+    /// * `dt` - The instant to format, in any timezone
+    /// 
+    /// # Returns
+    /// 
+    /// * `String` - The timestamp (e.g., "2024-07-01T10:00:00+02:00")
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use chrono::{TimeZone, Utc};
+    /// use timezone_converter::TimeZoneConverter;
+    /// 
+    /// let converter = TimeZoneConverter::new("UTC", "Europe/Paris").unwrap();
+    /// let dt = Utc.with_ymd_and_hms(2024, 7, 1, 8, 0, 0).unwrap();
+    /// assert_eq!(converter.to_rfc3339_target(dt), "2024-07-01T10:00:00+02:00");
+    /// ```
+    pub fn to_rfc3339_target<T: ChronoTimeZone>(&self, dt: DateTime<T>) -> String {
+        dt.with_timezone(&self.target_tz).to_rfc3339()
+    }
+
+    /// Formats an instant as an RFC 3339 timestamp with the source timezone's offset
+    /// 
+    /// # Arguments
+    /// 
+    /// * `dt` - The instant to format, in any timezone
+    /// 
+    /// # Returns
+    /// 
+    /// * `String` - The timestamp (e.g., "2024-07-01T04:00:00-04:00")
+    pub fn to_rfc3339_source<T: ChronoTimeZone>(&self, dt: DateTime<T>) -> String {
+        dt.with_timezone(&self.source_tz).to_rfc3339()
+    }
+
+    /// Formats an instant as an RFC 2822 date, as used in email and HTTP headers, with the target timezone's offset
+    /// 
+    /// # Arguments
+    /// 
+    /// * `dt` - The instant to format, in any timezone
+    /// 
+    /// # Returns
+    /// 
+    /// * `Result<String, Errors>` - The date (e.g., "Mon, 1 Jul 2024 10:00:00 +0200"), or `Errors::ConversionError` outside the years 0-9999 that RFC 2822 can represent
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use chrono::{TimeZone, Utc};
+    /// use timezone_converter::TimeZoneConverter;
+    /// 
+    /// let converter = TimeZoneConverter::new("UTC", "Europe/Paris").unwrap();
+    /// let dt = Utc.with_ymd_and_hms(2024, 7, 1, 8, 0, 0).unwrap();
+    /// assert_eq!(converter.to_rfc2822_target(dt).unwrap(), "Mon, 1 Jul 2024 10:00:00 +0200");
+    /// ```
+    pub fn to_rfc2822_target<T: ChronoTimeZone>(&self, dt: DateTime<T>) -> Result<String, Errors> {
+        to_rfc2822_in(&self.target_tz, dt)
+    }
+
+    /// Formats an instant as an RFC 2822 date, as used in email and HTTP headers, with the source timezone's offset
+    /// 
+    /// # Arguments
+    /// 
+    /// * `dt` - The instant to format, in any timezone
+    /// 
+    /// # Returns
+    /// 
+    /// * `Result<String, Errors>` - The date (e.g., "Mon, 1 Jul 2024 04:00:00 -0400"), or `Errors::ConversionError` outside the years 0-9999 that RFC 2822 can represent
+    pub fn to_rfc2822_source<T: ChronoTimeZone>(&self, dt: DateTime<T>) -> Result<String, Errors> {
+        to_rfc2822_in(&self.source_tz, dt)
+    }
+
+    /// Checks that a source-local wall-clock time converts to the target and back unchanged
+    /// 
+    /// This holds unless the time is skipped or repeated by a DST transition in the source
+    /// timezone, or the converted time is repeated in the target timezone, in which case
+    /// it would not identify a single instant when read back.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `dt` - The wall-clock time in the source timezone
+    /// 
+    /// # Returns
+    /// 
+    /// * `Result<(), RoundTripError>` - `Ok` if the time round-trips, or why it does not
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use timezone_converter::{RoundTripError, TimeZoneConverter, Zone};
+    /// 
+    /// let converter = TimeZoneConverter::new("Asia/Tokyo", "America/New_York").unwrap();
+    /// let date = NaiveDate::from_ymd_opt(2024, 11, 3).unwrap();
+    /// assert!(converter.verify_round_trip(date.and_hms_opt(9, 0, 0).unwrap()).is_ok());
+    /// // 15:30 in Tokyo is 01:30 in New York, which occurs twice that night
+    /// let error = converter.verify_round_trip(date.and_hms_opt(15, 30, 0).unwrap()).unwrap_err();
+    /// assert!(matches!(error, RoundTripError::Ambiguous { zone: Zone::Target, .. }));
+    /// ```
+    pub fn verify_round_trip(&self, dt: NaiveDateTime) -> Result<(), RoundTripError> {
+        let instant = unique_local(&self.source_tz, dt, Zone::Source)?;
+        let target_local = instant.with_timezone(&self.target_tz).naive_local();
+        let back = unique_local(&self.target_tz, target_local, Zone::Target)?;
+        debug_assert_eq!(back.with_timezone(&self.source_tz).naive_local(), dt);
+        Ok(())
+    }
+
+    /// Moves a datetime by calendar days and wall-clock hours in the target timezone
+    /// 
+    /// This is "the same local time tomorrow": a day is whatever the target-local calendar
+    /// says, so one day after 09:00 is 09:00 the next day even across a DST transition,
+    /// when that is 23 or 25 hours later. A result skipped or repeated by a transition is
+    /// resolved with the converter's [`AmbiguityPolicy`]. Use [`Self::add_absolute`] for
+    /// elapsed time.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `dt` - The datetime to move
+    /// * `days` - The number of calendar days to add, which may be negative
+    /// * `hours` - The number of wall-clock hours to add, which may be negative
+    /// 
+    /// # Returns
+    /// 
+    /// * `Result<DateTime<Tz>, Errors>` - The moved datetime in the target timezone, or an error if it is out of range, rejected by the policy or the target is not a tz database zone
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use chrono::TimeZone;
+    /// use chrono_tz::America::New_York;
+    /// use timezone_converter::{AmbiguityPolicy, TimeZoneConverter};
+    /// 
+    /// let converter = TimeZoneConverter::new("UTC", "America/New_York").unwrap();
+    /// let saturday = New_York.with_ymd_and_hms(2024, 3, 9, 9, 0, 0).unwrap();
+    /// assert_eq!(converter.add_wall(saturday, 1, 0).unwrap().to_string(), "2024-03-10 09:00:00 EDT");
+    /// 
+    /// // 02:30 does not exist on Mar 10, so it is shifted forward past the gap
+    /// let night = New_York.with_ymd_and_hms(2024, 3, 9, 2, 30, 0).unwrap();
+    /// assert_eq!(converter.add_wall(night, 1, 0).unwrap().to_string(), "2024-03-10 03:30:00 EDT");
+    /// let strict = TimeZoneConverter::builder().source("UTC").target("America/New_York")
+    ///     .ambiguity_policy(AmbiguityPolicy::Reject).build().unwrap();
+    /// assert!(strict.add_wall(night, 1, 0).is_err());
+    /// ```
+    pub fn add_wall<T: ChronoTimeZone>(&self, dt: DateTime<T>, days: i64, hours: i64) -> Result<DateTime<Tz>, Errors> {
+        let tz = self.named_tz(Zone::Target)?;
+        let local = dt.with_timezone(&self.target_tz).naive_local();
+        let moved = Duration::try_days(days)
+            .zip(Duration::try_hours(hours))
+            .and_then(|(days, hours)| local.checked_add_signed(days)?.checked_add_signed(hours))
+            .ok_or_else(|| Errors::ConversionError(format!("{} plus {} days and {} hours is out of range", local, days, hours)))?;
+        Ok(resolve_local(&self.target_tz, moved, self.ambiguity_policy)?.with_timezone(&tz))
+    }
+
+    /// Moves a datetime by an exact amount of elapsed time and gives the result in the target timezone
+    /// 
+    /// This is "+24 hours": across a DST transition the local time of day changes, as in
+    /// 09:00 EST plus 24 hours being 10:00 EDT. Use [`Self::add_wall`] for calendar days.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `dt` - The datetime to move
+    /// * `duration` - The time to add, which may be negative
+    /// 
+    /// # Returns
+    /// 
+    /// * `Result<DateTime<Tz>, Errors>` - The moved datetime in the target timezone, or an error if it is out of range or the target is not a tz database zone
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use chrono::{Duration, TimeZone};
+    /// use chrono_tz::America::New_York;
+    /// use timezone_converter::TimeZoneConverter;
+    /// 
+    /// let converter = TimeZoneConverter::new("UTC", "America/New_York").unwrap();
+    /// let saturday = New_York.with_ymd_and_hms(2024, 3, 9, 9, 0, 0).unwrap();
+    /// let later = converter.add_absolute(saturday, Duration::hours(24)).unwrap();
+    /// assert_eq!(later.to_string(), "2024-03-10 10:00:00 EDT");
+    /// ```
+    pub fn add_absolute<T: ChronoTimeZone>(&self, dt: DateTime<T>, duration: Duration) -> Result<DateTime<Tz>, Errors> {
+        let tz = self.named_tz(Zone::Target)?;
+        dt.with_timezone(&Utc)
+            .checked_add_signed(duration)
+            .map(|moved| moved.with_timezone(&tz))
+            .ok_or_else(|| Errors::ConversionError(format!("{} plus {} is out of range", dt.with_timezone(&Utc), duration)))
+    }
+
+    /// Implements [`Self::target_is_working`] for an arbitrary instant
+    fn target_is_working_at(&self, at: DateTime<Utc>, work_start: u32, work_end: u32, working_days: &[Weekday]) -> Result<bool, Errors> {
+        if work_start > 23 || work_end > 24 {
+            return Err(Errors::ConversionError(format!(
+                "invalid working hours {}-{}",
+                work_start, work_end
+            )));
+        }
+
+        let local = at.with_timezone(&self.target_tz);
+        let hour = local.hour();
+        let weekday = local.weekday();
+
+        Ok(if work_start <= work_end {
+            working_days.contains(&weekday) && hour >= work_start && hour < work_end
+        } else {
+            (working_days.contains(&weekday) && hour >= work_start)
+                || (working_days.contains(&weekday.pred()) && hour < work_end)
+        })
+    }
+
+    /// Implements [`Self::current_iso_pair`] for an arbitrary instant
+    fn iso_pair_at(&self, at: DateTime<Utc>) -> (String, String) {
+        (
+            at.with_timezone(&self.source_tz).to_rfc3339(),
+            at.with_timezone(&self.target_tz).to_rfc3339(),
+        )
+    }
+
+    /// Implements [`Self::until_same_date`] for an arbitrary instant
+    fn until_same_date_at(&self, now: DateTime<Utc>) -> Result<Option<Duration>, Errors> {
+        let source_date = now.with_timezone(&self.source_tz).date_naive();
+        let target_date = now.with_timezone(&self.target_tz).date_naive();
+        if source_date == target_date {
+            return Ok(None);
+        }
+
+        let (behind_tz, behind_date) = if source_date < target_date {
+            (&self.source_tz, source_date)
+        } else {
+            (&self.target_tz, target_date)
+        };
+        let next_date = behind_date.succ_opt().ok_or_else(|| {
+            Errors::ConversionError(format!("no day follows {}", behind_date))
+        })?;
+        let midnight = resolve_local_forward(behind_tz, next_date.and_time(NaiveTime::MIN))?;
+
+        Ok(Some(midnight.with_timezone(&Utc) - now))
+    }
+
+    /// Lists every instant in `[start, end)` at which the source/target difference changes,
+    /// along with the new difference
+    fn difference_changes_between(&self, start: DateTime<Utc>, end: DateTime<Utc>) -> Vec<(DateTime<Utc>, Duration)> {
+        let mut instants = offset_changes(&self.source_tz, start, end);
+        instants.extend(offset_changes(&self.target_tz, start, end));
+        instants.sort();
+        instants.dedup();
+
+        let mut changes = Vec::new();
+        let mut current = self.time_difference_at(start);
+        for instant in instants {
+            let difference = self.time_difference_at(instant);
+            if difference != current {
+                changes.push((instant, difference));
+                current = difference;
+            }
+        }
+        changes
+    }
+}
+
+impl ConverterCache {
+    /// Creates an empty ConverterCache
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Gets the converter for a source and target timezone, creating and caching it on first use
+    /// 
+    /// # Arguments
+    /// 
+    /// * `source` - The source timezone identifier (e.g., "America/New_York")
+    /// * `target` - The target timezone identifier (e.g., "Europe/London")
+    /// 
+    /// # Returns
+    /// 
+    /// * `Result<&TimeZoneConverter, Errors>` - The cached converter, or an error if either name is invalid
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use timezone_converter::ConverterCache;
+    /// 
+    /// let mut cache = ConverterCache::new();
+    /// let converter = cache.get("America/New_York", "Europe/London").unwrap();
+    /// assert!(!converter.is_identity());
+    /// assert_eq!(cache.len(), 1);
+    /// ```
+    pub fn get(&mut self, source: &str, target: &str) -> Result<&TimeZoneConverter, Errors> {
+        let key = (source.to_string(), target.to_string());
+        match self.converters.entry(key) {
+            Entry::Occupied(entry) => Ok(entry.into_mut()),
+            Entry::Vacant(entry) => Ok(entry.insert(TimeZoneConverter::new(source, target)?)),
+        }
+    }
+
+    /// Gets the number of cached converters
+    pub fn len(&self) -> usize {
+        self.converters.len()
+    }
+
+    /// Whether the cache holds no converters
+    pub fn is_empty(&self) -> bool {
+        self.converters.is_empty()
+    }
+
+    /// Removes every cached converter
+    pub fn clear(&mut self) {
+        self.converters.clear();
+    }
+}
+
+/// Detects the system's local timezone
+/// 
+/// The IANA identifier is read from the operating system (e.g., the `/etc/localtime`
+/// link on Linux or the registry on Windows).
+/// 
+/// # Returns
+/// 
+/// * `Result<Tz, Errors>` - The local timezone, `Errors::ConversionError` if it cannot be detected, or `Errors::InvalidTimeZone` if the system reports an unknown identifier
+/// 
+/// # Example
+/// 
+/// ```rust,no_run
+/// use timezone_converter::local_timezone;
+/// 
+/// println!("This machine is in {}", local_timezone().unwrap());
+/// ```
+pub fn local_timezone() -> Result<Tz, Errors> {
+    let name = iana_time_zone::get_timezone()
+        .map_err(|err| Errors::ConversionError(format!("cannot detect the local timezone: {}", err)))?;
+    parse_tz(&name)
+}
+
+/// Suggests valid timezone identifiers close to a possibly misspelled name, best matches first
+/// 
+/// Names are compared case-insensitively, both in full and by their final component, so
+/// "tokyo" suggests "Asia/Tokyo"; small typos such as swapped or missing letters are
+/// tolerated in proportion to the length of the query.
+/// 
+/// # Arguments
+/// 
+/// * `query` - The name to match (e.g., "America/New_Yrok")
+/// * `limit` - The most suggestions to return
+/// 
+/// # Returns
+/// 
+/// * `Vec<&'static str>` - The closest IANA names, ranked by edit distance and then alphabetically
+/// 
+/// # Example
+/// 
+/// ```rust
+/// use timezone_converter::suggest_timezones;
+/// 
+/// assert_eq!(suggest_timezones("America/New_york", 3), ["America/New_York"]);
+/// assert_eq!(suggest_timezones("Europe/Lodnon", 3), ["Europe/London", "Europe/Lisbon"]);
+/// ```
+pub fn suggest_timezones(query: &str, limit: usize) -> Vec<&'static str> {
+    let query = query.trim().replace(' ', "_").to_lowercase();
+    if query.is_empty() {
+        return Vec::new();
+    }
+
+    let max_distance = (query.chars().count() / 4).max(1);
+    let mut ranked: Vec<(usize, &'static str)> = chrono_tz::TZ_VARIANTS
+        .iter()
+        .filter_map(|tz| {
+            let candidate = tz.name().to_lowercase();
+            let city = candidate.rsplit('/').next().unwrap_or(&candidate);
+            let distance = edit_distance(&query, &candidate).min(edit_distance(&query, city));
+            (distance <= max_distance).then_some((distance, tz.name()))
+        })
+        .collect();
+    ranked.sort_unstable();
+    ranked.into_iter().take(limit).map(|(_, name)| name).collect()
+}
+
+/// Lists every canonical timezone with its current offset, abbreviation and DST status
+/// 
+/// Zones are listed in alphabetical order of their IANA names. Legacy names such as
+/// `US/Eastern`, and the tz database's links for places that share another zone's clocks,
+/// such as `Europe/Belfast`, are left out, so the list can populate a timezone picker as is.
+/// 
+/// # Returns
+/// 
+/// * `impl Iterator<Item = TimeZoneSummary>` - A summary of each timezone
+/// 
+/// # Example
+/// 
+/// ```rust
+/// use timezone_converter::list_timezones;
+/// 
+/// let berlin = list_timezones().find(|zone| zone.name() == "Europe/Berlin").unwrap();
+/// assert!(["CET", "CEST"].contains(&berlin.abbreviation()));
+/// ```
+pub fn list_timezones() -> impl Iterator<Item = TimeZoneSummary> {
+    list_timezones_at(Utc::now())
+}
+
+/// Lists every canonical timezone with its offset, abbreviation and DST status at an instant
+/// 
+/// See [`list_timezones`] for the zones listed.
+/// 
+/// # Arguments
+/// 
+/// * `at` - The instant at which to describe each timezone
+/// 
+/// # Returns
+/// 
+/// * `impl Iterator<Item = TimeZoneSummary>` - A summary of each timezone
+/// 
+/// # Example
+/// 
+/// ```rust
+/// use chrono::{Duration, TimeZone, Utc};
+/// use timezone_converter::list_timezones_at;
+/// 
+/// let at = Utc.with_ymd_and_hms(2024, 7, 1, 12, 0, 0).unwrap();
+/// let berlin = list_timezones_at(at).find(|zone| zone.name() == "Europe/Berlin").unwrap();
+/// assert_eq!((berlin.offset(), berlin.abbreviation(), berlin.is_dst()), (Duration::hours(2), "CEST", true));
+/// ```
+pub fn list_timezones_at(at: DateTime<Utc>) -> impl Iterator<Item = TimeZoneSummary> {
+    chrono_tz::TZ_VARIANTS
+        .iter()
+        .filter(|tz| !aliases::is_backward_link(**tz))
+        .map(move |tz| {
+            let local = at.with_timezone(tz);
+            TimeZoneSummary {
+                tz: *tz,
+                offset: Duration::seconds(local.offset().fix().local_minus_utc().into()),
+                abbreviation: local.format("%Z").to_string(),
+                is_dst: is_dst_at(&ZoneSpec::Named(*tz), at),
+            }
+        })
+}
+
+/// Lists the canonical timezones currently at a given offset from UTC
+/// 
+/// # Arguments
+/// 
+/// * `offset` - The offset east of UTC (e.g., `Duration::hours(2)`)
+/// 
+/// # Returns
+/// 
+/// * `Vec<Tz>` - The timezones at that offset, in alphabetical order
+/// 
+/// # Example
+/// 
+/// ```rust
+/// use chrono::Duration;
+/// use chrono_tz::Tz;
+/// use timezone_converter::timezones_by_offset;
+/// 
+/// assert!(timezones_by_offset(Duration::minutes(330)).contains(&Tz::Asia__Kolkata));
+/// ```
+pub fn timezones_by_offset(offset: Duration) -> Vec<Tz> {
+    timezones_by_offset_at(offset, Utc::now())
+}
+
+/// Lists the canonical timezones at a given offset from UTC at an instant
+/// 
+/// # Arguments
+/// 
+/// * `offset` - The offset east of UTC
+/// * `at` - The instant at which to evaluate each zone's offset
+/// 
+/// # Returns
+/// 
+/// * `Vec<Tz>` - The timezones at that offset, in alphabetical order
+/// 
+/// # Example
+/// 
+/// ```rust
+/// use chrono::{Duration, TimeZone, Utc};
+/// use chrono_tz::Tz;
+/// use timezone_converter::timezones_by_offset_at;
+/// 
+/// let summer = Utc.with_ymd_and_hms(2024, 7, 1, 12, 0, 0).unwrap();
+/// let zones = timezones_by_offset_at(Duration::hours(2), summer);
+/// assert!(zones.contains(&Tz::Africa__Johannesburg) && zones.contains(&Tz::Europe__Berlin));
+/// ```
+pub fn timezones_by_offset_at(offset: Duration, at: DateTime<Utc>) -> Vec<Tz> {
+    list_timezones_at(at).filter(|zone| zone.offset() == offset).map(|zone| zone.tz()).collect()
+}
+
+/// Groups the canonical timezones by their current offset from UTC
+/// 
+/// This builds "UTC+02:00: Cairo, Johannesburg, Kyiv…" style pickers, and finds zones
+/// that currently show the same time.
+/// 
+/// # Returns
+/// 
+/// * `BTreeMap<Duration, Vec<Tz>>` - The timezones at each offset, westernmost offset first and each list in alphabetical order
+/// 
+/// # Example
+/// 
+/// ```rust
+/// use chrono::Duration;
+/// use chrono_tz::Tz;
+/// use timezone_converter::group_by_current_offset;
+/// 
+/// let groups = group_by_current_offset();
+/// assert_eq!(groups[&Duration::hours(9)].iter().filter(|tz| **tz == Tz::Asia__Tokyo).count(), 1);
+/// ```
+pub fn group_by_current_offset() -> BTreeMap<Duration, Vec<Tz>> {
+    group_by_offset_at(Utc::now())
+}
+
+/// Groups the canonical timezones by their offset from UTC at an instant
+/// 
+/// # Arguments
+/// 
+/// * `at` - The instant at which to evaluate each zone's offset
+/// 
+/// # Returns
+/// 
+/// * `BTreeMap<Duration, Vec<Tz>>` - The timezones at each offset, westernmost offset first and each list in alphabetical order
+/// 
+/// # Example
+/// 
+/// ```rust
+/// use chrono::{Duration, TimeZone, Utc};
+/// use chrono_tz::Tz;
+/// use timezone_converter::group_by_offset_at;
+/// 
+/// let winter = Utc.with_ymd_and_hms(2024, 1, 15, 12, 0, 0).unwrap();
+/// let groups = group_by_offset_at(winter);
+/// assert!(groups[&Duration::hours(-5)].contains(&Tz::America__New_York));
+/// assert_eq!(groups.keys().next(), Some(&Duration::hours(-12)));
+/// ```
+pub fn group_by_offset_at(at: DateTime<Utc>) -> BTreeMap<Duration, Vec<Tz>> {
+    let mut groups: BTreeMap<Duration, Vec<Tz>> = BTreeMap::new();
+    for zone in list_timezones_at(at) {
+        groups.entry(zone.offset()).or_default().push(zone.tz());
+    }
+    groups
+}
+
+/// Whether two timezones show the same local time throughout a range of years
+/// 
+/// The full transition histories are compared, so zones that merely share an offset today
+/// (such as Europe/Berlin and Africa/Lagos in winter) are not equivalent. The range runs
+/// from midnight UTC on 1 January of the first year to midnight UTC on 1 January after the
+/// last; an empty range or one outside the representable years is never equivalent.
+/// 
+/// # Arguments
+/// 
+/// * `a` - The first timezone
+/// * `b` - The second timezone
+/// * `range` - The first and last year to compare, inclusive
+/// 
+/// # Returns
+/// 
+/// * `bool` - Whether the two zones have the same UTC offset at every instant in the range
+/// 
+/// # Example
+/// 
+/// ```rust
+/// use chrono_tz::Tz;
+/// use timezone_converter::zones_equivalent;
+/// 
+/// assert!(zones_equivalent(Tz::Europe__Berlin, Tz::Europe__Paris, (1996, 2030)));
+/// assert!(!zones_equivalent(Tz::Europe__Berlin, Tz::Europe__Paris, (1970, 2030)));
+/// ```
+pub fn zones_equivalent(a: Tz, b: Tz, range: (i32, i32)) -> bool {
+    match year_span(range) {
+        Some((start, end)) => same_offset_history(a, b, start, end),
+        None => false,
+    }
+}
+
+/// Finds the canonical timezones that show the same local time as a timezone throughout a range of years
+/// 
+/// See [`zones_equivalent`] for how zones are compared.
+/// 
+/// # Arguments
+/// 
+/// * `tz` - The timezone to match
+/// * `range` - The first and last year to compare, inclusive
+/// 
+/// # Returns
+/// 
+/// * `Vec<Tz>` - The equivalent timezones other than `tz` itself, in alphabetical order
+/// 
+/// # Example
+/// 
+/// ```rust
+/// use chrono_tz::Tz;
+/// use timezone_converter::equivalent_zones;
+/// 
+/// let zones = equivalent_zones(Tz::Europe__Berlin, (2000, 2030));
+/// assert!(zones.contains(&Tz::Europe__Paris) && zones.contains(&Tz::Europe__Rome));
+/// assert!(!zones.contains(&Tz::Europe__London));
+/// ```
+pub fn equivalent_zones(tz: Tz, range: (i32, i32)) -> Vec<Tz> {
+    let Some((start, end)) = year_span(range) else {
+        return Vec::new();
+    };
+    // Offsets in mid-January and mid-July of each year rule out most zones cheaply
+    let samples: Vec<DateTime<Utc>> = (range.0..=range.1)
+        .flat_map(|year| [1, 7].map(|month| Utc.with_ymd_and_hms(year, month, 15, 0, 0, 0).single()))
+        .flatten()
+        .collect();
+    chrono_tz::TZ_VARIANTS
+        .iter()
+        .copied()
+        .filter(|other| *other != tz && !aliases::is_backward_link(*other))
+        .filter(|other| samples.iter().all(|at| offset_seconds_at(other, *at) == offset_seconds_at(&tz, *at)))
+        .filter(|other| same_offset_history(tz, *other, start, end))
+        .collect()
+}
+
+/// Gets the release of the IANA tz database compiled into the crate (e.g., "2024b")
+/// 
+/// Conversions, [`TimeZoneInfo`] and the JSON reports record it, so results can be traced
+/// back to the rules that produced them.
+/// 
+/// # Returns
+/// 
+/// * `&'static str` - The release: the year followed by a letter
+/// 
+/// # Example
+/// 
+/// ```rust
+/// use timezone_converter::tzdb_version;
+/// 
+/// assert!(tzdb_version().starts_with("20"));
+/// ```
+pub fn tzdb_version() -> &'static str {
+    chrono_tz::IANA_TZDB_VERSION
+}
+
+/// Lists every timezone whose local hour at the given instant equals `hour`
+/// 
+/// Zones with half-hour or quarter-hour offsets are matched on their hour component,
+/// so Asia/Kolkata at 09:30 local time matches an `hour` of 9. Like [`list_timezones_at`],
+/// this leaves out legacy names and the links for places that share another zone's clocks.
+/// 
+/// # Arguments
+/// 
+/// * `hour` - The local hour to look for (0-23)
+/// * `at` - The instant at which to evaluate each zone's local time
+/// 
+/// # Returns
+/// 
+/// * `Vec<&'static str>` - The IANA names of the matching timezones
+/// 
+/// # Example
+/// 
+/// ```rust
+/// use chrono::{TimeZone, Utc};
+/// use timezone_converter::zones_at_local_hour;
+/// 
+/// let at = Utc.with_ymd_and_hms(2024, 1, 15, 14, 0, 0).unwrap();
+/// let zones = zones_at_local_hour(9, at);
+/// assert!(zones.contains(&"America/New_York"));
+/// ```
+pub fn zones_at_local_hour(hour: u32, at: DateTime<Utc>) -> Vec<&'static str> {
+    chrono_tz::TZ_VARIANTS
+        .iter()
+        .filter(|tz| !aliases::is_backward_link(**tz) && at.with_timezone(*tz).hour() == hour)
+        .map(|tz| tz.name())
+        .collect()
+}
+
+/// Decodes a value produced by [`TimeZoneConverter::pack_status`]
+/// 
+/// # Arguments
+/// 
+/// * `packed` - The packed status
+/// 
+/// # Returns
+/// 
+/// * `PackedStatus` - The decoded offsets and DST flags
+pub fn unpack_status(packed: u64) -> PackedStatus {
+    PackedStatus {
+        source_offset_minutes: (packed & 0xFFFF) as u16 as i16,
+        target_offset_minutes: ((packed >> 16) & 0xFFFF) as u16 as i16,
+        source_is_dst: packed & (1 << 32) != 0,
+        target_is_dst: packed & (1 << 33) != 0,
+    }
+}
+
+/// Converts a datetime to the named timezone, or to a fixed fallback offset if the name is not a valid timezone
+/// 
+/// This lets callers working with dynamic zone names degrade gracefully instead of
+/// failing outright. A fallback outside the range of ±24 hours is treated as UTC.
+/// 
+/// # Arguments
+/// 
+/// * `name` - The timezone identifier to convert to (e.g., "Europe/London")
+/// * `dt` - The datetime to convert
+/// * `fallback_offset_secs` - The offset east of UTC to apply if `name` is not a valid timezone
+/// 
+/// # Returns
+/// 
+/// * `DateTime<FixedOffset>` - The converted datetime with its offset fixed
+/// 
+/// # Example
+/// 
+/// ```rust
+/// use chrono::{TimeZone, Utc};
+/// use timezone_converter::try_convert_name;
+/// 
+/// let dt = Utc.with_ymd_and_hms(2024, 1, 15, 12, 0, 0).unwrap();
+/// assert_eq!(try_convert_name("Asia/Tokyo", dt, 0).to_rfc3339(), "2024-01-15T21:00:00+09:00");
+/// assert_eq!(try_convert_name("Moon/Base", dt, 3600).to_rfc3339(), "2024-01-15T13:00:00+01:00");
+/// ```
+pub fn try_convert_name<T: ChronoTimeZone>(name: &str, dt: DateTime<T>, fallback_offset_secs: i32) -> DateTime<FixedOffset> {
+    match name.parse::<ZoneSpec>() {
+        Ok(tz) => dt.with_timezone(&tz).fixed_offset(),
+        Err(_) => {
+            let fallback = FixedOffset::east_opt(fallback_offset_secs)
+                .unwrap_or_else(|| Utc.fix());
+            dt.with_timezone(&fallback)
+        }
+    }
+}
+
+/// Gets the distinct UTC offsets present among a group of timezones at an instant
+/// 
+/// # Arguments
+/// 
+/// * `zones` - The timezone identifiers to inspect
+/// * `at` - The instant at which to evaluate the offsets
+/// 
+/// # Returns
+/// 
+/// * `Result<Vec<i32>, Errors>` - The unique offsets in seconds, sorted ascending, or an error for an invalid zone name
+/// 
+/// # Example
+/// 
+/// ```rust
+/// use chrono::{TimeZone, Utc};
+/// use timezone_converter::distinct_offsets_among;
+/// 
+/// let at = Utc.with_ymd_and_hms(2024, 1, 15, 12, 0, 0).unwrap();
+/// let offsets = distinct_offsets_among(&["Europe/Paris", "Europe/Berlin", "UTC"], at).unwrap();
+/// assert_eq!(offsets, vec![0, 3600]);
+/// ```
+pub fn distinct_offsets_among(zones: &[&str], at: DateTime<Utc>) -> Result<Vec<i32>, Errors> {
+    let mut offsets = zones
+        .iter()
+        .map(|name| {
+            Ok(offset_seconds_at(&parse_tz(name)?, at))
+        })
+        .collect::<Result<Vec<i32>, Errors>>()?;
+
+    offsets.sort_unstable();
+    offsets.dedup();
+    Ok(offsets)
+}
+
+/// Gets the instant exactly halfway between two datetimes
+/// 
+/// Only the instants matter, so the inputs may be in any timezones. Render the result in
+/// a particular zone with a converter.
+/// 
+/// # Arguments
+/// 
+/// * `a` - The first datetime
+/// * `b` - The second datetime
+/// 
+/// # Returns
+/// 
+/// * `DateTime<Utc>` - The midpoint instant
+/// 
+/// # Example
+/// 
+/// ```rust
+/// use chrono::{TimeZone, Utc};
+/// use chrono_tz::Asia::Tokyo;
+/// use timezone_converter::midpoint;
+/// 
+/// let a = Utc.with_ymd_and_hms(2024, 7, 1, 0, 0, 0).unwrap();
+/// let b = Tokyo.with_ymd_and_hms(2024, 7, 1, 19, 0, 0).unwrap();
+/// assert_eq!(midpoint(&a, &b), Utc.with_ymd_and_hms(2024, 7, 1, 5, 0, 0).unwrap());
+/// ```
+pub fn midpoint<A: ChronoTimeZone, B: ChronoTimeZone>(a: &DateTime<A>, b: &DateTime<B>) -> DateTime<Utc> {
+    let a = a.with_timezone(&Utc);
+    let b = b.with_timezone(&Utc);
+    a + (b - a) / 2
+}
+
+/// Converts a datetime to its Julian Day Number on the UTC time scale
+/// 
+/// # Arguments
+/// 
+/// * `dt` - The datetime to convert
+/// 
+/// # Returns
+/// 
+/// * `f64` - The Julian Day Number
+/// 
+/// # Example
+/// 
+/// ```rust
+/// use chrono::{TimeZone, Utc};
+/// use timezone_converter::to_julian_day;
+/// 
+/// let dt = Utc.with_ymd_and_hms(2000, 1, 1, 12, 0, 0).unwrap();
+/// assert_eq!(to_julian_day(&dt), 2_451_545.0);
+/// ```
+pub fn to_julian_day<T: ChronoTimeZone>(dt: &DateTime<T>) -> f64 {
+    dt.timestamp_micros() as f64 / 86_400_000_000.0 + UNIX_EPOCH_JULIAN_DAY
+}
+
+/// Lists the timezones whose offset and DST state at an instant match the given values
+/// 
+/// This narrows down candidate zones from captured offset information, such as what a
+/// browser reports for its local time. Like [`list_timezones_at`], this leaves out legacy
+/// names and the links for places that share another zone's clocks.
+/// 
+/// # Arguments
+/// 
+/// * `offset_secs` - The offset east of UTC in seconds
+/// * `is_dst` - Whether DST is in effect
+/// * `at` - The instant at which the offset was observed
+/// 
+/// # Returns
+/// 
+/// * `Vec<&'static str>` - The IANA names of the matching timezones
+/// 
+/// # Example
+/// 
+/// ```rust
+/// use chrono::{TimeZone, Utc};
+/// use timezone_converter::zones_with_offset_dst;
+/// 
+/// let at = Utc.with_ymd_and_hms(2024, 7, 1, 12, 0, 0).unwrap();
+/// let zones = zones_with_offset_dst(-4 * 3600, true, at);
+/// assert!(zones.contains(&"America/New_York"));
+/// assert!(!zones.contains(&"America/Puerto_Rico"));
+/// ```
+pub fn zones_with_offset_dst(offset_secs: i32, is_dst: bool, at: DateTime<Utc>) -> Vec<&'static str> {
+    chrono_tz::TZ_VARIANTS
+        .iter()
+        .filter(|tz| !aliases::is_backward_link(**tz))
+        .filter(|tz| offset_seconds_at(*tz, at) == offset_secs && is_dst_at(&ZoneSpec::Named(**tz), at) == is_dst)
+        .map(|tz| tz.name())
+        .collect()
+}
+
+/// Parses a timezone identifier, suggesting close matches when it is invalid
+pub(crate) fn parse_tz(name: &str) -> Result<Tz, Errors> {
+    name.parse::<Tz>().map_err(|_| Errors::InvalidTimeZone {
+        input: name.to_string(),
+        suggestions: timezone_suggestions(name),
+    })
+}
+
+/// The most suggestions an [`Errors::InvalidTimeZone`] carries
+const MAX_SUGGESTIONS: usize = 5;
+
+/// Finds the valid identifiers closest to an invalid `name`, for [`Errors::InvalidTimeZone`]
+fn timezone_suggestions(name: &str) -> Vec<String> {
+    suggest_timezones(name, MAX_SUGGESTIONS).into_iter().map(String::from).collect()
+}
+
+/// Counts the single-character insertions, deletions, substitutions and adjacent swaps
+/// needed to turn `a` into `b` (the optimal string alignment distance)
+fn edit_distance(a: &str, b: &str) -> usize {
+    let (a, b): (Vec<char>, Vec<char>) = (a.chars().collect(), b.chars().collect());
+    // Rows for the two previous prefixes of `a` and the current one
+    let mut before: Vec<usize> = vec![0; b.len() + 1];
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    let mut current = vec![0; b.len() + 1];
+    for i in 1..=a.len() {
+        current[0] = i;
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            current[j] = (previous[j] + 1).min(current[j - 1] + 1).min(previous[j - 1] + cost);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                current[j] = current[j].min(before[j - 2] + 1);
+            }
+        }
+        std::mem::swap(&mut before, &mut previous);
+        std::mem::swap(&mut previous, &mut current);
+    }
+    previous[b.len()]
+}
+
+/// Formats an instant as a wall-clock time in a timezone, rejecting invalid formats instead of panicking
+fn format_in<T: ChronoTimeZone>(tz: &ZoneSpec, datetime: DateTime<T>, fmt: &str) -> Result<String, Errors> {
+    validate_format(fmt)?;
+    Ok(datetime.with_timezone(tz).format(fmt).to_string())
+}
+
+/// Checks that a strftime-style format contains no unknown specifiers
+pub(crate) fn validate_format(fmt: &str) -> Result<(), Errors> {
+    if StrftimeItems::new(fmt).any(|item| matches!(item, Item::Error)) {
+        return Err(Errors::ParseError(format!("invalid format '{}'", fmt)));
+    }
+    Ok(())
+}
+
+/// Formats an instant as an RFC 2822 date in a timezone, rejecting years chrono would panic on
+fn to_rfc2822_in<T: ChronoTimeZone>(tz: &ZoneSpec, dt: DateTime<T>) -> Result<String, Errors> {
+    let local = dt.with_timezone(tz);
+    if !(0..=9999).contains(&local.year()) {
+        return Err(Errors::ConversionError(format!(
+            "{} cannot be represented in RFC 2822",
+            local
+        )));
+    }
+    Ok(local.to_rfc2822())
+}
+
+/// The tz database's `zone.tab`: one row per country and zone, with coordinates
+pub(crate) const ZONE_TAB: &str = include_str!("../data/zone.tab");
+
+/// The data rows of a tab-separated table in the tz database's format, split on tabs
+pub(crate) fn tab_rows(table: &'static str) -> impl Iterator<Item = Vec<&'static str>> {
+    table
+        .lines()
+        .filter(|line| !line.starts_with('#') && !line.trim().is_empty())
+        .map(|line| line.split('\t').collect())
+}
+
+/// The instants from midnight UTC on 1 January of the first year of an inclusive range
+/// to midnight UTC on 1 January after the last, if the range is non-empty and representable
+fn year_span((first, last): (i32, i32)) -> Option<(DateTime<Utc>, DateTime<Utc>)> {
+    if first > last {
+        return None;
+    }
+    let start = Utc.with_ymd_and_hms(first, 1, 1, 0, 0, 0).single()?;
+    let end = Utc.with_ymd_and_hms(last.checked_add(1)?, 1, 1, 0, 0, 0).single()?;
+    Some((start, end))
+}
+
+/// Whether two timezones have the same UTC offset at every instant in `[start, end)`
+fn same_offset_history(a: Tz, b: Tz, start: DateTime<Utc>, end: DateTime<Utc>) -> bool {
+    let changes = offset_changes(&a, start, end);
+    changes == offset_changes(&b, start, end)
+        && std::iter::once(start).chain(changes).all(|at| offset_seconds_at(&a, at) == offset_seconds_at(&b, at))
+}
+
+/// Resolves a local time that occurs exactly once in a timezone, for [`TimeZoneConverter::verify_round_trip`]
+fn unique_local(tz: &ZoneSpec, local: NaiveDateTime, zone: Zone) -> Result<DateTime<Utc>, RoundTripError> {
+    match tz.from_local_datetime(&local) {
+        LocalResult::Single(dt) => Ok(dt.with_timezone(&Utc)),
+        LocalResult::Ambiguous(earliest, latest) => Err(RoundTripError::Ambiguous {
+            zone,
+            local,
+            earliest: earliest.with_timezone(&Utc),
+            latest: latest.with_timezone(&Utc),
+        }),
+        LocalResult::None => Err(RoundTripError::Nonexistent { zone, local }),
+    }
+}
+
+/// Gets the UTC offset in seconds of a timezone at the given instant
+fn offset_seconds_at<Z: ChronoTimeZone>(tz: &Z, instant: DateTime<Utc>) -> i32 {
+    instant.with_timezone(tz).offset().fix().local_minus_utc()
+}
+
+/// The names the tz database gives UTC: `Etc/UTC`, `Etc/GMT` and the names linked to them
+const UTC_ALIASES: [&str; 18] = [
+    "Etc/UTC", "Etc/UCT", "Etc/Universal", "Etc/Zulu", "UCT", "UTC", "Universal", "Zulu",
+    "Etc/GMT", "Etc/GMT+0", "Etc/GMT-0", "Etc/GMT0", "Etc/Greenwich", "GMT", "GMT+0", "GMT-0", "GMT0", "Greenwich",
+];
+
+/// Whether a timezone is `Etc/UTC`, one of its aliases, or a zero fixed offset or POSIX rule without DST
+fn is_utc_alias(tz: &ZoneSpec) -> bool {
+    match tz {
+        ZoneSpec::Named(tz) => UTC_ALIASES.contains(&tz.name()),
+        ZoneSpec::Fixed(offset) => offset.local_minus_utc() == 0,
+        ZoneSpec::Posix(posix) => posix.dst_abbreviation().is_none() && posix.std_offset().local_minus_utc() == 0,
+        ZoneSpec::Custom(_) => false,
+        #[cfg(feature = "system-tz")]
+        ZoneSpec::System(zone) => UTC_ALIASES.contains(&zone.name()),
+    }
+}
+
+/// The tz database release a timezone's rules come from: the system's for zones read by
+/// [`SystemTzdb`], or "unknown" if its directory does not record it, and the compiled-in
+/// release otherwise
+pub(crate) fn zone_tzdb_version(tz: &ZoneSpec) -> &'static str {
+    match tz {
+        #[cfg(feature = "system-tz")]
+        ZoneSpec::System(zone) => zone.tzdb_version().unwrap_or("unknown"),
+        _ => tzdb_version(),
+    }
+}
+
+/// Whether a timezone never changes offset: a literal offset, a POSIX rule or custom zone without DST, or
+/// one of the fixed-offset zones (UTC and its aliases, or `Etc/*`)
+fn is_fixed_offset(tz: &ZoneSpec) -> bool {
+    const FIXED_ZONES: [&str; 9] = [
+        "UTC", "UCT", "GMT", "GMT0", "GMT+0", "GMT-0", "Greenwich", "Universal", "Zulu",
+    ];
+    match tz {
+        ZoneSpec::Named(tz) => tz.name().starts_with("Etc/") || FIXED_ZONES.contains(&tz.name()),
+        ZoneSpec::Fixed(_) => true,
+        ZoneSpec::Posix(posix) => posix.dst_abbreviation().is_none(),
+        ZoneSpec::Custom(zone) => zone.rules().dst_abbreviation().is_none(),
+        #[cfg(feature = "system-tz")]
+        ZoneSpec::System(zone) => zone.is_fixed(),
+    }
+}
+
+/// Resolves a local wall-clock time in a timezone
+/// 
+/// Ambiguous times resolve to the earliest occurrence, and times inside a DST gap are
+/// shifted forward by the length of the gap.
+pub(crate) fn resolve_local_forward<Z: ChronoTimeZone + fmt::Display>(tz: &Z, naive: NaiveDateTime) -> Result<DateTime<Z>, Errors> {
+    resolve_local(tz, naive, AmbiguityPolicy::ShiftForward)
+}
+
+/// Resolves a local wall-clock time in a timezone according to an [`AmbiguityPolicy`]
+pub(crate) fn resolve_local<Z: ChronoTimeZone + fmt::Display>(tz: &Z, naive: NaiveDateTime, policy: AmbiguityPolicy) -> Result<DateTime<Z>, Errors> {
+    match tz.from_local_datetime(&naive) {
+        LocalResult::Single(dt) => Ok(dt),
+        LocalResult::Ambiguous(earliest, latest) => match policy {
+            AmbiguityPolicy::Earliest | AmbiguityPolicy::ShiftForward => Ok(earliest),
+            AmbiguityPolicy::Latest => Ok(latest),
+            AmbiguityPolicy::Reject => Err(Errors::ConversionError(format!(
+                "{} occurs twice in {}",
+                naive,
+                tz
+            ))),
+        },
+        LocalResult::None => {
+            let out_of_range = || Errors::ConversionError(format!("{} is out of range", naive));
+            // Applying the offset from one side of the gap lands on the other side of it
+            let reference = match policy {
+                AmbiguityPolicy::Latest | AmbiguityPolicy::ShiftForward => {
+                    naive.checked_sub_signed(Duration::days(1)).ok_or_else(out_of_range)?
+                }
+                AmbiguityPolicy::Earliest => {
+                    naive.checked_add_signed(Duration::days(1)).ok_or_else(out_of_range)?
+                }
+                AmbiguityPolicy::Reject => {
+                    return Err(Errors::ConversionError(format!(
+                        "{} does not exist in {}",
+                        naive,
+                        tz
+                    )));
+                }
+            };
+            let offset = tz.offset_from_utc_datetime(&reference).fix();
+            let utc = naive - Duration::seconds(offset.local_minus_utc() as i64);
+            Ok(utc.and_utc().with_timezone(tz))
+        }
+    }
+}
+
+/// How far apart offset samples are taken when scanning for transitions.
+/// Real-world zones never change offset twice within this window.
+const TRANSITION_SCAN_STEP_HOURS: i64 = 12;
+
+/// Finds every instant in `[start, end)` at which the timezone's UTC offset changes
+fn offset_changes<Z: ChronoTimeZone>(tz: &Z, start: DateTime<Utc>, end: DateTime<Utc>) -> Vec<DateTime<Utc>> {
+    offset_changes_iter(tz, start, end).collect()
+}
+
+/// Yields each instant in `[start, end)` at which the timezone's UTC offset changes, scanning only as far as it is consumed
+fn offset_changes_iter<'a, Z: ChronoTimeZone>(tz: &'a Z, start: DateTime<Utc>, end: DateTime<Utc>) -> impl Iterator<Item = DateTime<Utc>> + 'a {
+    changes_iter(start, end, move |at| offset_seconds_at(tz, at))
+}
+
+/// Finds every instant in `[start, end)` at which the timezone's offset or abbreviation changes
+fn timespan_changes<Z: ChronoTimeZone>(tz: &Z, start: DateTime<Utc>, end: DateTime<Utc>) -> Vec<DateTime<Utc>>
+where
+    Z::Offset: PartialEq,
+{
+    changes_by(start, end, |at| tz.offset_from_utc_datetime(&at.naive_utc()))
+}
+
+/// Finds every instant in `[start, end)` at which `key` changes value
+fn changes_by<K: PartialEq>(start: DateTime<Utc>, end: DateTime<Utc>, key: impl Fn(DateTime<Utc>) -> K) -> Vec<DateTime<Utc>> {
+    changes_iter(start, end, key).collect()
+}
+
+/// Yields each instant in `[start, end)` at which `key` changes value, in order
+/// 
+/// The range is sampled every [`TRANSITION_SCAN_STEP_HOURS`] and each change is then
+/// narrowed down to the exact second by bisection.
+fn changes_iter<K: PartialEq>(start: DateTime<Utc>, end: DateTime<Utc>, key: impl Fn(DateTime<Utc>) -> K) -> impl Iterator<Item = DateTime<Utc>> {
+    let step = Duration::hours(TRANSITION_SCAN_STEP_HOURS);
+    let mut window_start = start;
+
+    std::iter::from_fn(move || {
+        while window_start < end {
+            let window_end = window_start.checked_add_signed(step).map_or(end, |next| std::cmp::min(next, end));
+            let before = key(window_start);
+            if key(window_end) == before {
+                window_start = window_end;
+                continue;
+            }
+
+            // Bisect for the first second whose key differs from `before`
+            let mut low = window_start.timestamp();
+            let mut high = window_end.timestamp();
+            while high - low > 1 {
+                let mid = low + (high - low) / 2;
+                if key(timestamp_to_utc(mid)) == before {
+                    low = mid;
+                } else {
+                    high = mid;
+                }
+            }
+
+            let change = timestamp_to_utc(high);
+            window_start = change;
+            if change < end {
+                return Some(change);
+            }
+        }
+        None
+    })
+}
+
+/// The offsets of a timezone over a range, as consecutive spans
+struct OffsetSpans<Z: ChronoTimeZone> {
+    /// The instant each span starts; the first is the start of the range
+    starts: Vec<DateTime<Utc>>,
+    /// The offset in effect during each span
+    offsets: Vec<Z::Offset>,
+}
+
+impl<Z: ChronoTimeZone> OffsetSpans<Z>
+where
+    Z::Offset: PartialEq,
+{
+    /// Computes the spans of a timezone covering `[start, end)`
+    fn new(tz: &Z, start: DateTime<Utc>, end: DateTime<Utc>) -> Self {
+        let mut starts = vec![start];
+        starts.extend(timespan_changes(tz, start, end));
+        let offsets = starts
+            .iter()
+            .map(|at| tz.offset_from_utc_datetime(&at.naive_utc()))
+            .collect();
+        Self { starts, offsets }
+    }
+
+    /// Whether the span at `index` covers the instant
+    fn contains(&self, index: usize, at: &DateTime<Utc>) -> bool {
+        self.starts[index] <= *at && self.starts.get(index + 1).is_none_or(|next| at < next)
+    }
+
+    /// Finds the index of the span covering an instant within the range
+    fn find(&self, at: &DateTime<Utc>) -> usize {
+        self.starts.partition_point(|start| start <= at).saturating_sub(1)
+    }
+}
+
+/// Converts a `SystemTime` into a UTC instant, failing if it is outside chrono's range
+fn system_time_to_utc(t: SystemTime) -> Result<DateTime<Utc>, Errors> {
+    let out_of_range = || Errors::ConversionError(format!("system time {:?} is out of range", t));
+    match t.duration_since(UNIX_EPOCH) {
+        Ok(after) => Duration::from_std(after).ok().and_then(|after| DateTime::UNIX_EPOCH.checked_add_signed(after)),
+        Err(before) => Duration::from_std(before.duration()).ok().and_then(|before| DateTime::UNIX_EPOCH.checked_sub_signed(before)),
+    }
+    .ok_or_else(out_of_range)
+}
+
+/// Converts whole seconds since the Unix epoch into a UTC instant
+fn timestamp_to_utc(secs: i64) -> DateTime<Utc> {
+    DateTime::from_timestamp(secs, 0).expect("timestamp derived from a valid instant")
+}
+
+/// How far from an instant to look for the neighbouring transitions.
+/// Zones observing DST change offset at least once in this span.
+const TRANSITION_SEARCH_DAYS: i64 = 366;
+
+/// Finds the latest offset change at or before `at`, looking back at most [`TRANSITION_SEARCH_DAYS`]
+fn previous_offset_change<Z: ChronoTimeZone>(tz: &Z, at: DateTime<Utc>) -> Option<DateTime<Utc>> {
+    let start = at.checked_sub_signed(Duration::days(TRANSITION_SEARCH_DAYS)).unwrap_or(DateTime::<Utc>::MIN_UTC);
+    let end = at.checked_add_signed(Duration::seconds(1)).unwrap_or(DateTime::<Utc>::MAX_UTC);
+    offset_changes(tz, start, end).pop()
+}
+
+/// Finds the earliest offset change strictly after `at`, looking ahead at most [`TRANSITION_SEARCH_DAYS`]
+fn next_offset_change<Z: ChronoTimeZone>(tz: &Z, at: DateTime<Utc>) -> Option<DateTime<Utc>> {
+    let end = at.checked_add_signed(Duration::days(TRANSITION_SEARCH_DAYS)).unwrap_or(DateTime::<Utc>::MAX_UTC);
+    offset_changes_iter(tz, at, end).find(|change| *change > at)
+}
+
+/// FNV-1a 64-bit offset basis
+const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+
+/// FNV-1a 64-bit prime
+const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+/// Folds bytes into an FNV-1a hash, which unlike `DefaultHasher` is stable across Rust releases
+fn fnv1a(mut hash: u64, bytes: &[u8]) -> u64 {
+    for byte in bytes {
+        hash ^= *byte as u64;
+        hash = hash.wrapping_mul(FNV_PRIME);
+    }
+    hash
+}
+
+/// Whether Daylight Saving Time is in effect in a timezone at the given instant
+pub(crate) fn is_dst_at(tz: &ZoneSpec, instant: DateTime<Utc>) -> bool {
+    !dst_savings_at(tz, instant).is_zero()
+}
+
+/// How far a timezone's clocks are ahead of its standard time at the given instant
+/// 
+/// This compares offsets rather than abbreviations, which do not reliably say whether DST
+/// is in effect ("BST", "CEST", "+03"). Europe/Dublin is the exception: the tz database
+/// records its summer time as standard and its winter time as negative DST, so a zero DST
+/// offset followed by a season of negative DST is reported as Irish summer time, as its clocks
+/// have it. Shorter spells of negative DST, such as Africa/Casablanca moving off its standard
+/// +01:00 for Ramadan, leave the rest of the year as standard time.
+pub(crate) fn dst_savings_at(tz: &ZoneSpec, instant: DateTime<Utc>) -> Duration {
+    let dst = instant.with_timezone(tz).offset().dst_offset();
+    if dst < Duration::zero() {
+        return Duration::zero();
+    }
+    if dst.is_zero() {
+        if let Some(winter) = next_negative_dst_season(tz, instant) {
+            return -winter;
+        }
+    }
+    dst
+}
+
+/// The shortest spell of negative DST read as a winter, making the time around it summer time
+const NEGATIVE_DST_SEASON_DAYS: i64 = 90;
+
+/// Finds the negative DST that the next transition after an instant switches to, if it lasts
+/// at least [`NEGATIVE_DST_SEASON_DAYS`]
+fn next_negative_dst_season(tz: &ZoneSpec, instant: DateTime<Utc>) -> Option<Duration> {
+    // Monthly samples spare the transition search for zones with no season of negative DST ahead
+    let dst_at = |at: DateTime<Utc>| at.with_timezone(tz).offset().dst_offset();
+    let mut samples = (1..=12).map_while(|month| instant.checked_add_signed(Duration::days(30 * month)));
+    if !samples.any(|at| dst_at(at) < Duration::zero()) {
+        return None;
+    }
+    let start = next_offset_change(tz, instant)?;
+    let winter = dst_at(start);
+    let lasts_a_season = next_offset_change(tz, start).is_none_or(|end| end - start >= Duration::days(NEGATIVE_DST_SEASON_DAYS));
+    (winter < Duration::zero() && lasts_a_season).then_some(winter)
+}
+
+/// Encodes status fields using the layout documented on [`TimeZoneConverter::pack_status`]
+fn pack_status_fields(status: PackedStatus) -> u64 {
+    (status.source_offset_minutes as u16 as u64)
+        | ((status.target_offset_minutes as u16 as u64) << 16)
+        | ((status.source_is_dst as u64) << 32)
+        | ((status.target_is_dst as u64) << 33)
+}
+
+/// Formats a duration in words, such as `5 hours 30 minutes` or `-1 hour`
+fn format_long_duration(duration: Duration) -> String {
+    let sign = if duration < Duration::zero() { "-" } else { "" };
+    let total_minutes = duration.num_minutes().abs();
+    let hours = total_minutes / 60;
+    let minutes = total_minutes % 60;
+    let plural = |n: i64, unit: &str| format!("{} {}{}", n, unit, if n == 1 { "" } else { "s" });
+
+    match (hours, minutes) {
+        (_, 0) => format!("{}{}", sign, plural(hours, "hour")),
+        (0, _) => format!("{}{}", sign, plural(minutes, "minute")),
+        _ => format!("{}{} {}", sign, plural(hours, "hour"), plural(minutes, "minute")),
+    }
+}
+
+/// Formats a duration as a signed hours/minutes label such as `+5h` or `-4h30m`
+fn format_short_duration(duration: Duration) -> String {
+    let sign = if duration < Duration::zero() { '-' } else { '+' };
+    let total_minutes = duration.num_minutes().abs();
+    let hours = total_minutes / 60;
+    let minutes = total_minutes % 60;
+
+    if minutes == 0 {
+        format!("{}{}h", sign, hours)
+    } else {
+        format!("{}{}h{}m", sign, hours, minutes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono_tz::America::New_York;
+    use chrono_tz::Africa::Kampala;
+
+    #[test]
+    #[allow(non_snake_case)]
+    fn NewYorktoKampala() {
+        let timezone = TimeZoneConverter::new("America/New_York", "Africa/Kampala").unwrap();
+        // Create a specific time in New York
+        let ny_time = New_York.with_ymd_and_hms(2024, 11, 4, 10, 0, 0).unwrap();
+        let time = timezone.convert(ny_time).unwrap();
+        // Print both times to verify the conversion
+        println!("New York: {}", ny_time);
+        println!("Kampala: {}", time);
+    }
+
+    #[test]
+    #[allow(non_snake_case)]
+    fn NewYorkToKampala_current() {
+        let timezone = TimeZoneConverter::new("America/New_York", "Africa/Kampala").unwrap();
+        let ny_time = Utc::now().with_timezone(&New_York);
+        let time = timezone.convert(ny_time).unwrap();
+        println!("New York: {}", ny_time);
+        println!("Kampala: {}", time);
+    }
+
+    #[test]
+    fn get_timezone_info() {
+        let timezone = TimeZoneConverter::new("America/New_York", "Africa/Kampala").unwrap();
+        let info = timezone.get_timezone_info().unwrap();
+        println!("{:?}", info);
+    }
+
+    #[test]
+    fn timezone_info_getters() {
+        let timezone = TimeZoneConverter::new("America/New_York", "Africa/Kampala").unwrap();
+        let summer = Utc.with_ymd_and_hms(2024, 7, 1, 12, 0, 0).unwrap();
+        let info = timezone.timezone_info_at(summer, Zone::Source).unwrap();
+        assert_eq!(info.name(), "America/New_York");
+        assert_eq!(info.offset(), Duration::hours(-4));
+        assert!(info.is_dst());
+        assert_eq!(info.dst_savings(), Duration::hours(1));
+        assert_eq!(info.tzdb_version(), tzdb_version());
+        assert_eq!(info.clone(), info);
+        assert_ne!(timezone.timezone_info_at(summer, Zone::Target).unwrap(), info);
+    }
+
+    #[test]
+    fn dst_from_offsets() {
+        let info = |zone: &str, month| {
+            let converter = TimeZoneConverter::single(zone).unwrap();
+            let instant = Utc.with_ymd_and_hms(2024, month, 15, 12, 0, 0).unwrap();
+            let info = converter.timezone_info_at(instant, Zone::Source).unwrap();
+            (info.is_dst(), info.dst_savings().num_minutes())
+        };
+        assert_eq!(info("Europe/London", 7), (true, 60));
+        assert_eq!(info("Europe/London", 1), (false, 0));
+        assert_eq!(info("Europe/Dublin", 7), (true, 60));
+        assert_eq!(info("Europe/Dublin", 1), (false, 0));
+        // Casablanca's standard time is +01:00, suspended for Ramadan
+        for month in [3, 5, 8, 9, 10, 12] {
+            assert_eq!(info("Africa/Casablanca", month), (false, 0), "month {}", month);
+        }
+        let casablanca = TimeZoneConverter::single("Africa/Casablanca").unwrap();
+        for (month, day) in [(8, 1), (9, 1), (9, 20), (10, 15)] {
+            let instant = Utc.with_ymd_and_hms(2024, month, day, 12, 0, 0).unwrap();
+            assert!(!casablanca.timezone_info_at(instant, Zone::Source).unwrap().is_dst(), "{}-{}", month, day);
+        }
+        assert_eq!(info("Australia/Adelaide", 1), (true, 60));
+        assert_eq!(info("Australia/Lord_Howe", 1), (true, 30));
+        assert_eq!(info("America/Sao_Paulo", 1), (false, 0));
+        assert_eq!(info("Europe/Istanbul", 7), (false, 0));
+
+        // Sampling stops at the latest representable instant
+        for zone in ["Europe/London", "Africa/Casablanca"] {
+            let converter = TimeZoneConverter::new("UTC", zone).unwrap();
+            assert!(converter.timezone_info_at(DateTime::<Utc>::MAX_UTC, Zone::Target).is_ok(), "{}", zone);
+        }
+    }
+
+    #[test]
+    fn get_time_difference() {
+        let timezone = TimeZoneConverter::new("America/New_York", "Africa/Kampala").unwrap();
+        let difference = timezone.get_time_difference().unwrap();
+        println!("Time difference: {} hours", difference);
+    }
+
+    #[test]
+    fn convert_to_sql_round_trip() {
+        let timezone = TimeZoneConverter::new("America/New_York", "Asia/Kolkata").unwrap();
+        let ny_time = New_York.with_ymd_and_hms(2024, 7, 4, 9, 30, 15).unwrap()
+            + Duration::microseconds(123456);
+        let sql = timezone.convert_to_sql(ny_time);
+        assert_eq!(sql, "2024-07-04 19:00:15.123456+05:30");
+
+        let parsed = DateTime::parse_from_str(&sql, "%Y-%m-%d %H:%M:%S%.6f%:z").unwrap();
+        assert_eq!(parsed, ny_time);
+    }
+
+    #[test]
+    fn difference_short_string() {
+        let timezone = TimeZoneConverter::new("Asia/Kolkata", "UTC").unwrap();
+        assert_eq!(timezone.difference_short_string().unwrap(), "+5h30m");
+
+        let timezone = TimeZoneConverter::new("UTC", "Asia/Tokyo").unwrap();
+        assert_eq!(timezone.difference_short_string().unwrap(), "-9h");
+
+        assert_eq!(format_short_duration(Duration::minutes(-270)), "-4h30m");
+        assert_eq!(format_short_duration(Duration::zero()), "+0h");
+    }
+    #[test]
+    fn zones_at_local_hour_matches() {
+        let at = Utc.with_ymd_and_hms(2024, 1, 15, 14, 0, 0).unwrap();
+        let zones = zones_at_local_hour(9, at);
+        assert!(zones.contains(&"America/New_York"));
+        assert!(!zones.contains(&"Europe/London"));
+
+        // Kolkata is at 19:30, matched on the hour component
+        assert!(zones_at_local_hour(19, at).contains(&"Asia/Kolkata"));
+
+        // Names that link to a listed zone are left out
+        assert!(!zones.contains(&"US/Eastern"));
+        assert!(!zones.contains(&"America/Montreal"));
+        assert!(!zones_at_local_hour(19, at).contains(&"Asia/Calcutta"));
+    }
+
+    #[test]
+    fn convert_with_leap_flag() {
+        let timezone = TimeZoneConverter::new("UTC", "Asia/Tokyo").unwrap();
+
+        let leap = NaiveDate::from_ymd_opt(2016, 12, 31).unwrap()
+            .and_hms_nano_opt(23, 59, 59, 1_500_000_000).unwrap()
+            .and_utc();
+        let (time, is_leap) = timezone.convert_with_leap_flag(leap).unwrap();
+        assert!(is_leap);
+        assert_eq!(time.format("%H:%M:%S").to_string(), "08:59:60");
+
+        let regular = Utc.with_ymd_and_hms(2016, 12, 31, 23, 59, 59).unwrap();
+        let (_, is_leap) = timezone.convert_with_leap_flag(regular).unwrap();
+        assert!(!is_leap);
+
+        let timezone = TimeZoneConverter::new("UTC", "+05:30").unwrap();
+        assert!(matches!(timezone.convert_with_leap_flag(regular), Err(Errors::ConversionError(_))));
+    }
+
+    #[test]
+    fn difference_summary() {
+        let timezone = TimeZoneConverter::new("Europe/London", "America/New_York").unwrap();
+        let from = NaiveDate::from_ymd_opt(2024, 3, 1).unwrap();
+        let to = NaiveDate::from_ymd_opt(2024, 4, 30).unwrap();
+        assert_eq!(
+            timezone.difference_summary(from, to).unwrap(),
+            "5 hours, changing to 4 hours on Mar 10 and to 5 hours on Mar 31"
+        );
+
+        let timezone = TimeZoneConverter::new("Asia/Kolkata", "UTC").unwrap();
+        assert_eq!(
+            timezone.difference_summary(from, to).unwrap(),
+            "constant 5 hours 30 minutes"
+        );
+
+        assert!(timezone.difference_summary(to, from).is_err());
+    }
+
+    #[test]
+    fn target_part_of_day() {
+        let timezone = TimeZoneConverter::new("America/New_York", "Asia/Tokyo").unwrap();
+        // 08:00 in New York is 22:00 in Tokyo
+        let ny_time = New_York.with_ymd_and_hms(2024, 7, 1, 8, 0, 0).unwrap();
+        assert_eq!(timezone.target_part_of_day(ny_time), PartOfDay::Night);
+
+        let utc_time = Utc.with_ymd_and_hms(2024, 7, 1, 3, 0, 0).unwrap();
+        assert_eq!(timezone.target_part_of_day(utc_time), PartOfDay::Afternoon);
+
+        assert_eq!(PartOfDay::from_hour(5), PartOfDay::Morning);
+        assert_eq!(PartOfDay::from_hour(17), PartOfDay::Evening);
+        assert_eq!(PartOfDay::from_hour(4), PartOfDay::Night);
+    }
+
+    #[test]
+    fn transition_count() {
+        let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let end = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+
+        let timezone = TimeZoneConverter::new("America/New_York", "UTC").unwrap();
+        assert_eq!(timezone.transition_count(start, end).unwrap(), 2);
+        assert!(timezone.transition_count(end, start).is_err());
+
+        let timezone = TimeZoneConverter::new("Etc/GMT+5", "UTC").unwrap();
+        assert_eq!(timezone.transition_count(start, end).unwrap(), 0);
+
+        let timezone = TimeZoneConverter::new("Asia/Tokyo", "UTC").unwrap();
+        assert_eq!(timezone.transition_count(start, end).unwrap(), 0);
+    }
+
+    #[test]
+    fn single_zone_converter() {
+        let timezone = TimeZoneConverter::single("Europe/Paris").unwrap();
+        assert!(timezone.is_identity());
+        assert_eq!(timezone.get_time_difference().unwrap(), 0.0);
+        assert!(TimeZoneConverter::single("Mars/Olympus").is_err());
+
+        let timezone = TimeZoneConverter::new("Europe/Paris", "Europe/Berlin").unwrap();
+        assert!(!timezone.is_identity());
+    }
+
+    #[test]
+    fn target_dst_period_fraction() {
+        let timezone = TimeZoneConverter::new("UTC", "America/New_York").unwrap();
+        // DST in 2024 ran from Mar 10 07:00 UTC to Nov 3 06:00 UTC
+        let start = Utc.with_ymd_and_hms(2024, 3, 10, 7, 0, 0).unwrap();
+        let end = Utc.with_ymd_and_hms(2024, 11, 3, 6, 0, 0).unwrap();
+        let middle = start + (end - start) / 2;
+
+        let fraction = timezone.target_dst_period_fraction(middle).unwrap().unwrap();
+        assert!((fraction - 0.5).abs() < 1e-9);
+        assert_eq!(timezone.target_dst_period_fraction(start).unwrap(), Some(0.0));
+
+        let timezone = TimeZoneConverter::new("UTC", "Asia/Tokyo").unwrap();
+        assert_eq!(timezone.target_dst_period_fraction(middle).unwrap(), None);
+    }
+
+    #[test]
+    fn convert_all_day() {
+        let timezone = TimeZoneConverter::new("America/New_York", "Asia/Tokyo").unwrap();
+        let date = NaiveDate::from_ymd_opt(2024, 7, 4).unwrap();
+        let (start, end) = timezone.convert_all_day(date).unwrap();
+        assert_eq!(start.to_string(), "2024-07-04 13:00:00 JST");
+        assert_eq!(end.to_string(), "2024-07-05 13:00:00 JST");
+
+        // The spring-forward day is only 23 hours long
+        let date = NaiveDate::from_ymd_opt(2024, 3, 10).unwrap();
+        let (start, end) = timezone.convert_all_day(date).unwrap();
+        assert_eq!(end - start, Duration::hours(23));
+
+        // Santiago skips midnight when DST starts
+        let timezone = TimeZoneConverter::new("America/Santiago", "UTC").unwrap();
+        let date = NaiveDate::from_ymd_opt(2024, 9, 8).unwrap();
+        let (start, _) = timezone.convert_all_day(date).unwrap();
+        assert_eq!(start.to_string(), "2024-09-08 04:00:00 UTC");
+    }
+
+    #[test]
+    fn compare_with() {
+        let timezone = TimeZoneConverter::new("America/New_York", "UTC").unwrap();
+        let at = Utc.with_ymd_and_hms(2024, 7, 1, 12, 0, 0).unwrap();
+
+        let comparison = timezone.compare_with("Asia/Kolkata", at).unwrap();
+        assert_eq!(comparison.zone, chrono_tz::Asia::Kolkata);
+        assert_eq!(comparison.offset, Duration::minutes(330));
+        assert_eq!(comparison.difference, Duration::minutes(570));
+        assert!(comparison.is_ahead);
+
+        let comparison = timezone.compare_with("America/Los_Angeles", at).unwrap();
+        assert_eq!(comparison.difference, Duration::hours(-3));
+        assert!(!comparison.is_ahead);
+
+        assert!(timezone.compare_with("Not/AZone", at).is_err());
+    }
+
+    #[test]
+    fn parse_source_local_24() {
+        let timezone = TimeZoneConverter::new("America/New_York", "Europe/London").unwrap();
+
+        // 24:00 on the last day of the month rolls into the next month
+        let time = timezone.parse_source_local_24("2024-06-30 24:00").unwrap();
+        assert_eq!(time.to_string(), "2024-07-01 05:00:00 BST");
+
+        let time = timezone.parse_source_local_24("2024-06-30 13:15").unwrap();
+        assert_eq!(time.to_string(), "2024-06-30 18:15:00 BST");
+
+        assert!(timezone.parse_source_local_24("24:00").is_ok());
+
+        assert!(timezone.parse_source_local_24("2024-06-30 24:30").is_err());
+        assert!(timezone.parse_source_local_24("25:00").is_err());
+        assert!(timezone.parse_source_local_24("2024-02-30 10:00").is_err());
+    }
+
+    #[test]
+    fn rules_fingerprint() {
+        let timezone = TimeZoneConverter::new("America/New_York", "Europe/London").unwrap();
+        let fingerprint = timezone.rules_fingerprint(2020, 2025).unwrap();
+        assert_eq!(fingerprint, timezone.rules_fingerprint(2020, 2025).unwrap());
+        assert_ne!(fingerprint, timezone.rules_fingerprint(2020, 2026).unwrap());
+
+        let other = TimeZoneConverter::new("America/Toronto", "Europe/London").unwrap();
+        assert_ne!(fingerprint, other.rules_fingerprint(2020, 2025).unwrap());
+
+        assert!(timezone.rules_fingerprint(2025, 2020).is_err());
+    }
+
+    #[test]
+    fn convert_nearest_day() {
+        let timezone = TimeZoneConverter::new("UTC", "America/New_York").unwrap();
+        let date = NaiveDate::from_ymd_opt(2024, 7, 4).unwrap();
+
+        let morning = New_York.with_ymd_and_hms(2024, 7, 4, 11, 59, 0).unwrap();
+        assert_eq!(timezone.convert_nearest_day(morning).unwrap(), date);
+        let evening = New_York.with_ymd_and_hms(2024, 7, 4, 12, 1, 0).unwrap();
+        assert_eq!(timezone.convert_nearest_day(evening).unwrap(), date.succ_opt().unwrap());
+
+        // The 23-hour spring-forward day reaches its midpoint at 12:30 local time
+        let date = NaiveDate::from_ymd_opt(2024, 3, 10).unwrap();
+        let before_midpoint = New_York.with_ymd_and_hms(2024, 3, 10, 12, 15, 0).unwrap();
+        assert_eq!(timezone.convert_nearest_day(before_midpoint).unwrap(), date);
+        let after_midpoint = New_York.with_ymd_and_hms(2024, 3, 10, 12, 45, 0).unwrap();
+        assert_eq!(timezone.convert_nearest_day(after_midpoint).unwrap(), date.succ_opt().unwrap());
+    }
+
+    #[test]
+    fn pack_status_round_trip() {
+        let status = PackedStatus {
+            source_offset_minutes: -300,
+            target_offset_minutes: 345,
+            source_is_dst: true,
+            target_is_dst: false,
+        };
+        let packed = pack_status_fields(status);
+        assert_eq!(packed >> 34, 0);
+        assert_eq!(unpack_status(packed), status);
+
+        let status = PackedStatus {
+            source_offset_minutes: 840,
+            target_offset_minutes: -720,
+            source_is_dst: false,
+            target_is_dst: true,
+        };
+        assert_eq!(unpack_status(pack_status_fields(status)), status);
+
+        let timezone = TimeZoneConverter::new("Asia/Kathmandu", "Etc/GMT+10").unwrap();
+        let status = unpack_status(timezone.pack_status().unwrap());
+        assert_eq!(status.source_offset_minutes, 345);
+        assert_eq!(status.target_offset_minutes, -600);
+        assert!(!status.source_is_dst);
+        assert!(!status.target_is_dst);
+    }
+
+    #[test]
+    fn utc_relative() {
+        let timezone = TimeZoneConverter::new("Etc/GMT+5", "Asia/Kathmandu").unwrap();
+        let relative = timezone.utc_relative().unwrap();
+        assert_eq!(relative.source_offset_hours, -5.0);
+        assert_eq!(relative.target_offset_hours, 5.75);
+        assert_eq!(relative.difference_hours, -10.75);
+    }
+
+    #[test]
+    fn expand_daily() {
+        let timezone = TimeZoneConverter::new("America/New_York", "Europe/London").unwrap();
+        let from = NaiveDate::from_ymd_opt(2024, 3, 8).unwrap();
+        let runs = timezone.expand_daily(9, 30, from, 4).unwrap();
+        let rendered: Vec<String> = runs.iter().map(|dt| dt.to_string()).collect();
+        assert_eq!(rendered, [
+            "2024-03-08 14:30:00 GMT",
+            "2024-03-09 14:30:00 GMT",
+            "2024-03-10 13:30:00 GMT",
+            "2024-03-11 13:30:00 GMT",
+        ]);
+
+        assert!(timezone.expand_daily(24, 0, from, 1).is_err());
+        assert!(timezone.expand_daily(9, 0, from, 0).unwrap().is_empty());
+    }
+
+    #[test]
+    fn target_is_working() {
+        let timezone = TimeZoneConverter::new("UTC", "Asia/Tokyo").unwrap();
+        let weekdays = [Weekday::Mon, Weekday::Tue, Weekday::Wed, Weekday::Thu, Weekday::Fri];
+
+        // Monday 10:00 in Tokyo
+        let at = Utc.with_ymd_and_hms(2024, 7, 1, 1, 0, 0).unwrap();
+        assert!(timezone.target_is_working_at(at, 9, 17, &weekdays).unwrap());
+        // Saturday 10:00 in Tokyo
+        let at = Utc.with_ymd_and_hms(2024, 7, 6, 1, 0, 0).unwrap();
+        assert!(!timezone.target_is_working_at(at, 9, 17, &weekdays).unwrap());
+
+        // A night shift starting Friday 22:00 still covers Saturday 03:00
+        let at = Utc.with_ymd_and_hms(2024, 7, 5, 18, 0, 0).unwrap();
+        assert!(timezone.target_is_working_at(at, 22, 6, &weekdays).unwrap());
+        // but not Sunday 03:00
+        let at = Utc.with_ymd_and_hms(2024, 7, 6, 18, 0, 0).unwrap();
+        assert!(!timezone.target_is_working_at(at, 22, 6, &weekdays).unwrap());
+
+        assert!(timezone.target_is_working(9, 25, &weekdays).is_err());
+    }
+
+    #[test]
+    fn convert_with_fallback() {
+        let dt = New_York.with_ymd_and_hms(2024, 7, 1, 8, 0, 0).unwrap();
+
+        let timezone = TimeZoneConverter::new("America/New_York", "Europe/Paris").unwrap();
+        assert_eq!(timezone.convert_or(dt, 0).to_rfc3339(), "2024-07-01T14:00:00+02:00");
+
+        // Custom zones convert by their own rules rather than by looking up their name
+        let noon = Utc.with_ymd_and_hms(2024, 7, 1, 12, 0, 0).unwrap();
+        let plant = CustomZone::new("Plant Time", "PLT", FixedOffset::east_opt(3 * 3600 + 20 * 60).unwrap()).unwrap();
+        let timezone = TimeZoneConverter::from_tz(chrono_tz::UTC, plant);
+        assert_eq!(timezone.convert_or(noon, 0).to_rfc3339(), "2024-07-01T15:20:00+03:20");
+        assert_eq!(noon.with_timezone(&plant).format("%H:%M %Z").to_string(), "15:20 PLT");
+        let paris = CustomZone::new("Europe/Paris", "EXP", FixedOffset::east_opt(5 * 3600).unwrap()).unwrap();
+        let timezone = TimeZoneConverter::from_tz(chrono_tz::UTC, paris);
+        assert_eq!(timezone.convert_or(noon, 0).to_rfc3339(), "2024-07-01T17:00:00+05:00");
+
+        assert_eq!(try_convert_name("Europe/Paris", dt, 0).to_rfc3339(), "2024-07-01T14:00:00+02:00");
+        assert_eq!(try_convert_name("Europe/Pariss", dt, -3600).to_rfc3339(), "2024-07-01T11:00:00-01:00");
+        assert_eq!(try_convert_name("Europe/Pariss", dt, 100_000).to_rfc3339(), "2024-07-01T12:00:00+00:00");
+    }
+
+    #[test]
+    fn distinct_offsets_among() {
+        let at = Utc.with_ymd_and_hms(2024, 7, 15, 12, 0, 0).unwrap();
+        let zones = ["Asia/Kolkata", "Europe/London", "America/New_York", "Europe/Dublin", "Asia/Colombo"];
+        assert_eq!(super::distinct_offsets_among(&zones, at).unwrap(), vec![-14400, 3600, 19800]);
+
+        assert!(super::distinct_offsets_among(&[], at).unwrap().is_empty());
+        assert!(super::distinct_offsets_among(&["UTC", "Nowhere/Land"], at).is_err());
+    }
+
+    #[test]
+    fn until_same_date() {
+        let timezone = TimeZoneConverter::new("America/Los_Angeles", "Asia/Tokyo").unwrap();
+
+        // 20:00 in Los Angeles is 12:00 the next day in Tokyo
+        let now = Utc.with_ymd_and_hms(2024, 7, 2, 3, 0, 0).unwrap();
+        assert_eq!(timezone.until_same_date_at(now).unwrap(), Some(Duration::hours(4)));
+
+        // 10:00 in Los Angeles is 02:00 the next day in Tokyo
+        let now = Utc.with_ymd_and_hms(2024, 7, 1, 17, 0, 0).unwrap();
+        assert_eq!(timezone.until_same_date_at(now).unwrap(), Some(Duration::hours(14)));
+
+        // 17:00 in Los Angeles is 09:00 the next day in Tokyo, 7h until LA midnight
+        let swapped = TimeZoneConverter::new("Asia/Tokyo", "America/Los_Angeles").unwrap();
+        let now = Utc.with_ymd_and_hms(2024, 7, 2, 0, 0, 0).unwrap();
+        assert_eq!(swapped.until_same_date_at(now).unwrap(), Some(Duration::hours(7)));
+
+        // 06:00 in Los Angeles is 22:00 in Tokyo on the same date
+        let now = Utc.with_ymd_and_hms(2024, 7, 1, 13, 0, 0).unwrap();
+        assert_eq!(timezone.until_same_date_at(now).unwrap(), None);
+    }
+
+    #[test]
+    fn convert_guarded() {
+        let timezone = TimeZoneConverter::new("UTC", "Asia/Tokyo").unwrap();
+
+        let dt = Utc.with_ymd_and_hms(1850, 6, 1, 0, 0, 0).unwrap();
+        assert!(timezone.convert_guarded(dt).is_ok());
+
+        let dt = Utc.with_ymd_and_hms(0, 6, 1, 0, 0, 0).unwrap();
+        assert!(timezone.convert_guarded(dt).is_err());
+
+        // Still 9999 in UTC, but already 10000 in Tokyo
+        let dt = Utc.with_ymd_and_hms(9999, 12, 31, 20, 0, 0).unwrap();
+        assert!(timezone.convert_guarded(dt).is_err());
+    }
+
+    #[test]
+    fn abbrev_offset_line() {
+        let timezone = TimeZoneConverter::new("America/New_York", "Asia/Kolkata").unwrap();
+        let at = Utc.with_ymd_and_hms(2024, 7, 15, 12, 0, 0).unwrap();
+        assert_eq!(timezone.abbrev_offset_line(at).unwrap(), "EDT/-04:00 → IST/+05:30");
+
+        let timezone = TimeZoneConverter::new("Asia/Dubai", "UTC").unwrap();
+        assert_eq!(timezone.abbrev_offset_line(at).unwrap(), "+04/+04:00 → UTC/+00:00");
+    }
+
+    #[test]
+    fn convert_nudged() {
+        let timezone = TimeZoneConverter::new("UTC", "America/New_York").unwrap();
+
+        let dt = Utc.with_ymd_and_hms(2024, 7, 1, 12, 0, 0).unwrap();
+        assert_eq!(timezone.convert_nudged(dt, -15).unwrap().to_string(), "2024-07-01 07:45:00 EDT");
+
+        // 30 minutes after 01:45 EST is 03:15 EDT on the spring-forward night
+        let dt = Utc.with_ymd_and_hms(2024, 3, 10, 6, 45, 0).unwrap();
+        assert_eq!(timezone.convert_nudged(dt, 30).unwrap().to_string(), "2024-03-10 03:15:00 EDT");
+
+        let timezone = TimeZoneConverter::new("UTC", "+05:30").unwrap();
+        assert!(matches!(timezone.convert_nudged(dt, 30), Err(Errors::ConversionError(_))));
+    }
+
+    #[test]
+    fn agenda() {
+        let timezone = TimeZoneConverter::new("UTC", "Asia/Tokyo").unwrap();
+        let events = vec![
+            ("review".to_string(), Utc.with_ymd_and_hms(2024, 7, 1, 16, 0, 0).unwrap()),
+            ("standup".to_string(), Utc.with_ymd_and_hms(2024, 7, 1, 0, 0, 0).unwrap()),
+            ("retro".to_string(), Utc.with_ymd_and_hms(2024, 7, 1, 14, 0, 0).unwrap()),
+            ("lunch".to_string(), Utc.with_ymd_and_hms(2024, 7, 1, 3, 0, 0).unwrap()),
+        ];
+
+        let agenda = timezone.agenda(&events).unwrap();
+        let summary: Vec<(String, Vec<String>)> = agenda
+            .iter()
+            .map(|(date, events)| {
+                let labels = events.iter().map(|(label, dt)| format!("{} {}", dt.format("%H:%M"), label)).collect();
+                (date.to_string(), labels)
+            })
+            .collect();
+
+        assert_eq!(summary, vec![
+            ("2024-07-01".to_string(), vec![
+                "09:00 standup".to_string(),
+                "12:00 lunch".to_string(),
+                "23:00 retro".to_string(),
+            ]),
+            ("2024-07-02".to_string(), vec!["01:00 review".to_string()]),
+        ]);
+
+        assert!(timezone.agenda::<Utc>(&[]).unwrap().is_empty());
+
+        let timezone = TimeZoneConverter::new("UTC", "+05:30").unwrap();
+        assert!(matches!(timezone.agenda(&events), Err(Errors::ConversionError(_))));
+    }
+
+    #[test]
+    fn offset_for_local_fold() {
+        let timezone = TimeZoneConverter::single("Europe/London").unwrap();
+
+        let repeated = NaiveDate::from_ymd_opt(2024, 10, 27).unwrap().and_hms_opt(1, 30, 0).unwrap();
+        assert_eq!(timezone.offset_for_local_fold(repeated, false).unwrap(), Duration::hours(1));
+        assert_eq!(timezone.offset_for_local_fold(repeated, true).unwrap(), Duration::zero());
+
+        let regular = NaiveDate::from_ymd_opt(2024, 7, 1).unwrap().and_hms_opt(1, 30, 0).unwrap();
+        assert_eq!(timezone.offset_for_local_fold(regular, false).unwrap(), Duration::hours(1));
+        assert_eq!(timezone.offset_for_local_fold(regular, true).unwrap(), Duration::hours(1));
+
+        let skipped = NaiveDate::from_ymd_opt(2024, 3, 31).unwrap().and_hms_opt(1, 30, 0).unwrap();
+        assert!(timezone.offset_for_local_fold(skipped, false).is_err());
+    }
+
+    #[test]
+    fn converter_cache() {
+        let mut cache = ConverterCache::new();
+        assert!(cache.is_empty());
+
+        let first = cache.get("America/New_York", "Europe/London").unwrap() as *const TimeZoneConverter;
+        let second = cache.get("America/New_York", "Europe/London").unwrap() as *const TimeZoneConverter;
+        assert_eq!(first, second);
+        assert_eq!(cache.len(), 1);
+
+        cache.get("Europe/London", "America/New_York").unwrap();
+        assert_eq!(cache.len(), 2);
+
+        assert!(cache.get("Europe/London", "Atlantis/Capital").is_err());
+        assert_eq!(cache.len(), 2);
+
+        cache.clear();
+        assert!(cache.is_empty());
+    }
+
+    #[test]
+    fn target_in_repeated_hour() {
+        let timezone = TimeZoneConverter::new("UTC", "America/New_York").unwrap();
+
+        // Both 01:30 EDT and 01:30 EST are flagged
+        assert!(timezone.target_in_repeated_hour(Utc.with_ymd_and_hms(2024, 11, 3, 5, 30, 0).unwrap()));
+        assert!(timezone.target_in_repeated_hour(Utc.with_ymd_and_hms(2024, 11, 3, 6, 30, 0).unwrap()));
+        // 02:00 EST is after the repeated hour
+        assert!(!timezone.target_in_repeated_hour(Utc.with_ymd_and_hms(2024, 11, 3, 7, 0, 0).unwrap()));
+        assert!(!timezone.target_in_repeated_hour(Utc.with_ymd_and_hms(2024, 7, 1, 5, 30, 0).unwrap()));
+    }
+
+    #[test]
+    fn convert_with_next_transition() {
+        let timezone = TimeZoneConverter::new("America/New_York", "Australia/Sydney").unwrap();
+        let dt = New_York.with_ymd_and_hms(2024, 6, 1, 9, 0, 0).unwrap();
+        let (time, next) = timezone.convert_with_next_transition(dt).unwrap();
+        assert_eq!(time.to_string(), "2024-06-01 23:00:00 AEST");
+        assert_eq!(next.unwrap().to_string(), "2024-10-06 03:00:00 AEDT");
+
+        let timezone = TimeZoneConverter::new("America/New_York", "Asia/Tokyo").unwrap();
+        let (_, next) = timezone.convert_with_next_transition(dt).unwrap();
+        assert!(next.is_none());
+    }
+
+    #[test]
+    fn midpoint_of_instants() {
+        let a = New_York.with_ymd_and_hms(2024, 7, 1, 9, 0, 0).unwrap();
+        let b = Kampala.with_ymd_and_hms(2024, 7, 1, 20, 0, 0).unwrap();
+        assert_eq!(b.with_timezone(&Utc) - a.with_timezone(&Utc), Duration::hours(4));
+
+        let middle = midpoint(&a, &b);
+        assert_eq!(middle - a.with_timezone(&Utc), Duration::hours(2));
+        assert_eq!(b.with_timezone(&Utc) - middle, Duration::hours(2));
+        assert_eq!(midpoint(&b, &a), middle);
+    }
+
+    #[test]
+    fn convert_strict_no_transition() {
+        let timezone = TimeZoneConverter::new("UTC", "America/New_York").unwrap();
+
+        // Fall back at 06:00 UTC: both 01:30 EDT and 01:30 EST are rejected
+        assert!(timezone.convert_strict_no_transition(Utc.with_ymd_and_hms(2024, 11, 3, 5, 30, 0).unwrap()).is_err());
+        assert!(timezone.convert_strict_no_transition(Utc.with_ymd_and_hms(2024, 11, 3, 6, 30, 0).unwrap()).is_err());
+        let after = timezone.convert_strict_no_transition(Utc.with_ymd_and_hms(2024, 11, 3, 7, 0, 0).unwrap()).unwrap();
+        assert_eq!(after.to_string(), "2024-11-03 02:00:00 EST");
+
+        // Spring forward at 07:00 UTC: 01:59 EST is rejected, 00:59 EST is not
+        assert!(timezone.convert_strict_no_transition(Utc.with_ymd_and_hms(2024, 3, 10, 6, 59, 0).unwrap()).is_err());
+        assert!(timezone.convert_strict_no_transition(Utc.with_ymd_and_hms(2024, 3, 10, 5, 59, 0).unwrap()).is_ok());
+
+        // Lord Howe Island falls back half an hour at 15:00 UTC on Apr 6
+        let timezone = TimeZoneConverter::new("UTC", "Australia/Lord_Howe").unwrap();
+        let err = timezone.convert_strict_no_transition(Utc.with_ymd_and_hms(2024, 4, 6, 14, 45, 0).unwrap()).unwrap_err();
+        assert!(err.to_string().contains("within 30 minutes of"), "{}", err);
+        assert!(timezone.convert_strict_no_transition(Utc.with_ymd_and_hms(2024, 4, 6, 15, 15, 0).unwrap()).is_err());
+        assert!(timezone.convert_strict_no_transition(Utc.with_ymd_and_hms(2024, 4, 6, 14, 15, 0).unwrap()).is_ok());
+        assert!(timezone.convert_strict_no_transition(Utc.with_ymd_and_hms(2024, 4, 6, 15, 45, 0).unwrap()).is_ok());
+    }
+
+    #[test]
+    fn monthly_differences() {
+        let timezone = TimeZoneConverter::new("America/New_York", "Europe/Berlin").unwrap();
+        let days = timezone.monthly_differences(2024, 3).unwrap();
+        assert_eq!(days.len(), 31);
+        assert_eq!(days[0], (NaiveDate::from_ymd_opt(2024, 3, 1).unwrap(), -6.0));
+        // New York springs forward on Mar 10, Berlin on Mar 31
+        assert_eq!(days[8].1, -6.0);
+        assert_eq!(days[9].1, -5.0);
+        assert_eq!(days[29].1, -5.0);
+        assert_eq!(days[30].1, -6.0);
+
+        assert_eq!(timezone.monthly_differences(2024, 2).unwrap().len(), 29);
+        assert!(timezone.monthly_differences(2024, 13).is_err());
+    }
+
+    #[test]
+    fn julian_day_round_trip() {
+        let timezone = TimeZoneConverter::new("UTC", "America/New_York").unwrap();
+
+        let epoch = timezone.convert_julian_day(UNIX_EPOCH_JULIAN_DAY).unwrap();
+        assert_eq!(epoch.with_timezone(&Utc), DateTime::UNIX_EPOCH);
+        assert_eq!(epoch.to_string(), "1969-12-31 19:00:00 EST");
+
+        let dt = New_York.with_ymd_and_hms(2024, 7, 4, 9, 30, 15).unwrap();
+        let jd = to_julian_day(&dt);
+        assert_eq!(timezone.convert_julian_day(jd).unwrap(), dt);
+
+        // Noon on 4713-11-24 BCE (proleptic Gregorian) is Julian Day 0
+        let origin = timezone.convert_julian_day(0.0).unwrap().with_timezone(&Utc);
+        assert_eq!(origin, Utc.with_ymd_and_hms(-4713, 11, 24, 12, 0, 0).unwrap());
+
+        assert!(timezone.convert_julian_day(f64::NAN).is_err());
+        assert!(timezone.convert_julian_day(1e12).is_err());
+    }
+
+    #[test]
+    fn zones_with_offset_dst_matches() {
+        let at = Utc.with_ymd_and_hms(2024, 1, 15, 12, 0, 0).unwrap();
+
+        let standard = zones_with_offset_dst(-5 * 3600, false, at);
+        assert!(standard.contains(&"America/New_York"));
+        assert!(standard.contains(&"America/Bogota"));
+
+        let summer = zones_with_offset_dst(-5 * 3600, true, Utc.with_ymd_and_hms(2024, 7, 15, 12, 0, 0).unwrap());
+        assert!(summer.contains(&"America/Chicago"));
+        assert!(!summer.contains(&"America/Bogota"));
+        assert!(!summer.contains(&"US/Central"));
+        assert!(!standard.contains(&"US/Eastern") && !standard.contains(&"America/Montreal"));
+
+        assert!(zones_with_offset_dst(17, false, at).is_empty());
+    }
+
+    #[test]
+    fn convert_snapped_to_work() {
+        let timezone = TimeZoneConverter::new("America/New_York", "UTC").unwrap();
+        let at = |h, m| New_York.with_ymd_and_hms(2024, 7, 1, h, m, 0).unwrap();
+        let snap = |dt, slot| timezone.convert_snapped_to_work(dt, slot, 9, 17).unwrap().to_string();
+
+        assert_eq!(snap(at(10, 7), 15), "2024-07-01 14:00:00 UTC");
+        assert_eq!(snap(at(10, 8), 15), "2024-07-01 14:15:00 UTC");
+        // Clamped into the window
+        assert_eq!(snap(at(6, 0), 15), "2024-07-01 13:00:00 UTC");
+        assert_eq!(snap(at(20, 0), 15), "2024-07-01 21:00:00 UTC");
+        // 45 minute slots from 09:00 end at 16:30, the last slot before 17:00
+        assert_eq!(snap(at(16, 59), 45), "2024-07-01 20:30:00 UTC");
+
+        assert!(timezone.convert_snapped_to_work(at(10, 0), 0, 9, 17).is_err());
+        assert!(timezone.convert_snapped_to_work(at(10, 0), 15, 17, 9).is_err());
+
+        // A slot inside the spring-forward gap moves past it
+        let night = TimeZoneConverter::new("America/New_York", "UTC").unwrap();
+        let dt = New_York.with_ymd_and_hms(2024, 3, 10, 1, 50, 0).unwrap();
+        let snapped = night.convert_snapped_to_work(dt, 60, 0, 6).unwrap();
+        assert_eq!(snapped.with_timezone(&New_York).to_string(), "2024-03-10 03:00:00 EDT");
+    }
+
+    #[test]
+    fn current_iso_pair() {
+        let timezone = TimeZoneConverter::new("America/New_York", "Asia/Kolkata").unwrap();
+        let at = Utc.with_ymd_and_hms(2024, 7, 1, 12, 0, 0).unwrap();
+        assert_eq!(
+            timezone.iso_pair_at(at),
+            ("2024-07-01T08:00:00-04:00".to_string(), "2024-07-01T17:30:00+05:30".to_string())
+        );
+
+        let (source, target) = timezone.current_iso_pair();
+        let source = DateTime::parse_from_rfc3339(&source).unwrap();
+        let target = DateTime::parse_from_rfc3339(&target).unwrap();
+        assert_eq!(source, target);
+    }
+
+    #[test]
+    fn utc_aliases() {
+        for alias in UTC_ALIASES {
+            let timezone = TimeZoneConverter::new(alias, "Europe/London").unwrap();
+            assert!(timezone.source_is_utc(), "{} should be UTC", alias);
+            assert!(!timezone.target_is_utc());
+
+            let timezone = TimeZoneConverter::new("Europe/London", alias).unwrap();
+            assert!(timezone.target_is_utc(), "{} should be UTC", alias);
+        }
+
+        for alias in ["GMT", "Etc/GMT", "GMT0", "Greenwich", "Etc/Greenwich", "GMT+0"] {
+            assert!(TimeZoneConverter::new(alias, "UTC").unwrap().source_is_utc(), "{} should be UTC", alias);
+        }
+
+        // Zero offset in winter is not enough
+        let timezone = TimeZoneConverter::new("Europe/London", "Africa/Abidjan").unwrap();
+        assert!(!timezone.source_is_utc());
+        assert!(!timezone.target_is_utc());
+    }
+
+    #[test]
+    fn convert_from_str() {
+        let timezone = TimeZoneConverter::new("America/New_York", "Africa/Kampala").unwrap();
+        let time = timezone.convert_from_str("2024-11-04 10:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+        assert_eq!(time, Kampala.with_ymd_and_hms(2024, 11, 4, 18, 0, 0).unwrap());
+
+        let time = timezone.convert_from_str("04/07/2024 09:15", "%d/%m/%Y %H:%M").unwrap();
+        assert_eq!(time.to_string(), "2024-07-04 16:15:00 EAT");
+
+        match timezone.convert_from_str("2024-13-04 10:00", "%Y-%m-%d %H:%M") {
+            Err(Errors::ParseError(_)) => {}
+            other => panic!("expected a parse error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn convert_local_policies() {
+        let timezone = TimeZoneConverter::new("America/New_York", "UTC").unwrap();
+        let convert = |naive, policy| timezone.convert_local(naive, policy).map(|dt| dt.to_string());
+
+        let repeated = NaiveDate::from_ymd_opt(2024, 11, 3).unwrap().and_hms_opt(1, 30, 0).unwrap();
+        assert_eq!(convert(repeated, AmbiguityPolicy::Earliest).unwrap(), "2024-11-03 05:30:00 UTC");
+        assert_eq!(convert(repeated, AmbiguityPolicy::Latest).unwrap(), "2024-11-03 06:30:00 UTC");
+        assert_eq!(convert(repeated, AmbiguityPolicy::ShiftForward).unwrap(), "2024-11-03 05:30:00 UTC");
+        assert!(convert(repeated, AmbiguityPolicy::Reject).is_err());
+
+        // 02:30 is skipped: shifting back gives 01:30 EST, forward gives 03:30 EDT
+        let skipped = NaiveDate::from_ymd_opt(2024, 3, 10).unwrap().and_hms_opt(2, 30, 0).unwrap();
+        assert_eq!(convert(skipped, AmbiguityPolicy::Earliest).unwrap(), "2024-03-10 06:30:00 UTC");
+        assert_eq!(convert(skipped, AmbiguityPolicy::Latest).unwrap(), "2024-03-10 07:30:00 UTC");
+        assert_eq!(convert(skipped, AmbiguityPolicy::ShiftForward).unwrap(), "2024-03-10 07:30:00 UTC");
+        assert!(convert(skipped, AmbiguityPolicy::Reject).is_err());
+
+        let regular = NaiveDate::from_ymd_opt(2024, 7, 1).unwrap().and_hms_opt(9, 0, 0).unwrap();
+        assert_eq!(convert(regular, AmbiguityPolicy::Reject).unwrap(), "2024-07-01 13:00:00 UTC");
+    }
+
+    #[test]
+    fn convert_back_and_swapped() {
+        let timezone = TimeZoneConverter::new("America/New_York", "Africa/Kampala").unwrap();
+        let ny_time = New_York.with_ymd_and_hms(2024, 11, 4, 10, 0, 0).unwrap();
+        let kampala_time = timezone.convert(ny_time).unwrap();
+        assert_eq!(timezone.convert_back(kampala_time).unwrap(), ny_time);
+
+        let swapped = timezone.swapped();
+        assert_eq!(swapped.convert(kampala_time).unwrap(), ny_time);
+        assert_eq!(swapped.convert_back(ny_time).unwrap(), kampala_time);
+        assert_eq!(swapped.get_time_difference().unwrap(), -timezone.get_time_difference().unwrap());
+    }
+
+    #[test]
+    fn convert_batch_matches_convert() {
+        let timezone = TimeZoneConverter::new("UTC", "America/New_York").unwrap();
+        let start = Utc.with_ymd_and_hms(2024, 3, 9, 0, 0, 0).unwrap();
+        let mut items: Vec<DateTime<Utc>> = (0..5000)
+            .map(|i| start + Duration::minutes(i * 7))
+            .collect();
+        // Out-of-order items fall back to a span lookup
+        items.swap(10, 4000);
+        items.push(start - Duration::days(200));
+
+        let expected: Vec<DateTime<Tz>> = items.iter().map(|dt| timezone.convert(*dt).unwrap()).collect();
+        let converted = timezone.convert_batch(&items).unwrap();
+        assert_eq!(converted, expected);
+        for (converted, expected) in converted.iter().zip(&expected) {
+            assert_eq!(converted.to_string(), expected.to_string());
+        }
+
+        let mut buffer = vec![timezone.convert(start).unwrap(); 3];
+        timezone.convert_batch_into(&items[..2], &mut buffer).unwrap();
+        assert_eq!(buffer, expected[..2]);
+
+        assert!(timezone.convert_batch(&[]).unwrap().is_empty());
+
+        // A batch may end at the latest representable instant
+        let latest: Vec<DateTime<Utc>> = (0..100).map(|i| DateTime::<Utc>::MAX_UTC - Duration::minutes(i)).collect();
+        let expected: Vec<DateTime<Tz>> = latest.iter().map(|dt| dt.with_timezone(&New_York)).collect();
+        assert_eq!(timezone.convert_batch(&latest).unwrap(), expected);
+
+        let timezone = TimeZoneConverter::new("UTC", "+05:30").unwrap();
+        assert!(matches!(timezone.convert_batch(&items), Err(Errors::ConversionError(_))));
+        assert!(matches!(timezone.convert_batch_into(&items, &mut buffer), Err(Errors::ConversionError(_))));
+        assert!(buffer.is_empty());
+    }
+
+    #[test]
+    fn convert_timestamps() {
+        let timezone = TimeZoneConverter::new("UTC", "America/New_York").unwrap();
+        let expected = New_York.with_ymd_and_hms(2024, 7, 4, 12, 0, 0).unwrap();
+        let secs = expected.timestamp();
+
+        assert_eq!(timezone.convert_timestamp(secs).unwrap(), expected);
+        assert_eq!(timezone.convert_timestamp_millis(secs * 1000 + 250).unwrap(), expected + Duration::milliseconds(250));
+        assert_eq!(timezone.convert_timestamp_nanos(secs * 1_000_000_000 + 7).unwrap(), expected + Duration::nanoseconds(7));
+        assert_eq!(timezone.convert_timestamp(0).unwrap().to_string(), "1969-12-31 19:00:00 EST");
+
+        assert!(timezone.convert_timestamp(i64::MAX).is_err());
+        assert!(timezone.convert_timestamp_millis(i64::MIN).is_err());
+    }
+
+    #[test]
+    fn invalid_timezone_error() {
+        let error = TimeZoneConverter::new("america/new_york", "UTC").unwrap_err();
+        assert_eq!(error, Errors::InvalidTimeZone {
+            input: "america/new_york".to_string(),
+            suggestions: vec!["America/New_York".to_string()],
+        });
+        assert_eq!(error.to_string(), "invalid timezone 'america/new_york' (did you mean America/New_York?)");
+
+        match TimeZoneConverter::new("UTC", "tokyo").unwrap_err() {
+            Errors::InvalidTimeZone { input, suggestions } => {
+                assert_eq!(input, "tokyo");
+                assert_eq!(suggestions, vec!["Asia/Tokyo".to_string()]);
+            }
+            other => panic!("unexpected error {:?}", other),
+        }
+
+        let error = TimeZoneConverter::new("UTC", "Nowhere/Land").unwrap_err();
+        assert_eq!(error.to_string(), "invalid timezone 'Nowhere/Land'");
+    }
+
+    #[test]
+    fn errors_work_with_dyn_error() {
+        fn build() -> Result<TimeZoneConverter, Box<dyn std::error::Error>> {
+            Ok(TimeZoneConverter::new("UTC", "Not/AZone")?)
+        }
+        assert_eq!(build().unwrap_err().to_string(), "invalid timezone 'Not/AZone'");
+
+        let error = Errors::ConversionError("out of range".to_string());
+        assert_eq!(error.to_string(), "conversion error: out of range");
+        let error = Errors::ParseError("bad input".to_string());
+        assert_eq!(error.to_string(), "parse error: bad input");
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn timezone_info_serializes() {
+        let info = TimeZoneInfo {
+            name: "Asia/Kolkata".to_string(),
+            offset: Duration::minutes(330),
+            is_dst: false,
+            dst_savings: Duration::zero(),
+            tzdb_version: "2024b".to_string(),
+        };
+        let json = serde_json::to_string(&info).unwrap();
+        assert_eq!(json, r#"{"name":"Asia/Kolkata","offset_seconds":19800,"is_dst":false,"dst_savings_seconds":0,"tzdb_version":"2024b"}"#);
+        assert_eq!(serde_json::from_str::<TimeZoneInfo>(&json).unwrap(), info);
+    }
+
+    #[test]
+    fn timezone_info_for_either_zone() {
+        let timezone = TimeZoneConverter::new("America/New_York", "Europe/London").unwrap();
+        assert_eq!(timezone.get_timezone_info_target().unwrap().name(), "Europe/London");
+
+        let summer = Utc.with_ymd_and_hms(2024, 7, 1, 12, 0, 0).unwrap();
+        let source = timezone.timezone_info_at(summer, Zone::Source).unwrap();
+        assert_eq!(source.name(), "America/New_York");
+        assert_eq!(source.offset(), Duration::hours(-4));
+        assert!(source.is_dst());
+
+        let winter = Utc.with_ymd_and_hms(2024, 1, 15, 12, 0, 0).unwrap();
+        let source = timezone.timezone_info_at(winter, Zone::Source).unwrap();
+        assert_eq!(source.offset(), Duration::hours(-5));
+        assert!(!source.is_dst());
+    }
+
+    #[test]
+    fn next_and_previous_transition() {
+        let timezone = TimeZoneConverter::new("America/New_York", "Europe/London").unwrap();
+        let from = Utc.with_ymd_and_hms(2024, 7, 1, 0, 0, 0).unwrap();
+
+        let next = timezone.next_transition(from, Zone::Target).unwrap();
+        assert_eq!(next, Transition {
+            instant: Utc.with_ymd_and_hms(2024, 10, 27, 1, 0, 0).unwrap(),
+            offset_before: Duration::hours(1),
+            offset_after: Duration::zero(),
+            abbreviation_before: "BST".to_string(),
+            abbreviation_after: "GMT".to_string(),
+        });
+        assert_eq!(next.shift(), Duration::hours(-1));
+
+        let previous = timezone.previous_transition(from, Zone::Source).unwrap();
+        assert_eq!(previous.instant, Utc.with_ymd_and_hms(2024, 3, 10, 7, 0, 0).unwrap());
+        // The search is inclusive backwards and exclusive forwards
+        assert_eq!(timezone.previous_transition(previous.instant, Zone::Source).unwrap(), previous);
+        assert_ne!(timezone.next_transition(previous.instant, Zone::Source).unwrap(), previous);
+
+        let timezone = TimeZoneConverter::new("Asia/Tokyo", "UTC").unwrap();
+        assert!(timezone.next_transition(from, Zone::Source).is_none());
+        assert!(timezone.previous_transition(from, Zone::Target).is_none());
+
+        // The search window is clamped to the representable range
+        let timezone = TimeZoneConverter::new("America/New_York", "Europe/London").unwrap();
+        for zone in [Zone::Source, Zone::Target] {
+            assert!(timezone.next_transition(DateTime::<Utc>::MAX_UTC, zone).is_none());
+            assert!(timezone.previous_transition(DateTime::<Utc>::MIN_UTC, zone).is_none());
+        }
+    }
+
+    #[test]
+    fn transitions_between() {
+        let timezone = TimeZoneConverter::new("America/New_York", "Europe/London").unwrap();
+        let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let end = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+
+        let source: Vec<_> = timezone
+            .transitions_between(start, end, Zone::Source)
+            .into_iter()
+            .map(|transition| transition.instant)
+            .collect();
+        assert_eq!(source, [
+            Utc.with_ymd_and_hms(2024, 3, 10, 7, 0, 0).unwrap(),
+            Utc.with_ymd_and_hms(2024, 11, 3, 6, 0, 0).unwrap(),
+        ]);
+        let target = timezone.transitions_between(start, end, Zone::Target);
+        assert_eq!(target[0].instant, Utc.with_ymd_and_hms(2024, 3, 31, 1, 0, 0).unwrap());
+        assert_eq!(target[1].offset_after, Duration::zero());
+
+        assert!(timezone.transitions_between(end, start, Zone::Source).is_empty());
+        let timezone = TimeZoneConverter::new("UTC", "Asia/Tokyo").unwrap();
+        assert!(timezone.transitions_between(start, end, Zone::Source).is_empty());
+        assert!(timezone.transitions_between(start, end, Zone::Target).is_empty());
+    }
+
+    #[test]
+    fn time_difference_at() {
+        let timezone = TimeZoneConverter::new("Asia/Kathmandu", "America/New_York").unwrap();
+        let winter = Utc.with_ymd_and_hms(2024, 1, 15, 12, 0, 0).unwrap();
+        let summer = Utc.with_ymd_and_hms(2024, 7, 15, 12, 0, 0).unwrap();
+        assert_eq!(timezone.time_difference_at(winter), Duration::minutes(10 * 60 + 45));
+        assert_eq!(timezone.time_difference_at(summer), Duration::minutes(9 * 60 + 45));
+        assert_eq!(timezone.swapped().time_difference_at(summer), Duration::minutes(-(9 * 60 + 45)));
+    }
+
+    #[test]
+    fn difference_changes() {
+        let timezone = TimeZoneConverter::new("America/New_York", "Europe/London").unwrap();
+        let differences: Vec<_> = timezone
+            .difference_changes(2024)
+            .into_iter()
+            .map(|(_, difference)| difference.num_hours())
+            .collect();
+        assert_eq!(differences, [-4, -5, -4, -5]);
+
+        let timezone = TimeZoneConverter::new("Europe/Berlin", "Europe/Paris").unwrap();
+        assert!(timezone.difference_changes(2024).is_empty());
+        assert!(timezone.difference_changes(i32::MAX).is_empty());
+    }
+
+    #[test]
+    fn convert_checked() {
+        let timezone = TimeZoneConverter::new("Europe/London", "America/New_York").unwrap();
+        let at = |month, day, hour, minute| {
+            NaiveDate::from_ymd_opt(2024, month, day).unwrap().and_hms_opt(hour, minute, 0).unwrap()
+        };
+
+        let plain = timezone.convert_checked(at(7, 1, 12, 0)).unwrap();
+        assert_eq!(plain.datetime.to_string(), "2024-07-01 07:00:00 EDT");
+        assert!(!plain.crossed_dst_in_source && !plain.crossed_dst_in_target && !plain.date_changed);
+
+        // 01:30 is repeated in London on Oct 27 2024
+        let repeated = timezone.convert_checked(at(10, 27, 1, 30)).unwrap();
+        assert!(repeated.crossed_dst_in_source);
+        assert!(repeated.date_changed);
+
+        // 06:30 in London is the second 01:30 in New York on Nov 3 2024
+        let target_repeated = timezone.convert_checked(at(11, 3, 6, 30)).unwrap();
+        assert_eq!(target_repeated.datetime.to_string(), "2024-11-03 01:30:00 EST");
+        assert!(!target_repeated.crossed_dst_in_source);
+        assert!(target_repeated.crossed_dst_in_target);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn converter_serde_round_trip() {
+        let timezone: TimeZoneConverter =
+            serde_json::from_str(r#"{"source":"America/New_York","target":"Europe/London"}"#).unwrap();
+        assert_eq!(timezone.source_tz, New_York);
+        assert_eq!(
+            serde_json::to_string(&timezone).unwrap(),
+            r#"{"source":"America/New_York","target":"Europe/London"}"#
+        );
+        let error = serde_json::from_str::<TimeZoneConverter>(r#"{"source":"tokyo","target":"UTC"}"#).unwrap_err();
+        assert!(error.to_string().contains("did you mean Asia/Tokyo?"));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn transition_and_conversion_result_serde_round_trip() {
+        let timezone = TimeZoneConverter::new("America/New_York", "Asia/Tokyo").unwrap();
+        let from = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let transition = timezone.next_transition(from, Zone::Source).unwrap();
+        let json = serde_json::to_string(&transition).unwrap();
+        assert_eq!(
+            json,
+            r#"{"instant":"2024-03-10T07:00:00Z","offset_before_seconds":-18000,"offset_after_seconds":-14400,"abbreviation_before":"EST","abbreviation_after":"EDT"}"#
+        );
+        assert_eq!(serde_json::from_str::<Transition>(&json).unwrap(), transition);
+
+        let naive = NaiveDate::from_ymd_opt(2024, 3, 10).unwrap().and_hms_opt(2, 30, 0).unwrap();
+        let result = timezone.convert_checked(naive).unwrap();
+        let json = serde_json::to_string(&result).unwrap();
+        assert!(json.starts_with(r#"{"datetime":"2024-03-10T16:30:00+09:00[Asia/Tokyo]","#));
+        assert_eq!(serde_json::from_str::<ConversionResult>(&json).unwrap(), result);
+
+        let mismatched = json.replace("+09:00", "+08:00");
+        assert!(serde_json::from_str::<ConversionResult>(&mismatched).is_err());
+    }
+
+    #[test]
+    fn ambiguity_policy_applies_to_source_local_parsing() {
+        let timezone = TimeZoneConverter::builder()
+            .source(New_York)
+            .target("UTC")
+            .ambiguity_policy(AmbiguityPolicy::Latest)
+            .build()
+            .unwrap();
+        let time = timezone.convert_from_str("2024-11-03 01:30", "%Y-%m-%d %H:%M").unwrap();
+        assert_eq!(time.to_string(), "2024-11-03 06:30:00 UTC");
+        assert_eq!(timezone.swapped().ambiguity_policy(), AmbiguityPolicy::Latest);
+
+        let timezone = TimeZoneConverter::builder()
+            .source(New_York)
+            .target("UTC")
+            .ambiguity_policy(AmbiguityPolicy::Reject)
+            .build()
+            .unwrap();
+        assert!(timezone.parse_source_local_24("2024-03-10 02:30").is_err());
+        let skipped = NaiveDate::from_ymd_opt(2024, 3, 10).unwrap().and_hms_opt(2, 30, 0).unwrap();
+        assert!(timezone.convert_checked(skipped).is_err());
+    }
+
+    #[test]
+    fn from_tz() {
+        let timezone = TimeZoneConverter::from_tz(New_York, Kampala);
+        assert_eq!(timezone.source_tz, New_York);
+        assert_eq!(timezone.target_tz, Kampala);
+        let timezone = TimeZoneConverter::from((Kampala, New_York));
+        assert_eq!(timezone.source_tz, Kampala);
+        assert_eq!(timezone.ambiguity_policy(), AmbiguityPolicy::ShiftForward);
+    }
+
+    #[test]
+    fn injected_clock_drives_current_time_methods() {
+        let clock = Arc::new(FixedClock::new(Utc.with_ymd_and_hms(2024, 3, 9, 12, 0, 0).unwrap()));
+        let timezone = TimeZoneConverter::new("America/New_York", "Europe/London")
+            .unwrap()
+            .with_clock(Arc::clone(&clock));
+        assert_eq!(timezone.get_time_difference().unwrap(), -5.0);
+        assert_eq!(timezone.get_current_time_source().unwrap().to_string(), "2024-03-09 07:00:00 EST");
+        assert!(!timezone.get_timezone_info().unwrap().is_dst());
+
+        // New York springs forward on Mar 10, London not until Mar 31
+        clock.advance(Duration::days(2));
+        assert_eq!(timezone.get_time_difference().unwrap(), -4.0);
+        assert_eq!(timezone.get_current_time_source().unwrap().to_string(), "2024-03-11 08:00:00 EDT");
+        assert!(timezone.get_timezone_info().unwrap().is_dst());
+        assert_eq!(timezone.swapped().get_time_difference().unwrap(), 4.0);
+
+        clock.set(Utc.with_ymd_and_hms(2024, 4, 1, 12, 0, 0).unwrap());
+        assert_eq!(timezone.get_time_difference().unwrap(), -5.0);
+    }
+
+    #[test]
+    fn working_hours_overlap() {
+        let hours = |start, end| (NaiveTime::from_hms_opt(start, 0, 0).unwrap(), NaiveTime::from_hms_opt(end, 0, 0).unwrap());
+        let timezone = TimeZoneConverter::new("America/New_York", "Europe/London").unwrap();
+
+        // New York has sprung forward by Mar 11 2024 but London has not, so the overlap grows by an hour
+        let date = NaiveDate::from_ymd_opt(2024, 3, 11).unwrap();
+        let (start, end) = timezone.working_hours_overlap(hours(9, 17), hours(9, 17), date).unwrap().unwrap();
+        assert_eq!(start.to_string(), "2024-03-11 13:00:00 GMT");
+        assert_eq!(end.to_string(), "2024-03-11 17:00:00 GMT");
+
+        // An overnight target shift overlaps the source afternoon of the previous target day
+        let timezone = TimeZoneConverter::new("Europe/London", "Asia/Tokyo").unwrap();
+        let date = NaiveDate::from_ymd_opt(2024, 1, 15).unwrap();
+        let (start, end) = timezone.working_hours_overlap(hours(9, 17), hours(22, 6), date).unwrap().unwrap();
+        assert_eq!(start.to_string(), "2024-01-15 22:00:00 JST");
+        assert_eq!(end.to_string(), "2024-01-16 02:00:00 JST");
+
+        let timezone = TimeZoneConverter::new("America/Los_Angeles", "Europe/London").unwrap();
+        assert!(timezone.working_hours_overlap(hours(9, 17), hours(9, 17), date).unwrap().is_none());
+
+        let timezone = TimeZoneConverter::new("UTC", "+05:30").unwrap();
+        assert!(matches!(timezone.working_hours_overlap(hours(9, 17), hours(9, 17), date), Err(Errors::ConversionError(_))));
+    }
+
+    #[test]
+    fn format_in_either_zone() {
+        let timezone = TimeZoneConverter::new("Europe/London", "Australia/Sydney").unwrap();
+        let dt = Utc.with_ymd_and_hms(2024, 7, 1, 9, 30, 0).unwrap();
+        assert_eq!(timezone.format_in_source(dt, "%H:%M %Z %z").unwrap(), "10:30 BST +0100");
+        assert_eq!(timezone.format_in_target(dt, "%H:%M %Z %z").unwrap(), "19:30 AEST +1000");
+        assert_eq!(timezone.convert_formatted(dt, "%a %d %b").unwrap(), "Mon 01 Jul");
+        assert!(matches!(timezone.convert_formatted(dt, "%Q"), Err(Errors::ParseError(_))));
+    }
+
+    #[test]
+    fn rfc_output_in_either_zone() {
+        let timezone = TimeZoneConverter::new("America/New_York", "Asia/Kolkata").unwrap();
+        let dt = Utc.with_ymd_and_hms(2024, 12, 24, 18, 0, 0).unwrap();
+        assert_eq!(timezone.to_rfc3339_source(dt), "2024-12-24T13:00:00-05:00");
+        assert_eq!(timezone.to_rfc3339_target(dt), "2024-12-24T23:30:00+05:30");
+        assert_eq!(timezone.to_rfc2822_source(dt).unwrap(), "Tue, 24 Dec 2024 13:00:00 -0500");
+        assert_eq!(timezone.to_rfc2822_target(dt).unwrap(), "Tue, 24 Dec 2024 23:30:00 +0530");
+
+        let ancient = Utc.with_ymd_and_hms(-50, 1, 1, 0, 0, 0).unwrap();
+        assert!(matches!(timezone.to_rfc2822_target(ancient), Err(Errors::ConversionError(_))));
+    }
+
+    #[test]
+    fn system_time_input() {
+        let timezone = TimeZoneConverter::new("UTC", "America/New_York").unwrap();
+        let before_epoch = UNIX_EPOCH - std::time::Duration::from_millis(86_400_500);
+        assert_eq!(timezone.convert_system_time(before_epoch).unwrap().to_string(), "1969-12-30 18:59:59.500 EST");
+        let mtime = UNIX_EPOCH + std::time::Duration::new(1_720_000_000, 250);
+        assert_eq!(timezone.convert_system_time(mtime).unwrap().timestamp_subsec_nanos(), 250);
+        let far_future = UNIX_EPOCH + std::time::Duration::from_secs(u64::MAX / 4);
+        assert!(matches!(timezone.convert_system_time(far_future), Err(Errors::ConversionError(_))));
+    }
+
+    #[test]
+    fn local_source_timezone() {
+        match local_timezone() {
+            Ok(local) => {
+                let timezone = TimeZoneConverter::from_local_to("Asia/Tokyo").unwrap();
+                assert_eq!(timezone.source_tz, local);
+                assert_eq!(timezone.target_tz, Tz::Asia__Tokyo);
+            }
+            Err(_) => assert!(TimeZoneConverter::from_local_to("Asia/Tokyo").is_err()),
+        }
+        assert!(TimeZoneConverter::from_local_to("Nowhere/City").is_err());
+    }
+
+    #[test]
+    fn fixed_offset_zones() {
+        let timezone = TimeZoneConverter::new("+05:30", "-07:00").unwrap();
+        let dt = Utc.with_ymd_and_hms(2024, 7, 1, 12, 0, 0).unwrap();
+        assert_eq!(timezone.convert_zoned(dt).unwrap().to_string(), "2024-07-01 05:00:00 -07:00");
+        assert!(matches!(timezone.convert(dt), Err(Errors::ConversionError(_))));
+        assert_eq!(timezone.time_difference_at(dt), Duration::minutes(750));
+        assert_eq!(timezone.get_timezone_info().unwrap().name(), "+05:30");
+        assert!(timezone.transitions_between(dt, dt + Duration::days(365), Zone::Source).is_empty());
+
+        let naive = NaiveDate::from_ymd_opt(2024, 3, 10).unwrap().and_hms_opt(2, 30, 0).unwrap();
+        let from_new_york = TimeZoneConverter::from_tz(Tz::America__New_York, FixedOffset::east_opt(0).unwrap());
+        assert_eq!(from_new_york.convert_local(naive, AmbiguityPolicy::Reject).unwrap_err().to_string(), "conversion error: 2024-03-10 02:30:00 does not exist in America/New_York");
+        assert!(from_new_york.target_is_utc());
+    }
+
+    #[test]
+    fn posix_rule_zones() {
+        let timezone = TimeZoneConverter::new("EST5EDT,M3.2.0,M11.1.0", "CET-1CEST,M3.5.0,M10.5.0/3").unwrap();
+        let dt = Utc.with_ymd_and_hms(2024, 3, 20, 12, 0, 0).unwrap();
+        assert_eq!(timezone.convert_zoned(dt).unwrap().to_string(), "2024-03-20 13:00:00 CET");
+        assert_eq!(timezone.time_difference_at(dt), Duration::hours(-5));
+
+        let year = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let changes: Vec<_> = timezone.transitions_between(year, year + Duration::days(366), Zone::Source).iter().map(|t| t.instant).collect();
+        assert_eq!(changes, [Utc.with_ymd_and_hms(2024, 3, 10, 7, 0, 0).unwrap(), Utc.with_ymd_and_hms(2024, 11, 3, 6, 0, 0).unwrap()]);
+        assert!(TimeZoneConverter::new("UTC0", "Europe/Paris").unwrap().source_is_utc());
+    }
+
+    #[test]
+    fn legacy_names_resolve_to_canonical_zones() {
+        let timezone = TimeZoneConverter::new("US/Eastern", "Asia/Calcutta").unwrap();
+        assert_eq!(timezone.source_tz, Tz::America__New_York);
+        assert_eq!(timezone.target_tz, Tz::Asia__Kolkata);
+        assert_eq!(TimeZoneConverter::single("GMT+5").unwrap().get_timezone_info().unwrap().offset(), Duration::hours(5));
+
+        assert_eq!(TimeZoneConverter::new("PST", "JST").unwrap().target_tz, Tz::Asia__Tokyo);
+        let err = TimeZoneConverter::new("PST", "IST").unwrap_err();
+        assert_eq!(err.to_string(), "ambiguous timezone abbreviation (could be Asia/Kolkata, Europe/Dublin, Asia/Jerusalem)");
+    }
+
+    #[test]
+    fn fuzzy_suggestions() {
+        assert_eq!(edit_distance("new_yrok", "new_york"), 1);
+        assert_eq!(edit_distance("", "utc"), 3);
+        assert_eq!(edit_distance("kitten", "sitting"), 3);
+
+        match TimeZoneConverter::new("Amerca/New_Yrok", "UTC").unwrap_err() {
+            Errors::InvalidTimeZone { suggestions, .. } => assert_eq!(suggestions, ["America/New_York"]),
+            other => panic!("unexpected error {:?}", other),
+        }
+        assert_eq!(suggest_timezones("Pari", 5), ["Europe/Paris"]);
+        assert_eq!(suggest_timezones("utc", 2), ["Etc/UTC", "UTC"]);
+        assert!(suggest_timezones("Atlantis/Capital", 5).is_empty());
+        assert!(suggest_timezones("   ", 5).is_empty());
+    }
+
+    #[test]
+    fn lists_timezones() {
+        let at = Utc.with_ymd_and_hms(2024, 1, 15, 12, 0, 0).unwrap();
+        let zones: Vec<TimeZoneSummary> = list_timezones_at(at).collect();
+        assert!(zones.windows(2).all(|pair| pair[0].name() < pair[1].name()));
+        assert!(zones.iter().all(|zone| zone.name() != "US/Eastern" && zone.name() != "Asia/Calcutta"));
+        for link in ["Europe/Belfast", "Asia/Kashgar", "America/Montreal", "Antarctica/South_Pole", "Atlantic/Jan_Mayen"] {
+            assert!(zones.iter().all(|zone| zone.name() != link), "{} should not be listed", link);
+        }
+        assert!(zones.iter().any(|zone| zone.name() == "Europe/Amsterdam"));
+
+        let kolkata = zones.iter().find(|zone| zone.tz() == Tz::Asia__Kolkata).unwrap();
+        assert_eq!((kolkata.offset(), kolkata.abbreviation(), kolkata.is_dst()), (Duration::minutes(330), "IST", false));
+        let dubai = zones.iter().find(|zone| zone.name() == "Asia/Dubai").unwrap();
+        assert_eq!(dubai.abbreviation(), "+04");
+    }
+
+    #[test]
+    fn groups_timezones_by_offset() {
+        let winter = Utc.with_ymd_and_hms(2024, 1, 15, 12, 0, 0).unwrap();
+        let summer = Utc.with_ymd_and_hms(2024, 7, 15, 12, 0, 0).unwrap();
+        assert!(timezones_by_offset_at(Duration::hours(1), winter).contains(&Tz::Europe__Berlin));
+        assert!(!timezones_by_offset_at(Duration::hours(1), summer).contains(&Tz::Europe__Berlin));
+        assert!(timezones_by_offset_at(Duration::minutes(45), winter).is_empty());
+
+        let groups = group_by_offset_at(summer);
+        assert_eq!(groups.values().map(Vec::len).sum::<usize>(), list_timezones_at(summer).count());
+        assert_eq!(groups[&Duration::minutes(345)], [Tz::Asia__Kathmandu]);
+        assert_eq!(groups.keys().last(), Some(&Duration::hours(14)));
+    }
+
+    #[test]
+    fn equivalent_timezones() {
+        assert!(zones_equivalent(Tz::America__Detroit, Tz::America__New_York, (1976, 2030)));
+        assert!(!zones_equivalent(Tz::America__Detroit, Tz::America__New_York, (1973, 2030)));
+        // Same offset in January, but Lagos has no DST
+        assert!(!zones_equivalent(Tz::Europe__Berlin, Tz::Africa__Lagos, (2024, 2024)));
+        assert!(!zones_equivalent(Tz::Europe__Berlin, Tz::Europe__Paris, (2030, 2000)));
+
+        // Colombo moved off India's offset in 1996
+        assert!(equivalent_zones(Tz::Asia__Kolkata, (1990, 2030)).is_empty());
+        assert!(equivalent_zones(Tz::America__Phoenix, (2000, 2030)).contains(&Tz::America__Hermosillo));
+    }
+
+    #[test]
+    fn verify_round_trip() {
+        let converter = TimeZoneConverter::new("America/New_York", "Europe/London").unwrap();
+        let at = |m, d, h, min| NaiveDate::from_ymd_opt(2024, m, d).unwrap().and_hms_opt(h, min, 0).unwrap();
+        assert_eq!(converter.verify_round_trip(at(7, 1, 9, 0)), Ok(()));
+        assert_eq!(
+            converter.verify_round_trip(at(3, 10, 2, 30)),
+            Err(RoundTripError::Nonexistent { zone: Zone::Source, local: at(3, 10, 2, 30) })
+        );
+        let error = converter.verify_round_trip(at(11, 3, 1, 30)).unwrap_err();
+        assert_eq!(error.to_string(), "2024-11-03 01:30:00 is ambiguous in the source timezone (2024-11-03 05:30:00 UTC or 2024-11-03 06:30:00 UTC)");
+        // 21:30 in New York is 01:30 in London as its clocks fall back
+        assert!(matches!(converter.verify_round_trip(at(10, 26, 21, 30)), Err(RoundTripError::Ambiguous { zone: Zone::Target, .. })));
+    }
+
+    #[test]
+    fn calendar_boundaries_in_target() {
+        let converter = TimeZoneConverter::new("UTC", "Europe/London").unwrap();
+        let date = |m, d| NaiveDate::from_ymd_opt(2024, m, d).unwrap();
+        let length = |d: NaiveDate| converter.end_of_day_in_target(d).unwrap() - converter.start_of_day_in_target(d).unwrap();
+        assert_eq!(length(date(3, 31)), Duration::hours(23));
+        assert_eq!(length(date(10, 27)), Duration::hours(25));
+        assert_eq!(length(date(7, 1)), Duration::hours(24));
+
+        let week = converter.start_of_week_in_target(date(3, 31), Weekday::Sun).unwrap();
+        assert_eq!(week.to_string(), "2024-03-31 00:00:00 GMT");
+        let week = converter.start_of_week_in_target(date(4, 2), Weekday::Sun).unwrap();
+        assert_eq!(week.to_string(), "2024-03-31 00:00:00 GMT");
+        let month = converter.start_of_month_in_target(date(4, 30)).unwrap();
+        assert_eq!(month.with_timezone(&Utc).to_string(), "2024-03-31 23:00:00 UTC");
+        assert!(converter.end_of_day_in_target(NaiveDate::MAX).is_err());
+        assert!(matches!(converter.start_of_week_in_target(NaiveDate::MIN, Weekday::Mon), Err(Errors::ConversionError(_))));
+
+        let converter = TimeZoneConverter::new("UTC", "+05:30").unwrap();
+        assert!(matches!(converter.start_of_day_in_target(date(7, 1)), Err(Errors::ConversionError(_))));
+        assert!(matches!(converter.start_of_month_in_target(date(7, 1)), Err(Errors::ConversionError(_))));
+    }
+
+    #[test]
+    fn split_by_local_days() {
+        let converter = TimeZoneConverter::new("America/New_York", "UTC").unwrap();
+        let start = Utc.with_ymd_and_hms(2024, 11, 2, 12, 0, 0).unwrap();
+        let end = Utc.with_ymd_and_hms(2024, 11, 4, 12, 0, 0).unwrap();
+        let pieces = converter.split_by_local_days(start, end, Zone::Source);
+        let lengths: Vec<_> = pieces.iter().map(|(from, to)| (*to - *from).num_hours()).collect();
+        assert_eq!(lengths, [16, 25, 7]);
+        assert_eq!(pieces[1].0.to_string(), "2024-11-03 00:00:00 EDT");
+        assert_eq!(pieces[2].1.with_timezone(&Utc), end);
+
+        assert_eq!(converter.split_by_local_days(start, start + Duration::hours(1), Zone::Target).len(), 1);
+        assert!(converter.split_by_local_days(end, start, Zone::Target).is_empty());
+
+        let converter = TimeZoneConverter::new("America/New_York", "+05:30").unwrap();
+        assert_eq!(converter.split_by_local_days(start, end, Zone::Source).len(), 3);
+        assert!(converter.split_by_local_days(start, end, Zone::Target).is_empty());
+    }
+
+    #[test]
+    fn wall_and_absolute_arithmetic() {
+        let converter = TimeZoneConverter::new("UTC", "Europe/London").unwrap();
+        let before = chrono_tz::Europe::London.with_ymd_and_hms(2024, 10, 26, 18, 0, 0).unwrap();
+        assert_eq!(converter.add_wall(before, 1, 0).unwrap().to_string(), "2024-10-27 18:00:00 GMT");
+        assert_eq!(converter.add_absolute(before, Duration::days(1)).unwrap().to_string(), "2024-10-27 17:00:00 GMT");
+        assert_eq!(converter.add_wall(before, -1, 6).unwrap().to_string(), "2024-10-26 00:00:00 BST");
+
+        // 01:30 occurs twice on Oct 27, and resolves to the first occurrence
+        let night = chrono_tz::Europe::London.with_ymd_and_hms(2024, 10, 26, 1, 30, 0).unwrap();
+        let repeated = converter.add_wall(night, 1, 0).unwrap();
+        assert_eq!(repeated.to_rfc3339(), "2024-10-27T01:30:00+01:00");
+
+        assert!(converter.add_wall(before, i64::MAX, 0).is_err());
+        assert!(converter.add_absolute(before, Duration::max_value()).is_err());
+
+        let converter = TimeZoneConverter::new("UTC", "+05:30").unwrap();
+        assert!(matches!(converter.add_wall(before, 1, 0), Err(Errors::ConversionError(_))));
+        assert!(matches!(converter.add_absolute(before, Duration::days(1)), Err(Errors::ConversionError(_))));
     }
 }
@@ -0,0 +1,177 @@
+//! Locale-aware formatting, using the locale data bundled with `chrono`
+
+use chrono::{DateTime, Locale, TimeZone as ChronoTimeZone};
+
+use crate::{validate_format, Errors, TimeZoneConverter};
+
+impl TimeZoneConverter {
+    /// Formats an instant in the target timezone with localized month and day names
+    /// 
+    /// `%c`, `%x`, `%X` and `%r` follow the locale's own conventions, including whether it
+    /// uses a 12- or 24-hour clock.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `dt` - The instant to format, in any timezone
+    /// * `fmt` - A `chrono` strftime-style format (e.g. "%A %d %B %Y")
+    /// * `locale` - A locale identifier such as "de-DE", "en_US" or "fr"
+    /// 
+    /// # Returns
+    /// 
+    /// * `Result<String, Errors>` - The formatted target-local datetime, or `Errors::ParseError` for an unknown locale or invalid format
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use chrono::{TimeZone, Utc};
+    /// use timezone_converter::TimeZoneConverter;
+    /// 
+    /// let converter = TimeZoneConverter::new("UTC", "Europe/Berlin").unwrap();
+    /// let dt = Utc.with_ymd_and_hms(2024, 12, 24, 17, 0, 0).unwrap();
+    /// assert_eq!(converter.format_localized(dt, "%A, %d. %B %Y %H:%M", "de-DE").unwrap(), "Dienstag, 24. Dezember 2024 18:00");
+    /// ```
+    pub fn format_localized<T: ChronoTimeZone>(&self, dt: DateTime<T>, fmt: &str, locale: &str) -> Result<String, Errors> {
+        let locale = parse_locale(locale)?;
+        validate_format(fmt)?;
+        Ok(dt.with_timezone(&self.target_tz).format_localized(fmt, locale).to_string())
+    }
+
+    /// Formats an instant in the target timezone using the locale's preferred date and time representation
+    /// 
+    /// This is `%c` in the locale, so the order of fields and the 12/24-hour convention
+    /// come from the locale itself.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `dt` - The instant to format, in any timezone
+    /// * `locale` - A locale identifier such as "de-DE", "en_US" or "fr"
+    /// 
+    /// # Returns
+    /// 
+    /// * `Result<String, Errors>` - The formatted target-local datetime, or `Errors::ParseError` for an unknown locale
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use chrono::{TimeZone, Utc};
+    /// use timezone_converter::TimeZoneConverter;
+    /// 
+    /// let converter = TimeZoneConverter::new("UTC", "America/New_York").unwrap();
+    /// let dt = Utc.with_ymd_and_hms(2024, 7, 4, 19, 30, 0).unwrap();
+    /// assert_eq!(converter.format_for_locale(dt, "en-US").unwrap(), "Thu 04 Jul 2024 03:30:00 PM EDT");
+    /// ```
+    pub fn format_for_locale<T: ChronoTimeZone>(&self, dt: DateTime<T>, locale: &str) -> Result<String, Errors> {
+        self.format_localized(dt, "%c", locale)
+    }
+}
+
+/// The main country of languages whose code differs from that country's, sorted by language
+const DEFAULT_REGIONS: &[(&str, &str)] = &[
+    ("af", "ZA"),
+    ("am", "ET"),
+    ("ar", "SA"),
+    ("be", "BY"),
+    ("bn", "BD"),
+    ("ca", "ES"),
+    ("cs", "CZ"),
+    ("cy", "GB"),
+    ("da", "DK"),
+    ("el", "GR"),
+    ("en", "US"),
+    ("et", "EE"),
+    ("eu", "ES"),
+    ("fa", "IR"),
+    ("ga", "IE"),
+    ("gl", "ES"),
+    ("gu", "IN"),
+    ("he", "IL"),
+    ("hi", "IN"),
+    ("hy", "AM"),
+    ("ja", "JP"),
+    ("ka", "GE"),
+    ("kk", "KZ"),
+    ("km", "KH"),
+    ("kn", "IN"),
+    ("ko", "KR"),
+    ("lo", "LA"),
+    ("ml", "IN"),
+    ("mr", "IN"),
+    ("ms", "MY"),
+    ("my", "MM"),
+    ("nb", "NO"),
+    ("ne", "NP"),
+    ("nn", "NO"),
+    ("pa", "IN"),
+    ("si", "LK"),
+    ("sl", "SI"),
+    ("sq", "AL"),
+    ("sr", "RS"),
+    ("sv", "SE"),
+    ("sw", "KE"),
+    ("ta", "IN"),
+    ("te", "IN"),
+    ("uk", "UA"),
+    ("ur", "PK"),
+    ("vi", "VN"),
+    ("zh", "CN"),
+    ("zu", "ZA"),
+];
+
+/// Parses a BCP 47 or POSIX style locale identifier
+/// 
+/// A bare language such as "fr" or "en" is read as its main country variant ("fr_FR",
+/// "en_US").
+fn parse_locale(id: &str) -> Result<Locale, Errors> {
+    let posix = id.trim().replace('-', "_");
+    Locale::try_from(posix.as_str())
+        .or_else(|_| {
+            let language = posix.to_lowercase();
+            let region = match DEFAULT_REGIONS.binary_search_by(|(code, _)| code.cmp(&language.as_str())) {
+                Ok(index) => DEFAULT_REGIONS[index].1.to_string(),
+                Err(_) => language.to_uppercase(),
+            };
+            Locale::try_from(format!("{}_{}", language, region).as_str())
+        })
+        .map_err(|_| Errors::ParseError(format!("unknown locale '{}'", id)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{TimeZone, Utc};
+
+    #[test]
+    fn localized_names_and_clock_conventions() {
+        let timezone = TimeZoneConverter::new("UTC", "Europe/Paris").unwrap();
+        let dt = Utc.with_ymd_and_hms(2024, 2, 5, 13, 15, 0).unwrap();
+        assert_eq!(timezone.format_localized(dt, "%A %d %B", "fr").unwrap(), "lundi 05 février");
+        assert_eq!(timezone.format_localized(dt, "%X", "de_DE").unwrap(), "14:15:00");
+        assert_eq!(timezone.format_localized(dt, "%X", "en-US").unwrap(), "02:15:00 PM");
+    }
+
+    #[test]
+    fn unknown_locale() {
+        let timezone = TimeZoneConverter::new("UTC", "UTC").unwrap();
+        let dt = Utc.with_ymd_and_hms(2024, 2, 5, 13, 15, 0).unwrap();
+        assert!(matches!(timezone.format_for_locale(dt, "xx-YY"), Err(Errors::ParseError(_))));
+        assert!(matches!(timezone.format_localized(dt, "%Q", "en-US"), Err(Errors::ParseError(_))));
+    }
+
+    #[test]
+    fn bare_languages_use_their_main_country() {
+        assert!(DEFAULT_REGIONS.windows(2).all(|pair| pair[0].0 < pair[1].0));
+        for (language, region) in DEFAULT_REGIONS {
+            assert!(Locale::try_from(format!("{}_{}", language, region).as_str()).is_ok(), "{}_{}", language, region);
+        }
+        assert_eq!(parse_locale("en").unwrap(), Locale::en_US);
+        assert_eq!(parse_locale("ja").unwrap(), Locale::ja_JP);
+        assert_eq!(parse_locale("zh").unwrap(), Locale::zh_CN);
+        assert_eq!(parse_locale("SV").unwrap(), Locale::sv_SE);
+        assert_eq!(parse_locale("de").unwrap(), Locale::de_DE);
+
+        let timezone = TimeZoneConverter::new("UTC", "Asia/Tokyo").unwrap();
+        let dt = Utc.with_ymd_and_hms(2024, 2, 5, 13, 15, 0).unwrap();
+        assert_eq!(timezone.format_localized(dt, "%B", "en").unwrap(), "February");
+        assert_eq!(timezone.format_localized(dt, "%A", "da").unwrap(), "mandag");
+    }
+}
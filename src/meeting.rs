@@ -0,0 +1,248 @@
+//! Finding meeting times that fall within every participant's working hours
+
+use std::fmt;
+use std::ops::RangeInclusive;
+
+use chrono::{DateTime, Datelike, Duration, NaiveDate, NaiveTime, TimeZone, Utc, Weekday};
+use chrono_tz::Tz;
+
+use crate::{parse_tz, resolve_local_forward, Errors};
+
+/// A window of time as its UTC start (inclusive) and end (exclusive)
+pub type UtcWindow = (DateTime<Utc>, DateTime<Utc>);
+
+/// A meeting participant: their timezone and local working hours
+#[derive(Debug, Clone, PartialEq)]
+pub struct Participant {
+    /// The participant's name (e.g., "Amara")
+    pub name: String,
+    /// The participant's timezone
+    pub zone: Tz,
+    /// The local time their working day starts
+    pub work_start: NaiveTime,
+    /// The local time their working day ends; before `work_start` for overnight shifts
+    pub work_end: NaiveTime,
+    /// The weekdays their working days start on
+    pub working_days: Vec<Weekday>,
+}
+
+/// Finds windows in which every participant is within their working hours
+#[derive(Debug, Clone, Default)]
+pub struct MeetingPlanner {
+    /// The participants, in the order they were added
+    participants: Vec<Participant>,
+}
+
+impl MeetingPlanner {
+    /// Creates a MeetingPlanner with no participants
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a participant
+    /// 
+    /// # Arguments
+    /// 
+    /// * `name` - The participant's name
+    /// * `zone` - The participant's timezone identifier (e.g., "Asia/Tokyo")
+    /// * `work_start` - The local time their working day starts
+    /// * `work_end` - The local time their working day ends; an earlier time than `work_start` means an overnight shift
+    /// * `working_days` - The weekdays their working days start on
+    /// 
+    /// # Returns
+    /// 
+    /// * `Result<&mut MeetingPlanner, Errors>` - The planner, for chaining, or an error if `zone` is invalid or the hours are empty
+    pub fn add(&mut self, name: &str, zone: &str, work_start: NaiveTime, work_end: NaiveTime, working_days: &[Weekday]) -> Result<&mut Self, Errors> {
+        let zone = parse_tz(zone)?;
+        if work_start == work_end {
+            return Err(Errors::ConversionError(format!(
+                "working hours of {} start and end at {}",
+                name, work_start
+            )));
+        }
+
+        self.participants.push(Participant {
+            name: name.to_string(),
+            zone,
+            work_start,
+            work_end,
+            working_days: working_days.to_vec(),
+        });
+        Ok(self)
+    }
+
+    /// Gets the participants, in the order they were added
+    pub fn participants(&self) -> &[Participant] {
+        &self.participants
+    }
+
+    /// Finds the windows in which every participant is working and a meeting of `duration` fits
+    /// 
+    /// Each participant's hours are read as local wall-clock times on each of their working
+    /// days, so the windows follow every zone's DST changes. Local times skipped by DST are
+    /// shifted forward. With no participants there is no one to meet and no windows are returned.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `duration` - The length of the meeting
+    /// * `date_range` - The UTC dates to search, inclusive
+    /// 
+    /// # Returns
+    /// 
+    /// * `Result<Vec<UtcWindow>, Errors>` - The maximal shared windows as UTC start and end, in order
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use chrono::{Duration, NaiveDate, NaiveTime, TimeZone, Utc, Weekday};
+    /// use timezone_converter::MeetingPlanner;
+    /// 
+    /// let weekdays = [Weekday::Mon, Weekday::Tue, Weekday::Wed, Weekday::Thu, Weekday::Fri];
+    /// let nine = NaiveTime::from_hms_opt(9, 0, 0).unwrap();
+    /// let five = NaiveTime::from_hms_opt(17, 0, 0).unwrap();
+    /// let mut planner = MeetingPlanner::new();
+    /// planner.add("Ana", "America/New_York", nine, five, &weekdays).unwrap()
+    ///     .add("Ben", "Europe/London", nine, five, &weekdays).unwrap();
+    /// 
+    /// let monday = NaiveDate::from_ymd_opt(2024, 7, 1).unwrap();
+    /// let slots = planner.find_slots(Duration::hours(1), monday..=monday).unwrap();
+    /// assert_eq!(slots, [(
+    ///     Utc.with_ymd_and_hms(2024, 7, 1, 13, 0, 0).unwrap(),
+    ///     Utc.with_ymd_and_hms(2024, 7, 1, 16, 0, 0).unwrap(),
+    /// )]);
+    /// ```
+    pub fn find_slots(&self, duration: Duration, date_range: RangeInclusive<NaiveDate>) -> Result<Vec<UtcWindow>, Errors> {
+        let (first, last) = date_range.into_inner();
+        if self.participants.is_empty() || last < first {
+            return Ok(Vec::new());
+        }
+
+        let range_start = first.and_time(NaiveTime::MIN).and_utc();
+        let range_end = last
+            .succ_opt()
+            .ok_or_else(|| Errors::ConversionError(format!("no day follows {}", last)))?
+            .and_time(NaiveTime::MIN)
+            .and_utc();
+
+        let mut shared = vec![(range_start, range_end)];
+        for participant in &self.participants {
+            let windows = working_windows(participant, first, last)?;
+            shared = intersect(&shared, &windows);
+        }
+
+        shared.retain(|(start, end)| *end - *start >= duration);
+        Ok(shared)
+    }
+}
+
+/// Lists a participant's working windows in UTC that may overlap the UTC dates `first..=last`
+fn working_windows(participant: &Participant, first: NaiveDate, last: NaiveDate) -> Result<Vec<UtcWindow>, Errors> {
+    // Local dates can be a day either side of the UTC dates, and overnight shifts reach into the next day
+    let local_first = first.pred_opt().unwrap_or(first);
+    let local_last = last.succ_opt().unwrap_or(last);
+
+    let mut windows = Vec::new();
+    for date in local_first.iter_days().take_while(|date| *date <= local_last) {
+        if !participant.working_days.contains(&date.weekday()) {
+            continue;
+        }
+
+        let (start, end) = local_window(&participant.zone, date, participant.work_start, participant.work_end)?;
+        if start < end {
+            windows.push((start, end));
+        }
+    }
+    Ok(windows)
+}
+
+/// Resolves local hours on a date to a UTC window, ending on the next day if `end` is not after `start`
+pub(crate) fn local_window<Z: TimeZone + fmt::Display>(zone: &Z, date: NaiveDate, start: NaiveTime, end: NaiveTime) -> Result<UtcWindow, Errors> {
+    let end_date = if end > start {
+        date
+    } else {
+        date.succ_opt()
+            .ok_or_else(|| Errors::ConversionError(format!("no day follows {}", date)))?
+    };
+    let start = resolve_local_forward(zone, date.and_time(start))?;
+    let end = resolve_local_forward(zone, end_date.and_time(end))?;
+    Ok((start.with_timezone(&Utc), end.with_timezone(&Utc)))
+}
+
+/// Intersects two sorted lists of non-overlapping windows
+pub(crate) fn intersect(a: &[UtcWindow], b: &[UtcWindow]) -> Vec<UtcWindow> {
+    let mut shared = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    while i < a.len() && j < b.len() {
+        let start = a[i].0.max(b[j].0);
+        let end = a[i].1.min(b[j].1);
+        if start < end {
+            shared.push((start, end));
+        }
+        if a[i].1 < b[j].1 {
+            i += 1;
+        } else {
+            j += 1;
+        }
+    }
+    shared
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    const WEEKDAYS: [Weekday; 5] = [Weekday::Mon, Weekday::Tue, Weekday::Wed, Weekday::Thu, Weekday::Fri];
+
+    fn time(hour: u32) -> NaiveTime {
+        NaiveTime::from_hms_opt(hour, 0, 0).unwrap()
+    }
+
+    #[test]
+    fn find_slots_across_three_zones() {
+        let mut planner = MeetingPlanner::new();
+        planner.add("Ana", "America/Los_Angeles", time(8), time(17), &WEEKDAYS).unwrap()
+            .add("Ben", "Europe/Berlin", time(9), time(18), &WEEKDAYS).unwrap()
+            .add("Chi", "Asia/Tokyo", time(9), time(18), &WEEKDAYS).unwrap();
+        let week = NaiveDate::from_ymd_opt(2024, 7, 1).unwrap()..=NaiveDate::from_ymd_opt(2024, 7, 5).unwrap();
+        assert!(planner.find_slots(Duration::minutes(30), week).unwrap().is_empty());
+
+        // An overnight shift in Tokyo bridges Los Angeles and Berlin mornings
+        let mut planner = MeetingPlanner::new();
+        planner.add("Ana", "America/Los_Angeles", time(8), time(17), &WEEKDAYS).unwrap()
+            .add("Chi", "Asia/Tokyo", time(22), time(6), &WEEKDAYS).unwrap();
+        let tuesday = NaiveDate::from_ymd_opt(2024, 7, 2).unwrap();
+        let slots = planner.find_slots(Duration::hours(1), tuesday..=tuesday).unwrap();
+        assert_eq!(slots, [(
+            Utc.with_ymd_and_hms(2024, 7, 2, 15, 0, 0).unwrap(),
+            Utc.with_ymd_and_hms(2024, 7, 2, 21, 0, 0).unwrap(),
+        )]);
+    }
+
+    #[test]
+    fn find_slots_follows_dst_and_weekends() {
+        let mut planner = MeetingPlanner::new();
+        planner.add("Ana", "America/New_York", time(9), time(17), &WEEKDAYS).unwrap()
+            .add("Ben", "Europe/London", time(9), time(17), &WEEKDAYS).unwrap();
+
+        // New York has sprung forward by Mar 11 2024, London has not
+        let days = NaiveDate::from_ymd_opt(2024, 3, 8).unwrap()..=NaiveDate::from_ymd_opt(2024, 3, 11).unwrap();
+        let slots = planner.find_slots(Duration::hours(2), days).unwrap();
+        assert_eq!(slots, [
+            (Utc.with_ymd_and_hms(2024, 3, 8, 14, 0, 0).unwrap(), Utc.with_ymd_and_hms(2024, 3, 8, 17, 0, 0).unwrap()),
+            (Utc.with_ymd_and_hms(2024, 3, 11, 13, 0, 0).unwrap(), Utc.with_ymd_and_hms(2024, 3, 11, 17, 0, 0).unwrap()),
+        ]);
+        let days = NaiveDate::from_ymd_opt(2024, 3, 8).unwrap()..=NaiveDate::from_ymd_opt(2024, 3, 11).unwrap();
+        assert_eq!(planner.find_slots(Duration::hours(4), days).unwrap().len(), 1);
+    }
+
+    #[test]
+    fn invalid_participants() {
+        let mut planner = MeetingPlanner::new();
+        assert!(planner.add("Ana", "Mars/Olympus", time(9), time(17), &WEEKDAYS).is_err());
+        assert!(planner.add("Ana", "UTC", time(9), time(9), &WEEKDAYS).is_err());
+        assert!(planner.participants().is_empty());
+        let today = NaiveDate::from_ymd_opt(2024, 7, 1).unwrap();
+        assert!(planner.find_slots(Duration::hours(1), today..=today).unwrap().is_empty());
+    }
+}
@@ -0,0 +1,123 @@
+//! Fan-out conversion from one source timezone to many targets
+
+use chrono::{DateTime, TimeZone as ChronoTimeZone};
+use chrono_tz::Tz;
+
+use crate::{parse_tz, Errors};
+
+/// A struct that converts times from one source timezone to several target timezones at once
+#[derive(Debug, Clone)]
+pub struct MultiZoneConverter {
+    /// The source timezone to convert from
+    source_tz: Tz,
+    /// The target timezones to convert to, in the order given
+    targets: Vec<Tz>,
+}
+
+impl MultiZoneConverter {
+    /// Creates a new MultiZoneConverter instance
+    /// 
+    /// # Arguments
+    /// 
+    /// * `source` - The source timezone identifier (e.g., "America/New_York")
+    /// * `targets` - The target timezone identifiers (e.g., ["Europe/London", "Asia/Tokyo"])
+    /// 
+    /// # Returns
+    /// 
+    /// * `Result<MultiZoneConverter, Errors>` - A new MultiZoneConverter instance, or an error for the first invalid name
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use timezone_converter::MultiZoneConverter;
+    /// 
+    /// let converter = MultiZoneConverter::new("America/New_York", &["Europe/London", "Asia/Tokyo"]).unwrap();
+    /// assert_eq!(converter.targets().len(), 2);
+    /// ```
+    pub fn new(source: &str, targets: &[&str]) -> Result<Self, Errors> {
+        let source_tz = parse_tz(source)?;
+        let targets = targets
+            .iter()
+            .map(|name| parse_tz(name))
+            .collect::<Result<Vec<Tz>, Errors>>()?;
+
+        Ok(Self {
+            source_tz,
+            targets,
+        })
+    }
+
+    /// Gets the source timezone
+    pub fn source(&self) -> Tz {
+        self.source_tz
+    }
+
+    /// Gets the target timezones, in the order given at construction
+    pub fn targets(&self) -> &[Tz] {
+        &self.targets
+    }
+
+    /// Converts a datetime to every target timezone
+    /// 
+    /// # Arguments
+    /// 
+    /// * `datetime` - The datetime to convert
+    /// 
+    /// # Returns
+    /// 
+    /// * `Vec<(Tz, DateTime<Tz>)>` - Each target timezone with the converted datetime, in target order
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use chrono::TimeZone;
+    /// use chrono_tz::America::New_York;
+    /// use timezone_converter::MultiZoneConverter;
+    /// 
+    /// let converter = MultiZoneConverter::new("America/New_York", &["Europe/London", "Asia/Tokyo"]).unwrap();
+    /// let event = New_York.with_ymd_and_hms(2024, 7, 1, 9, 0, 0).unwrap();
+    /// let times = converter.convert_all(event);
+    /// assert_eq!(times[0].1.to_string(), "2024-07-01 14:00:00 BST");
+    /// assert_eq!(times[1].1.to_string(), "2024-07-01 22:00:00 JST");
+    /// ```
+    pub fn convert_all<T: ChronoTimeZone>(&self, datetime: DateTime<T>) -> Vec<(Tz, DateTime<Tz>)> {
+        self.targets
+            .iter()
+            .map(|tz| (*tz, datetime.with_timezone(tz)))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{TimeZone, Utc};
+
+    #[test]
+    fn convert_all() {
+        let converter = MultiZoneConverter::new(
+            "America/New_York",
+            &["Europe/London", "Asia/Kolkata", "America/New_York"],
+        ).unwrap();
+        assert_eq!(converter.source(), chrono_tz::America::New_York);
+
+        let event = Utc.with_ymd_and_hms(2024, 1, 15, 15, 0, 0).unwrap();
+        let rendered: Vec<(String, String)> = converter
+            .convert_all(event)
+            .iter()
+            .map(|(tz, dt)| (tz.name().to_string(), dt.to_string()))
+            .collect();
+        assert_eq!(rendered, vec![
+            ("Europe/London".to_string(), "2024-01-15 15:00:00 GMT".to_string()),
+            ("Asia/Kolkata".to_string(), "2024-01-15 20:30:00 IST".to_string()),
+            ("America/New_York".to_string(), "2024-01-15 10:00:00 EST".to_string()),
+        ]);
+    }
+
+    #[test]
+    fn invalid_target() {
+        assert!(MultiZoneConverter::new("UTC", &["Europe/London", "Europe/Lundon"]).is_err());
+        assert!(MultiZoneConverter::new("Utc/Nowhere", &[]).is_err());
+        assert!(MultiZoneConverter::new("UTC", &[]).unwrap().convert_all(Utc::now()).is_empty());
+    }
+}
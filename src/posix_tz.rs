@@ -0,0 +1,483 @@
+//! Timezones given as POSIX TZ rule strings such as `EST5EDT,M3.2.0,M11.1.0`
+
+use std::fmt;
+use std::str::FromStr;
+
+use chrono::{Datelike, Duration, FixedOffset, LocalResult, NaiveDate, NaiveDateTime, NaiveTime, Offset, TimeZone, Weekday};
+use chrono_tz::OffsetComponents;
+
+use crate::Errors;
+
+/// The longest abbreviation a rule string may give, in bytes
+const MAX_ABBREVIATION: usize = 16;
+
+/// The time of day transitions happen at when a rule gives none
+const DEFAULT_TRANSITION_SECS: i32 = 2 * 3600;
+
+/// The rules used when a rule string names a DST abbreviation but gives no dates: the US
+/// rules since 2007, as glibc and RFC 8536 assume
+const DEFAULT_RULES: (RuleDate, RuleDate) = (
+    RuleDate::MonthWeekDay { month: 3, week: 2, weekday: 0 },
+    RuleDate::MonthWeekDay { month: 11, week: 1, weekday: 0 },
+);
+
+/// A timezone described by a POSIX TZ rule string
+/// 
+/// The string gives the standard abbreviation and offset and, for zones with DST, the DST
+/// abbreviation, its offset and the dates and times it starts and ends, as in
+/// `EST5EDT,M3.2.0,M11.1.0` or `<+1030>-10:30<+11>-11,M10.1.0,M4.1.0`. As in POSIX, offsets
+/// are hours *west* of UTC, so `EST5` is five hours behind it. Dates are given as
+/// `Mm.w.d` (day `d` of week `w` of month `m`, `w` 5 meaning the last), `Jn` (day `n` of
+/// the year, never counting February 29) or `n` (day `n` of the year counting from zero).
+/// Transition times may be negative or exceed 24 hours, as RFC 8536 allows.
+/// 
+/// # Example
+/// 
+/// ```rust
+/// use chrono::{TimeZone, Utc};
+/// use timezone_converter::PosixTz;
+/// 
+/// let eastern: PosixTz = "EST5EDT,M3.2.0,M11.1.0".parse().unwrap();
+/// let summer = Utc.with_ymd_and_hms(2024, 7, 1, 12, 0, 0).unwrap().with_timezone(&eastern);
+/// assert_eq!(summer.to_string(), "2024-07-01 08:00:00 EDT");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PosixTz {
+    /// The standard time abbreviation
+    std_abbreviation: Abbreviation,
+    /// The standard offset, in seconds east of UTC
+    std_offset: i32,
+    /// When and how the zone observes DST, if it does
+    dst: Option<DstRule>,
+}
+
+/// The offset of a [`PosixTz`] at a particular instant
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PosixOffset {
+    /// The zone the offset belongs to
+    zone: PosixTz,
+    /// Whether DST is in effect
+    is_dst: bool,
+}
+
+/// How a [`PosixTz`] observes DST
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct DstRule {
+    /// The DST abbreviation
+    abbreviation: Abbreviation,
+    /// The DST offset, in seconds east of UTC
+    offset: i32,
+    /// The local standard date DST starts on
+    start: RuleDate,
+    /// The local standard time of day DST starts at, in seconds
+    start_time: i32,
+    /// The local DST date DST ends on
+    end: RuleDate,
+    /// The local DST time of day DST ends at, in seconds
+    end_time: i32,
+}
+
+/// A date a POSIX rule falls on each year
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum RuleDate {
+    /// `Jn`: day `n` of the year from 1 to 365, never counting February 29
+    Julian(u16),
+    /// `n`: day `n` of the year from 0 to 365, counting February 29
+    ZeroBased(u16),
+    /// `Mm.w.d`: weekday `d` (0 is Sunday) of week `w` (5 is the last) of month `m`
+    MonthWeekDay { month: u8, week: u8, weekday: u8 },
+}
+
+/// A short string stored inline, so zones stay `Copy`
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) struct InlineStr<const N: usize> {
+    bytes: [u8; N],
+    len: u8,
+}
+
+/// An abbreviation of a rule string
+type Abbreviation = InlineStr<MAX_ABBREVIATION>;
+
+impl PosixTz {
+    /// Gets the standard time abbreviation (e.g., "EST")
+    pub fn std_abbreviation(&self) -> &str {
+        self.std_abbreviation.as_str()
+    }
+
+    /// Gets the standard offset from UTC
+    pub fn std_offset(&self) -> FixedOffset {
+        FixedOffset::east_opt(self.std_offset).expect("offsets are checked when parsed")
+    }
+
+    /// Gets the DST offset from UTC, if the zone observes DST
+    pub fn dst_offset(&self) -> Option<FixedOffset> {
+        self.dst.map(|dst| FixedOffset::east_opt(dst.offset).expect("offsets are checked when parsed"))
+    }
+
+    /// Gets the DST abbreviation (e.g., "EDT"), if the zone observes DST
+    pub fn dst_abbreviation(&self) -> Option<&str> {
+        self.dst.as_ref().map(|dst| dst.abbreviation.as_str())
+    }
+
+    /// Whether DST is in effect at a UTC instant
+    fn is_dst_at(&self, utc: &NaiveDateTime) -> bool {
+        let Some(dst) = &self.dst else {
+            return false;
+        };
+        // Transitions of the neighbouring years are included, because transition times may
+        // move a transition across the new year
+        let mut transitions: Vec<(NaiveDateTime, bool)> = (utc.year() - 1..=utc.year() + 1)
+            .flat_map(|year| {
+                let start = dst.start.local_midnight(year) + Duration::seconds((dst.start_time - self.std_offset).into());
+                let end = dst.end.local_midnight(year) + Duration::seconds((dst.end_time - dst.offset).into());
+                [(start, true), (end, false)]
+            })
+            .collect();
+        // An end and a start at the same instant (DST all year) leave DST in effect
+        transitions.sort();
+        transitions.iter().rev().find(|(at, _)| at <= utc).is_some_and(|(_, is_dst)| *is_dst)
+    }
+
+    fn offset_at(&self, utc: &NaiveDateTime) -> PosixOffset {
+        PosixOffset { zone: *self, is_dst: self.is_dst_at(utc) }
+    }
+}
+
+impl PosixOffset {
+    /// Gets the abbreviation in effect (e.g., "EDT")
+    pub fn abbreviation(&self) -> &str {
+        match (&self.zone.dst, self.is_dst) {
+            (Some(dst), true) => dst.abbreviation.as_str(),
+            _ => self.zone.std_abbreviation(),
+        }
+    }
+
+    /// Whether DST is in effect
+    #[cfg(feature = "system-tz")]
+    pub(crate) fn is_dst(&self) -> bool {
+        self.is_dst
+    }
+
+    /// Gets the offset in effect, in seconds east of UTC
+    fn seconds(&self) -> i32 {
+        match (&self.zone.dst, self.is_dst) {
+            (Some(dst), true) => dst.offset,
+            _ => self.zone.std_offset,
+        }
+    }
+}
+
+impl RuleDate {
+    /// The midnight starting the rule's date in a year
+    fn local_midnight(&self, year: i32) -> NaiveDateTime {
+        let jan_1 = NaiveDate::from_ymd_opt(year, 1, 1).unwrap_or_default();
+        let date = match *self {
+            Self::Julian(day) => {
+                let leap_day = jan_1.leap_year() && day >= 60;
+                jan_1 + Duration::days(i64::from(day) - 1 + i64::from(leap_day))
+            }
+            Self::ZeroBased(day) => jan_1 + Duration::days(day.into()),
+            Self::MonthWeekDay { month, week, weekday } => {
+                let weekday = Weekday::try_from((weekday + 6) % 7).unwrap_or(Weekday::Sun);
+                NaiveDate::from_weekday_of_month_opt(year, month.into(), weekday, week)
+                    .or_else(|| NaiveDate::from_weekday_of_month_opt(year, month.into(), weekday, week - 1))
+                    .unwrap_or(jan_1)
+            }
+        };
+        date.and_time(NaiveTime::MIN)
+    }
+}
+
+impl<const N: usize> InlineStr<N> {
+    /// Stores a string, if it fits
+    pub(crate) fn new(s: &str) -> Option<Self> {
+        if s.len() > N || s.len() > usize::from(u8::MAX) {
+            return None;
+        }
+        let mut bytes = [0; N];
+        bytes[..s.len()].copy_from_slice(s.as_bytes());
+        Some(Self { bytes, len: s.len() as u8 })
+    }
+
+    pub(crate) fn as_str(&self) -> &str {
+        std::str::from_utf8(&self.bytes[..usize::from(self.len)]).unwrap_or_default()
+    }
+}
+
+impl<const N: usize> fmt::Debug for InlineStr<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl fmt::Display for PosixTz {
+    /// Writes the zone back as a rule string, spelling out defaulted DST rules
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_abbreviation(f, &self.std_abbreviation)?;
+        write_hms(f, -self.std_offset)?;
+        let Some(dst) = &self.dst else {
+            return Ok(());
+        };
+        write_abbreviation(f, &dst.abbreviation)?;
+        if dst.offset != self.std_offset + 3600 {
+            write_hms(f, -dst.offset)?;
+        }
+        for (date, time) in [(dst.start, dst.start_time), (dst.end, dst.end_time)] {
+            match date {
+                RuleDate::Julian(day) => write!(f, ",J{}", day)?,
+                RuleDate::ZeroBased(day) => write!(f, ",{}", day)?,
+                RuleDate::MonthWeekDay { month, week, weekday } => write!(f, ",M{}.{}.{}", month, week, weekday)?,
+            }
+            if time != DEFAULT_TRANSITION_SECS {
+                f.write_str("/")?;
+                write_hms(f, time)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for PosixOffset {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.abbreviation())
+    }
+}
+
+impl FromStr for PosixTz {
+    type Err = Errors;
+
+    fn from_str(s: &str) -> Result<Self, Errors> {
+        let invalid = |reason: &str| Errors::ParseError(format!("invalid POSIX TZ string '{}': {}", s, reason));
+        let mut parser = Parser { rest: s.trim() };
+
+        let std_abbreviation = parser.abbreviation().ok_or_else(|| invalid("expected a standard abbreviation"))?;
+        let std_offset = -parser.hms(24).ok_or_else(|| invalid("expected a standard offset"))?;
+        if std_offset.abs() >= 24 * 3600 {
+            return Err(invalid("offsets must be under 24 hours"));
+        }
+        if parser.rest.is_empty() {
+            return Ok(Self { std_abbreviation, std_offset, dst: None });
+        }
+
+        let abbreviation = parser.abbreviation().ok_or_else(|| invalid("expected a DST abbreviation"))?;
+        let offset = if parser.rest.starts_with(',') || parser.rest.is_empty() {
+            std_offset + 3600
+        } else {
+            -parser.hms(24).ok_or_else(|| invalid("invalid DST offset"))?
+        };
+        if offset.abs() >= 24 * 3600 {
+            return Err(invalid("offsets must be under 24 hours"));
+        }
+        let ((start, start_time), (end, end_time)) = if parser.rest.is_empty() {
+            ((DEFAULT_RULES.0, DEFAULT_TRANSITION_SECS), (DEFAULT_RULES.1, DEFAULT_TRANSITION_SECS))
+        } else {
+            let start = parser.rule().ok_or_else(|| invalid("invalid DST start rule"))?;
+            let end = parser.rule().ok_or_else(|| invalid("invalid DST end rule"))?;
+            (start, end)
+        };
+        if !parser.rest.is_empty() {
+            return Err(invalid(&format!("unexpected '{}'", parser.rest)));
+        }
+        let dst = DstRule { abbreviation, offset, start, start_time, end, end_time };
+        Ok(Self { std_abbreviation, std_offset, dst: Some(dst) })
+    }
+}
+
+impl TimeZone for PosixTz {
+    type Offset = PosixOffset;
+
+    fn from_offset(offset: &PosixOffset) -> Self {
+        offset.zone
+    }
+
+    fn offset_from_local_date(&self, local: &NaiveDate) -> LocalResult<PosixOffset> {
+        self.offset_from_local_datetime(&local.and_time(NaiveTime::MIN))
+    }
+
+    fn offset_from_local_datetime(&self, local: &NaiveDateTime) -> LocalResult<PosixOffset> {
+        let std = PosixOffset { zone: *self, is_dst: false };
+        let Some(dst) = self.dst.map(|_| PosixOffset { zone: *self, is_dst: true }) else {
+            return LocalResult::Single(std);
+        };
+        // The offset fits the local time if it is the one in effect at the instant it gives
+        let fits = |offset: &PosixOffset| self.offset_at(&(*local - Duration::seconds(offset.seconds().into()))) == *offset;
+        // The larger offset gives the earlier instant
+        let (earlier, later) = if dst.seconds() > std.seconds() { (dst, std) } else { (std, dst) };
+        match (fits(&earlier), fits(&later)) {
+            (true, true) => LocalResult::Ambiguous(earlier, later),
+            (true, false) => LocalResult::Single(earlier),
+            (false, true) => LocalResult::Single(later),
+            (false, false) => LocalResult::None,
+        }
+    }
+
+    fn offset_from_utc_date(&self, utc: &NaiveDate) -> PosixOffset {
+        self.offset_at(&utc.and_time(NaiveTime::MIN))
+    }
+
+    fn offset_from_utc_datetime(&self, utc: &NaiveDateTime) -> PosixOffset {
+        self.offset_at(utc)
+    }
+}
+
+impl OffsetComponents for PosixOffset {
+    fn base_utc_offset(&self) -> Duration {
+        Duration::seconds(self.zone.std_offset.into())
+    }
+
+    fn dst_offset(&self) -> Duration {
+        Duration::seconds((self.seconds() - self.zone.std_offset).into())
+    }
+}
+
+impl Offset for PosixOffset {
+    fn fix(&self) -> FixedOffset {
+        FixedOffset::east_opt(self.seconds()).expect("offsets are checked when parsed")
+    }
+}
+
+/// Reads a rule string from the front
+struct Parser<'a> {
+    rest: &'a str,
+}
+
+impl Parser<'_> {
+    /// Reads an abbreviation: three or more letters, or anything alphanumeric, `+` or `-` in angle brackets
+    fn abbreviation(&mut self) -> Option<Abbreviation> {
+        let (name, rest) = match self.rest.strip_prefix('<') {
+            Some(quoted) => {
+                let (name, rest) = quoted.split_once('>')?;
+                name.bytes().all(|b| b.is_ascii_alphanumeric() || b == b'+' || b == b'-').then_some((name, rest))?
+            }
+            None => self.rest.split_at(self.rest.bytes().take_while(u8::is_ascii_alphabetic).count()),
+        };
+        if name.len() < 3 {
+            return None;
+        }
+        let abbreviation = Abbreviation::new(name)?;
+        self.rest = rest;
+        Some(abbreviation)
+    }
+
+    /// Reads a signed `h[h][:mm[:ss]]` duration in seconds, with at most `max_hours` hours
+    fn hms(&mut self, max_hours: i32) -> Option<i32> {
+        let (sign, rest) = match self.rest.as_bytes().first()? {
+            b'-' => (-1, &self.rest[1..]),
+            b'+' => (1, &self.rest[1..]),
+            _ => (1, self.rest),
+        };
+        let len = rest.bytes().take_while(|b| b.is_ascii_digit() || *b == b':').count();
+        let (field, rest) = rest.split_at(len);
+        let mut parts = field.split(':');
+        let hours: i32 = parts.next().filter(|h| (1..=3).contains(&h.len()))?.parse().ok()?;
+        let mut seconds = hours * 3600;
+        for scale in [60, 1] {
+            if let Some(part) = parts.next() {
+                let value: i32 = part.parse().ok().filter(|v| part.len() == 2 && *v < 60)?;
+                seconds += value * scale;
+            }
+        }
+        if parts.next().is_some() || hours > max_hours {
+            return None;
+        }
+        self.rest = rest;
+        Some(sign * seconds)
+    }
+
+    /// Reads `,date[/time]`
+    fn rule(&mut self) -> Option<(RuleDate, i32)> {
+        let rest = self.rest.strip_prefix(',')?;
+        let len = rest.find([',', '/']).unwrap_or(rest.len());
+        let (date, rest) = rest.split_at(len);
+        let date = if let Some(day) = date.strip_prefix('J') {
+            RuleDate::Julian(day.parse().ok().filter(|day| (1..=365).contains(day))?)
+        } else if let Some(mwd) = date.strip_prefix('M') {
+            let mut fields = mwd.split('.').map(|field| field.parse::<u8>().ok());
+            let (month, week, weekday) = (fields.next()??, fields.next()??, fields.next()??);
+            if fields.next().is_some() || !(1..=12).contains(&month) || !(1..=5).contains(&week) || weekday > 6 {
+                return None;
+            }
+            RuleDate::MonthWeekDay { month, week, weekday }
+        } else {
+            RuleDate::ZeroBased(date.parse().ok().filter(|day| *day <= 365)?)
+        };
+        self.rest = rest;
+        let time = match self.rest.strip_prefix('/') {
+            Some(time) => {
+                self.rest = time;
+                self.hms(167)?
+            }
+            None => DEFAULT_TRANSITION_SECS,
+        };
+        Some((date, time))
+    }
+}
+
+/// Writes an abbreviation, in angle brackets unless it is all letters
+fn write_abbreviation(f: &mut fmt::Formatter<'_>, abbreviation: &Abbreviation) -> fmt::Result {
+    match abbreviation.as_str() {
+        name if name.bytes().all(|b| b.is_ascii_alphabetic()) => f.write_str(name),
+        name => write!(f, "<{}>", name),
+    }
+}
+
+/// Writes a duration in seconds as `[-]h[:mm[:ss]]`
+fn write_hms(f: &mut fmt::Formatter<'_>, seconds: i32) -> fmt::Result {
+    let sign = if seconds < 0 { "-" } else { "" };
+    let seconds = seconds.abs();
+    write!(f, "{}{}", sign, seconds / 3600)?;
+    match (seconds % 3600 / 60, seconds % 60) {
+        (0, 0) => Ok(()),
+        (minutes, 0) => write!(f, ":{:02}", minutes),
+        (minutes, secs) => write!(f, ":{:02}:{:02}", minutes, secs),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+    use chrono_tz::Tz;
+
+    #[test]
+    fn parses_and_formats_rule_strings() {
+        for rule in ["EST5EDT,M3.2.0,M11.1.0", "<+0530>-5:30", "IST-1GMT0,M10.5.0,M3.5.0/1", "<-03>3<-02>,M3.5.0/-2,M10.5.0/-1", "EST5EDT,0/0,J365/25"] {
+            assert_eq!(rule.parse::<PosixTz>().unwrap().to_string(), rule);
+        }
+        assert_eq!("CET-1CEST".parse::<PosixTz>().unwrap().to_string(), "CET-1CEST,M3.2.0,M11.1.0");
+        for invalid in ["", "EST", "ES5", "EST25", "EST5EDT,M3.2.0", "EST5EDT,M13.1.0,M11.1.0", "<+05", "EST5EDT,M3.2.0,M11.1.0,"] {
+            assert!(matches!(invalid.parse::<PosixTz>(), Err(Errors::ParseError(_))), "{}", invalid);
+        }
+    }
+
+    #[test]
+    fn follows_the_zone_it_describes() {
+        let cases = [
+            ("EST5EDT,M3.2.0,M11.1.0", Tz::America__New_York),
+            ("AEST-10AEDT,M10.1.0,M4.1.0/3", Tz::Australia__Sydney),
+            ("<-03>3<-02>,M3.2.0,M11.1.0", Tz::America__Miquelon),
+            ("IST-1GMT0,M10.5.0,M3.5.0/1", Tz::Europe__Dublin),
+        ];
+        for (rule, tz) in cases {
+            let posix: PosixTz = rule.parse().unwrap();
+            let mut at = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+            while at.year() < 2026 {
+                let expected = tz.offset_from_utc_datetime(&at.naive_utc()).fix();
+                assert_eq!(posix.offset_from_utc_datetime(&at.naive_utc()).fix(), expected, "{} at {}", rule, at);
+                at += Duration::minutes(30);
+            }
+        }
+
+        let eastern: PosixTz = "EST5EDT,M3.2.0,M11.1.0".parse().unwrap();
+        let local = |h, m| NaiveDate::from_ymd_opt(2024, 11, 3).unwrap().and_hms_opt(h, m, 0).unwrap();
+        let LocalResult::Ambiguous(first, second) = eastern.offset_from_local_datetime(&local(1, 30)) else {
+            panic!("01:30 on 3 November should be ambiguous");
+        };
+        assert_eq!((first.abbreviation(), second.abbreviation()), ("EDT", "EST"));
+        let spring = NaiveDate::from_ymd_opt(2024, 3, 10).unwrap().and_hms_opt(2, 30, 0).unwrap();
+        assert_eq!(eastern.offset_from_local_datetime(&spring), LocalResult::None);
+
+        let always: PosixTz = "EST5EDT4,0/0,J365/25".parse().unwrap();
+        assert_eq!(always.offset_from_utc_datetime(&local(12, 0)).abbreviation(), "EDT");
+    }
+}
@@ -0,0 +1,138 @@
+//! Python bindings built with `pyo3`
+
+use chrono::{DateTime, Duration, FixedOffset, NaiveDateTime};
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+use crate::{resolve_local, Errors, TimeZoneConverter};
+
+/// A [`TimeZoneConverter`] exposed to Python as `timezone_converter.TimeZoneConverter`
+/// 
+/// ```python
+/// from datetime import datetime
+/// from timezone_converter import TimeZoneConverter
+/// 
+/// converter = TimeZoneConverter("America/New_York", "Europe/London")
+/// converter.convert(datetime(2024, 11, 4, 10, 0))   # naive: read as New York wall-clock time
+/// converter.now_in_target()
+/// converter.difference()                           # datetime.timedelta(days=-1, seconds=68400)
+/// ```
+#[pyclass(name = "TimeZoneConverter", module = "timezone_converter", frozen)]
+#[derive(Debug)]
+pub struct PyTimeZoneConverter {
+    /// The converter every call is delegated to
+    inner: TimeZoneConverter,
+}
+
+/// A Python `datetime`, which may or may not carry a `tzinfo`
+#[derive(FromPyObject)]
+enum DateTimeInput {
+    /// An aware datetime, an exact instant
+    Aware(DateTime<FixedOffset>),
+    /// A naive datetime, read as a wall-clock time in the source timezone
+    Naive(NaiveDateTime),
+}
+
+#[pymethods]
+impl PyTimeZoneConverter {
+    /// Creates a converter between two IANA timezone identifiers, raising `ValueError` if either is invalid
+    #[new]
+    fn new(source: &str, target: &str) -> PyResult<Self> {
+        let inner = TimeZoneConverter::new(source, target).map_err(value_error)?;
+        Ok(Self { inner })
+    }
+
+    /// The source timezone identifier
+    #[getter]
+    fn source(&self) -> String {
+        self.inner.source_tz.to_string()
+    }
+
+    /// The target timezone identifier
+    #[getter]
+    fn target(&self) -> String {
+        self.inner.target_tz.to_string()
+    }
+
+    /// Converts a datetime to the target timezone, returning an aware datetime
+    /// 
+    /// Naive datetimes are read as source-local wall-clock times, resolving skipped and
+    /// repeated times with the converter's ambiguity policy.
+    fn convert(&self, dt: DateTimeInput) -> PyResult<DateTime<FixedOffset>> {
+        let instant = match dt {
+            DateTimeInput::Aware(dt) => dt.with_timezone(&self.inner.source_tz),
+            DateTimeInput::Naive(naive) => {
+                resolve_local(&self.inner.source_tz, naive, self.inner.ambiguity_policy).map_err(value_error)?
+            }
+        };
+        Ok(instant.with_timezone(&self.inner.target_tz).fixed_offset())
+    }
+
+    /// Gets the current time in the target timezone as an aware datetime
+    fn now_in_target(&self) -> DateTime<FixedOffset> {
+        self.inner.clock.now().with_timezone(&self.inner.target_tz).fixed_offset()
+    }
+
+    /// Gets the source offset minus the target offset as a `timedelta` (positive if source is ahead)
+    fn difference(&self) -> Duration {
+        self.inner.time_difference()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("TimeZoneConverter({:?}, {:?})", self.source(), self.target())
+    }
+}
+
+/// The `timezone_converter` Python module
+#[pymodule]
+#[pyo3(name = "timezone_converter")]
+fn python_module(module: &Bound<'_, PyModule>) -> PyResult<()> {
+    module.add_class::<PyTimeZoneConverter>()
+}
+
+/// Converts an error into a Python `ValueError`
+fn value_error(err: Errors) -> PyErr {
+    PyValueError::new_err(err.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{TimeZone, Utc};
+    use pyo3::types::PyDict;
+
+    use crate::FixedClock;
+
+    #[test]
+    fn converts_python_datetimes() {
+        Python::initialize();
+        Python::attach(|py| {
+            let now = Utc.with_ymd_and_hms(2024, 7, 1, 12, 0, 0).unwrap();
+            let converter = PyTimeZoneConverter {
+                inner: TimeZoneConverter::new("America/New_York", "Europe/London").unwrap().with_clock(FixedClock::new(now)),
+            };
+            let globals = PyDict::new(py);
+            globals.set_item("converter", Bound::new(py, converter).unwrap()).unwrap();
+            let eval = |code: &str| -> String {
+                let code = std::ffi::CString::new(code).unwrap();
+                py.eval(&code, Some(&globals), None).unwrap().str().unwrap().to_string()
+            };
+
+            py.run(c"from datetime import datetime, timezone, timedelta", Some(&globals), None).unwrap();
+            assert_eq!(eval("converter.convert(datetime(2024, 11, 4, 10, 0))"), "2024-11-04 15:00:00+00:00");
+            assert_eq!(eval("converter.convert(datetime(2024, 7, 1, 9, 0, tzinfo=timezone.utc))"), "2024-07-01 10:00:00+01:00");
+            assert_eq!(eval("converter.now_in_target()"), "2024-07-01 13:00:00+01:00");
+            assert_eq!(eval("converter.difference() == timedelta(hours=-5)"), "True");
+            assert_eq!(eval("repr(converter)"), "TimeZoneConverter(\"America/New_York\", \"Europe/London\")");
+        });
+    }
+
+    #[test]
+    fn invalid_zone_raises_value_error() {
+        Python::initialize();
+        Python::attach(|py| {
+            let err = PyTimeZoneConverter::new("Mars/Olympus", "UTC").unwrap_err();
+            assert!(err.is_instance_of::<PyValueError>(py));
+        });
+    }
+}
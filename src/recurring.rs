@@ -0,0 +1,208 @@
+//! Events that repeat at a fixed wall-clock time in their own timezone
+
+use std::sync::Arc;
+
+use chrono::{DateTime, Datelike, NaiveDate, NaiveTime, Utc, Weekday};
+use chrono_tz::Tz;
+
+use crate::{parse_tz, resolve_local_forward, Clock, Errors, SystemClock};
+
+/// An event that repeats daily or weekly at a wall-clock time in its anchor timezone
+/// 
+/// Occurrences stay at the same local time in the anchor zone across DST changes, so when
+/// only one of the anchor and target zones changes its clocks, the target-local time of
+/// the event drifts by the difference. Local times skipped by DST are shifted forward, and
+/// repeated ones use the earlier occurrence.
+/// 
+/// # Example
+/// 
+/// ```rust
+/// use chrono::{NaiveTime, TimeZone, Utc, Weekday};
+/// use timezone_converter::RecurringEvent;
+/// 
+/// let standup = RecurringEvent::weekly(
+///     Weekday::Tue,
+///     NaiveTime::from_hms_opt(9, 0, 0).unwrap(),
+///     "America/New_York",
+///     "Europe/London",
+/// ).unwrap();
+/// let after = Utc.with_ymd_and_hms(2024, 3, 1, 0, 0, 0).unwrap();
+/// let times: Vec<String> = standup.occurrences_after(after, 3).iter().map(|t| t.to_string()).collect();
+/// // New York springs forward on Mar 10, London not until Mar 31
+/// assert_eq!(times, [
+///     "2024-03-05 14:00:00 GMT",
+///     "2024-03-12 13:00:00 GMT",
+///     "2024-03-19 13:00:00 GMT",
+/// ]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct RecurringEvent {
+    /// The weekday the event falls on, or `None` for every day
+    weekday: Option<Weekday>,
+    /// The wall-clock time of the event in the anchor timezone
+    time: NaiveTime,
+    /// The timezone whose wall clock the event follows
+    anchor_tz: Tz,
+    /// The timezone occurrences are converted to
+    target_tz: Tz,
+    /// The clock read by [`Self::next_occurrences`]
+    clock: Arc<dyn Clock>,
+}
+
+impl RecurringEvent {
+    /// Creates an event that repeats every day
+    /// 
+    /// # Arguments
+    /// 
+    /// * `time` - The wall-clock time of the event in `anchor`
+    /// * `anchor` - The timezone identifier whose wall clock the event follows (e.g., "America/New_York")
+    /// * `target` - The timezone identifier to convert occurrences to (e.g., "Europe/London")
+    /// 
+    /// # Returns
+    /// 
+    /// * `Result<RecurringEvent, Errors>` - The event, or an error if a timezone is invalid
+    pub fn daily(time: NaiveTime, anchor: &str, target: &str) -> Result<Self, Errors> {
+        Self::new(None, time, anchor, target)
+    }
+
+    /// Creates an event that repeats every week on the same weekday
+    /// 
+    /// # Arguments
+    /// 
+    /// * `weekday` - The anchor-local weekday of the event
+    /// * `time` - The wall-clock time of the event in `anchor`
+    /// * `anchor` - The timezone identifier whose wall clock the event follows (e.g., "America/New_York")
+    /// * `target` - The timezone identifier to convert occurrences to (e.g., "Europe/London")
+    /// 
+    /// # Returns
+    /// 
+    /// * `Result<RecurringEvent, Errors>` - The event, or an error if a timezone is invalid
+    pub fn weekly(weekday: Weekday, time: NaiveTime, anchor: &str, target: &str) -> Result<Self, Errors> {
+        Self::new(Some(weekday), time, anchor, target)
+    }
+
+    /// Creates an event on `weekday`, or every day if it is `None`
+    fn new(weekday: Option<Weekday>, time: NaiveTime, anchor: &str, target: &str) -> Result<Self, Errors> {
+        Ok(Self {
+            weekday,
+            time,
+            anchor_tz: parse_tz(anchor)?,
+            target_tz: parse_tz(target)?,
+            clock: Arc::new(SystemClock),
+        })
+    }
+
+    /// Replaces the clock [`Self::next_occurrences`] reads the current time from
+    pub fn with_clock(mut self, clock: impl Clock + 'static) -> Self {
+        self.clock = Arc::new(clock);
+        self
+    }
+
+    /// Gets the next occurrences after the current time
+    /// 
+    /// # Arguments
+    /// 
+    /// * `n` - The number of occurrences
+    /// 
+    /// # Returns
+    /// 
+    /// * `Vec<DateTime<Tz>>` - The occurrences in the target timezone, in order
+    pub fn next_occurrences(&self, n: usize) -> Vec<DateTime<Tz>> {
+        self.occurrences_after(self.clock.now(), n)
+    }
+
+    /// Gets the first occurrences strictly after an instant
+    /// 
+    /// Fewer than `n` are returned only if the calendar runs out.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `after` - The instant to start after
+    /// * `n` - The number of occurrences
+    /// 
+    /// # Returns
+    /// 
+    /// * `Vec<DateTime<Tz>>` - The occurrences in the target timezone, in order
+    pub fn occurrences_after(&self, after: DateTime<Utc>, n: usize) -> Vec<DateTime<Tz>> {
+        self.occurrences_from(after.with_timezone(&self.anchor_tz).date_naive())
+            .filter(|occurrence| *occurrence > after)
+            .take(n)
+            .map(|occurrence| occurrence.with_timezone(&self.target_tz))
+            .collect()
+    }
+
+    /// Gets every occurrence between two instants
+    /// 
+    /// # Arguments
+    /// 
+    /// * `start` - The start of the range (inclusive)
+    /// * `end` - The end of the range (exclusive)
+    /// 
+    /// # Returns
+    /// 
+    /// * `Vec<DateTime<Tz>>` - The occurrences in the target timezone, in order
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use chrono::{NaiveTime, TimeZone, Utc};
+    /// use timezone_converter::RecurringEvent;
+    /// 
+    /// let backup = RecurringEvent::daily(NaiveTime::from_hms_opt(2, 0, 0).unwrap(), "Europe/Berlin", "UTC").unwrap();
+    /// let start = Utc.with_ymd_and_hms(2024, 3, 30, 0, 0, 0).unwrap();
+    /// let end = Utc.with_ymd_and_hms(2024, 4, 1, 0, 0, 0).unwrap();
+    /// let times: Vec<String> = backup.occurrences_between(start, end).iter().map(|t| t.to_string()).collect();
+    /// // 02:00 is skipped in Berlin on Mar 31 and shifts forward to 03:00 CEST
+    /// assert_eq!(times, ["2024-03-30 01:00:00 UTC", "2024-03-31 01:00:00 UTC"]);
+    /// ```
+    pub fn occurrences_between(&self, start: DateTime<Utc>, end: DateTime<Utc>) -> Vec<DateTime<Tz>> {
+        self.occurrences_from(start.with_timezone(&self.anchor_tz).date_naive())
+            .skip_while(|occurrence| *occurrence < start)
+            .take_while(|occurrence| *occurrence < end)
+            .map(|occurrence| occurrence.with_timezone(&self.target_tz))
+            .collect()
+    }
+
+    /// Iterates the occurrences in the anchor timezone on or after an anchor-local date
+    fn occurrences_from(&self, first: NaiveDate) -> impl Iterator<Item = DateTime<Tz>> + '_ {
+        first
+            .iter_days()
+            .filter(move |date| self.weekday.is_none_or(|weekday| date.weekday() == weekday))
+            .map_while(move |date| resolve_local_forward(&self.anchor_tz, date.and_time(self.time)).ok())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use crate::FixedClock;
+
+    #[test]
+    fn weekly_event_drifts_when_only_the_target_changes() {
+        let event = RecurringEvent::weekly(
+            Weekday::Mon,
+            NaiveTime::from_hms_opt(10, 0, 0).unwrap(),
+            "Asia/Tokyo",
+            "Europe/London",
+        ).unwrap()
+        .with_clock(FixedClock::new(Utc.with_ymd_and_hms(2024, 3, 18, 1, 0, 0).unwrap()));
+
+        let times: Vec<String> = event.next_occurrences(3).iter().map(|t| t.to_string()).collect();
+        assert_eq!(times, [
+            "2024-03-25 01:00:00 GMT",
+            "2024-04-01 02:00:00 BST",
+            "2024-04-08 02:00:00 BST",
+        ]);
+    }
+
+    #[test]
+    fn occurrence_bounds() {
+        let event = RecurringEvent::daily(NaiveTime::from_hms_opt(9, 0, 0).unwrap(), "UTC", "UTC").unwrap();
+        let nine = Utc.with_ymd_and_hms(2024, 1, 1, 9, 0, 0).unwrap();
+        assert_eq!(event.occurrences_after(nine, 1)[0], Utc.with_ymd_and_hms(2024, 1, 2, 9, 0, 0).unwrap());
+        assert_eq!(event.occurrences_between(nine, nine + chrono::Duration::days(2)).len(), 2);
+        assert!(event.occurrences_between(nine, nine).is_empty());
+        assert!(RecurringEvent::daily(NaiveTime::MIN, "UTC", "Nowhere/Land").is_err());
+    }
+}
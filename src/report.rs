@@ -0,0 +1,214 @@
+//! Serializable reports of the converter's answers, for exposing them over HTTP or in logs
+
+use chrono::{DateTime, Duration, TimeZone as ChronoTimeZone, Utc};
+use chrono_tz::Tz;
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "json")]
+use crate::{ConversionResult, TimeZoneInfo};
+use crate::{format_short_duration, serde_helpers, zone_tzdb_version, Errors, TimeZoneConverter, Transition, Zone};
+
+/// A datetime converted from the source timezone to the target timezone
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ConversionReport {
+    /// The datetime in the source timezone
+    #[serde(with = "serde_helpers::zoned_datetime")]
+    pub source: DateTime<Tz>,
+    /// The same instant in the target timezone
+    #[serde(with = "serde_helpers::zoned_datetime")]
+    pub target: DateTime<Tz>,
+    /// The source timezone's abbreviation at that instant (e.g., "EST")
+    pub source_abbreviation: String,
+    /// The target timezone's abbreviation at that instant (e.g., "GMT")
+    pub target_abbreviation: String,
+    /// Whether the target-local date differs from the source-local date
+    pub date_changed: bool,
+    /// The IANA tz database release the conversion used (e.g., "2024b")
+    pub tzdb_version: String,
+}
+
+/// The offset difference between the source and target timezones at an instant
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DifferenceReport {
+    /// The source timezone's name
+    pub source: String,
+    /// The target timezone's name
+    pub target: String,
+    /// The instant the offsets were compared at
+    pub at: DateTime<Utc>,
+    /// The source offset minus the target offset (positive if source is ahead)
+    #[serde(rename = "difference_seconds", with = "serde_helpers::duration_seconds")]
+    pub difference: Duration,
+    /// The difference as a compact label (e.g., "+5h45m")
+    pub label: String,
+    /// The IANA tz database release the offsets came from (e.g., "2024b")
+    pub tzdb_version: String,
+}
+
+/// The offset transitions of a timezone within a time range
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TransitionReport {
+    /// The timezone's name
+    pub zone: String,
+    /// The start of the range (inclusive)
+    pub start: DateTime<Utc>,
+    /// The end of the range (exclusive)
+    pub end: DateTime<Utc>,
+    /// The transitions within the range, in order
+    pub transitions: Vec<Transition>,
+    /// The IANA tz database release the transitions came from (e.g., "2024b")
+    pub tzdb_version: String,
+}
+
+impl TimeZoneConverter {
+    /// Converts a datetime to the target timezone and reports both sides of the conversion
+    /// 
+    /// # Arguments
+    /// 
+    /// * `dt` - The datetime to convert, in any timezone
+    /// 
+    /// # Returns
+    /// 
+    /// * `Result<ConversionReport, Errors>` - The datetime in both timezones with their abbreviations, or `Errors::ConversionError` if either is not a tz database zone
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use chrono::{TimeZone, Utc};
+    /// use timezone_converter::TimeZoneConverter;
+    /// 
+    /// let converter = TimeZoneConverter::new("America/New_York", "Asia/Tokyo").unwrap();
+    /// let report = converter.conversion_report(Utc.with_ymd_and_hms(2024, 1, 15, 20, 0, 0).unwrap()).unwrap();
+    /// assert_eq!(report.source_abbreviation, "EST");
+    /// assert!(report.date_changed);
+    /// ```
+    pub fn conversion_report<T: ChronoTimeZone>(&self, dt: DateTime<T>) -> Result<ConversionReport, Errors> {
+        let source = dt.with_timezone(&self.named_tz(Zone::Source)?);
+        let target = dt.with_timezone(&self.named_tz(Zone::Target)?);
+        Ok(ConversionReport {
+            source_abbreviation: source.format("%Z").to_string(),
+            target_abbreviation: target.format("%Z").to_string(),
+            date_changed: source.date_naive() != target.date_naive(),
+            source,
+            target,
+            tzdb_version: self.tzdb_version().to_string(),
+        })
+    }
+
+    /// Reports the offset difference between the source and target timezones at an instant
+    /// 
+    /// # Arguments
+    /// 
+    /// * `at` - The instant to compare the offsets at
+    /// 
+    /// # Returns
+    /// 
+    /// * `DifferenceReport` - Both zone names with the exact difference and its label
+    pub fn difference_report(&self, at: DateTime<Utc>) -> DifferenceReport {
+        let difference = self.time_difference_at(at);
+        DifferenceReport {
+            source: self.source_tz.name().to_string(),
+            target: self.target_tz.name().to_string(),
+            at,
+            difference,
+            label: format_short_duration(difference),
+            tzdb_version: self.tzdb_version().to_string(),
+        }
+    }
+
+    /// Reports the offset transitions of either timezone within a time range
+    /// 
+    /// # Arguments
+    /// 
+    /// * `start` - The start of the range (inclusive)
+    /// * `end` - The end of the range (exclusive)
+    /// * `zone` - Whether to list the source or the target timezone's transitions
+    /// 
+    /// # Returns
+    /// 
+    /// * `TransitionReport` - The zone name, the range and the transitions within it
+    pub fn transition_report(&self, start: DateTime<Utc>, end: DateTime<Utc>, zone: Zone) -> TransitionReport {
+        TransitionReport {
+            zone: self.zone(zone).name().to_string(),
+            start,
+            end,
+            transitions: self.transitions_between(start, end, zone),
+            tzdb_version: zone_tzdb_version(self.zone(zone)).to_string(),
+        }
+    }
+}
+
+/// Implements `to_json` for serializable answer types
+#[cfg(feature = "json")]
+macro_rules! impl_to_json {
+    ($($ty:ty),*) => {
+        $(
+            impl $ty {
+                /// Serializes this value as a compact JSON string
+                /// 
+                /// # Returns
+                /// 
+                /// * `Result<String, Errors>` - The JSON text, or `Errors::ConversionError` if serialization fails
+                pub fn to_json(&self) -> Result<String, Errors> {
+                    serde_json::to_string(self).map_err(|err| Errors::ConversionError(err.to_string()))
+                }
+            }
+        )*
+    };
+}
+
+#[cfg(feature = "json")]
+impl_to_json!(ConversionReport, DifferenceReport, TransitionReport, ConversionResult, TimeZoneInfo, Transition);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tzdb_version;
+
+    #[test]
+    fn reports_round_trip() {
+        let timezone = TimeZoneConverter::new("America/New_York", "Asia/Kathmandu").unwrap();
+        let at = Utc.with_ymd_and_hms(2024, 7, 1, 12, 0, 0).unwrap();
+
+        let difference = timezone.difference_report(at);
+        assert_eq!(difference.label, "-9h45m");
+        let value = serde_json::to_value(&difference).unwrap();
+        assert_eq!(value["difference_seconds"], -(9 * 3600 + 45 * 60));
+        assert_eq!(serde_json::from_value::<DifferenceReport>(value).unwrap(), difference);
+
+        let conversion = timezone.conversion_report(at).unwrap();
+        let value = serde_json::to_value(&conversion).unwrap();
+        assert_eq!(value["source"], "2024-07-01T08:00:00-04:00[America/New_York]");
+        assert_eq!(value["target_abbreviation"], "+0545");
+        assert_eq!(serde_json::from_value::<ConversionReport>(value).unwrap(), conversion);
+
+        let timezone = TimeZoneConverter::new("UTC", "+05:30").unwrap();
+        assert!(matches!(timezone.conversion_report(at), Err(Errors::ConversionError(_))));
+    }
+
+    #[test]
+    fn transition_report_lists_the_range() {
+        let timezone = TimeZoneConverter::new("America/New_York", "UTC").unwrap();
+        let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let end = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+        let report = timezone.transition_report(start, end, Zone::Source);
+        assert_eq!(report.zone, "America/New_York");
+        assert_eq!(report.transitions.len(), 2);
+        assert!(timezone.transition_report(start, end, Zone::Target).transitions.is_empty());
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn to_json() {
+        let timezone = TimeZoneConverter::new("Europe/Berlin", "UTC").unwrap();
+        let at = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        assert_eq!(
+            timezone.difference_report(at).to_json().unwrap(),
+            format!(r#"{{"source":"Europe/Berlin","target":"UTC","at":"2024-01-01T00:00:00Z","difference_seconds":3600,"label":"+1h","tzdb_version":"{}"}}"#, tzdb_version())
+        );
+        assert_eq!(
+            timezone.timezone_info_at(at, Zone::Source).unwrap().to_json().unwrap(),
+            format!(r#"{{"name":"Europe/Berlin","offset_seconds":3600,"is_dst":false,"dst_savings_seconds":0,"tzdb_version":"{}"}}"#, tzdb_version())
+        );
+    }
+}
@@ -0,0 +1,248 @@
+//! An async scheduler that runs jobs at local wall-clock times
+
+use std::ops::ControlFlow;
+use std::sync::Arc;
+use std::time::Duration as StdDuration;
+
+use chrono::{DateTime, NaiveTime, Utc, Weekday};
+use chrono_tz::Tz;
+
+use crate::{Clock, Errors, RecurringEvent, SystemClock};
+
+/// The longest the scheduler sleeps before checking the clock again, so a suspended
+/// machine or a system clock adjustment is noticed promptly
+const MAX_SLEEP: StdDuration = StdDuration::from_secs(60);
+
+/// A job firing, passed to the callback given to [`Scheduler::run`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Firing {
+    /// The label the job was added with
+    pub label: String,
+    /// The wall-clock time the job was scheduled for, in its own timezone
+    pub scheduled: DateTime<Tz>,
+}
+
+/// Runs jobs at wall-clock times such as "07:00 in Europe/Berlin every day"
+/// 
+/// Each job's next fire time is recomputed from its wall-clock time after every firing,
+/// so it stays at the same local time across DST transitions instead of drifting by an
+/// hour as a fixed sleep interval would. Wall-clock times skipped by DST are shifted
+/// forward, and repeated ones fire once, at their first occurrence.
+/// 
+/// # Example
+/// 
+/// ```rust,no_run
+/// use std::ops::ControlFlow;
+/// use chrono::NaiveTime;
+/// use timezone_converter::Scheduler;
+/// 
+/// # async fn run() {
+/// let mut scheduler = Scheduler::new();
+/// scheduler.daily("report", NaiveTime::from_hms_opt(7, 0, 0).unwrap(), "Europe/Berlin").unwrap();
+/// scheduler.run(|firing| {
+///     println!("{} due at {}", firing.label, firing.scheduled);
+///     ControlFlow::Continue(())
+/// }).await;
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Scheduler {
+    /// The labelled jobs, in the order they were added
+    jobs: Vec<(String, RecurringEvent)>,
+    /// The clock fire times are measured against
+    clock: Arc<dyn Clock>,
+}
+
+impl Default for Scheduler {
+    fn default() -> Self {
+        Self {
+            jobs: Vec::new(),
+            clock: Arc::new(SystemClock),
+        }
+    }
+}
+
+impl Scheduler {
+    /// Creates a Scheduler with no jobs
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Replaces the clock fire times are measured against
+    pub fn with_clock(mut self, clock: impl Clock + 'static) -> Self {
+        self.clock = Arc::new(clock);
+        self
+    }
+
+    /// Adds a job that runs every day at a wall-clock time
+    /// 
+    /// # Arguments
+    /// 
+    /// * `label` - The label passed back in each [`Firing`]
+    /// * `time` - The wall-clock time to run at
+    /// * `zone` - The timezone identifier whose wall clock the job follows (e.g., "Europe/Berlin")
+    /// 
+    /// # Returns
+    /// 
+    /// * `Result<&mut Scheduler, Errors>` - The scheduler, for chaining, or an error if `zone` is invalid
+    pub fn daily(&mut self, label: &str, time: NaiveTime, zone: &str) -> Result<&mut Self, Errors> {
+        let event = RecurringEvent::daily(time, zone, zone)?;
+        self.jobs.push((label.to_string(), event));
+        Ok(self)
+    }
+
+    /// Adds a job that runs every week on a weekday at a wall-clock time
+    /// 
+    /// # Arguments
+    /// 
+    /// * `label` - The label passed back in each [`Firing`]
+    /// * `weekday` - The local weekday to run on
+    /// * `time` - The wall-clock time to run at
+    /// * `zone` - The timezone identifier whose wall clock the job follows (e.g., "Europe/Berlin")
+    /// 
+    /// # Returns
+    /// 
+    /// * `Result<&mut Scheduler, Errors>` - The scheduler, for chaining, or an error if `zone` is invalid
+    pub fn weekly(&mut self, label: &str, weekday: Weekday, time: NaiveTime, zone: &str) -> Result<&mut Self, Errors> {
+        let event = RecurringEvent::weekly(weekday, time, zone, zone)?;
+        self.jobs.push((label.to_string(), event));
+        Ok(self)
+    }
+
+    /// Gets the number of jobs
+    pub fn len(&self) -> usize {
+        self.jobs.len()
+    }
+
+    /// Whether the scheduler has no jobs
+    pub fn is_empty(&self) -> bool {
+        self.jobs.is_empty()
+    }
+
+    /// Gets the next job to fire after the current time
+    /// 
+    /// # Returns
+    /// 
+    /// * `Option<Firing>` - The earliest upcoming firing, or `None` if there are no jobs
+    pub fn next_firing(&self) -> Option<Firing> {
+        let now = self.clock.now();
+        self.jobs
+            .iter()
+            .filter_map(|(label, event)| next_after(label, event, now))
+            .min_by_key(|firing| firing.scheduled)
+    }
+
+    /// Sleeps until each job is due and calls `callback` with it, until the callback breaks
+    /// 
+    /// Only firings after the call are made; missed ones are not caught up. Jobs due at
+    /// the same instant fire in the order they were added. Returns immediately if there
+    /// are no jobs.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `callback` - Called with each firing; returning `ControlFlow::Break` stops the scheduler
+    pub async fn run<F: FnMut(Firing) -> ControlFlow<()>>(&self, mut callback: F) {
+        let start = self.clock.now();
+        let mut upcoming: Vec<Option<Firing>> = self.jobs
+            .iter()
+            .map(|(label, event)| next_after(label, event, start))
+            .collect();
+
+        loop {
+            let Some(index) = upcoming
+                .iter()
+                .enumerate()
+                .filter_map(|(index, firing)| firing.as_ref().map(|firing| (index, firing.scheduled)))
+                .min_by_key(|(_, scheduled)| *scheduled)
+                .map(|(index, _)| index)
+            else {
+                return;
+            };
+            let Some(firing) = upcoming[index].take() else {
+                return;
+            };
+
+            let due = firing.scheduled.with_timezone(&Utc);
+            loop {
+                let now = self.clock.now();
+                if now >= due {
+                    break;
+                }
+                let wait = (due - now).to_std().unwrap_or_default().min(MAX_SLEEP);
+                tokio::time::sleep(wait).await;
+            }
+
+            let (label, event) = &self.jobs[index];
+            upcoming[index] = next_after(label, event, due);
+            if callback(firing).is_break() {
+                return;
+            }
+        }
+    }
+}
+
+/// Gets a job's first firing strictly after an instant
+fn next_after(label: &str, event: &RecurringEvent, after: DateTime<Utc>) -> Option<Firing> {
+    event.occurrences_after(after, 1).pop().map(|scheduled| Firing {
+        label: label.to_string(),
+        scheduled,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    /// A clock that follows tokio's (paused, auto-advancing) time from a starting instant
+    #[derive(Debug)]
+    struct TokioClock {
+        /// The instant the clock read when `started`
+        base: DateTime<Utc>,
+        /// When the clock started, in tokio's time
+        started: tokio::time::Instant,
+    }
+
+    impl Clock for TokioClock {
+        fn now(&self) -> DateTime<Utc> {
+            self.base + chrono::Duration::from_std(self.started.elapsed()).unwrap()
+        }
+    }
+
+    fn time(hour: u32) -> NaiveTime {
+        NaiveTime::from_hms_opt(hour, 0, 0).unwrap()
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn fires_at_local_time_across_dst() {
+        let clock = TokioClock {
+            base: Utc.with_ymd_and_hms(2024, 3, 29, 12, 0, 0).unwrap(),
+            started: tokio::time::Instant::now(),
+        };
+        let mut scheduler = Scheduler::new().with_clock(clock);
+        scheduler.daily("berlin", time(7), "Europe/Berlin").unwrap()
+            .weekly("tokyo", Weekday::Sat, time(9), "Asia/Tokyo").unwrap();
+
+        let mut fired = Vec::new();
+        scheduler.run(|firing| {
+            fired.push(format!("{} {}", firing.label, firing.scheduled.with_timezone(&Utc)));
+            if fired.len() == 4 { ControlFlow::Break(()) } else { ControlFlow::Continue(()) }
+        }).await;
+
+        // Berlin springs forward on Mar 31, so 07:00 moves from 06:00 to 05:00 UTC
+        assert_eq!(fired, [
+            "tokyo 2024-03-30 00:00:00 UTC",
+            "berlin 2024-03-30 06:00:00 UTC",
+            "berlin 2024-03-31 05:00:00 UTC",
+            "berlin 2024-04-01 05:00:00 UTC",
+        ]);
+    }
+
+    #[tokio::test]
+    async fn empty_scheduler_returns() {
+        let scheduler = Scheduler::new();
+        assert!(scheduler.is_empty());
+        assert!(scheduler.next_firing().is_none());
+        scheduler.run(|_| ControlFlow::Continue(())).await;
+    }
+}
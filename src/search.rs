@@ -0,0 +1,232 @@
+//! Finding timezones by country, city or free-text query
+
+use std::sync::OnceLock;
+
+use chrono_tz::Tz;
+
+use crate::aliases::is_backward_link;
+use crate::{tab_rows, ZONE_TAB};
+
+/// The tz database's `iso3166.tab`: country codes and names
+const COUNTRY_TAB: &str = include_str!("../data/iso3166.tab");
+
+/// Major cities that do not name a zone themselves
+const CITY_TAB: &str = include_str!("../data/cities.tab");
+
+/// The parsed country and city tables
+struct Index {
+    /// The country code and zone of each `zone.tab` row, in file order
+    zones: Vec<(&'static str, Tz)>,
+    /// Each country code with the normalized forms of its name
+    countries: Vec<(&'static str, Vec<String>)>,
+    /// Each normalized city name with its zone, sorted by name
+    cities: Vec<(String, Tz)>,
+}
+
+impl Index {
+    /// Gets the index, parsing the embedded tables on first use
+    fn get() -> &'static Index {
+        static INDEX: OnceLock<Index> = OnceLock::new();
+        INDEX.get_or_init(Index::parse)
+    }
+
+    fn parse() -> Self {
+        // Rows for zones newer than the compiled-in tz database are skipped
+        let zones: Vec<(&str, Tz)> = tab_rows(ZONE_TAB)
+            .filter_map(|fields| Some((fields[0], fields.get(2)?.parse().ok()?)))
+            .collect();
+
+        let countries = tab_rows(COUNTRY_TAB)
+            .filter_map(|fields| Some((fields[0], country_names(fields.get(1)?))))
+            .collect();
+
+        let mut cities: Vec<(String, Tz)> = zones
+            .iter()
+            .map(|(_, tz)| (normalize(tz.name().rsplit('/').next().unwrap_or_default()), *tz))
+            .chain(tab_rows(CITY_TAB).filter_map(|fields| Some((normalize(fields[0]), fields.get(1)?.parse().ok()?))))
+            .collect();
+        cities.sort_by(|a, b| a.0.cmp(&b.0));
+
+        Self { zones, countries, cities }
+    }
+
+    /// The zones of a country, given its ISO 3166 code
+    fn country_zones<'a>(&'a self, code: &'a str) -> impl Iterator<Item = Tz> + 'a {
+        self.zones
+            .iter()
+            .filter(move |(country, _)| country.eq_ignore_ascii_case(code))
+            .map(|(_, tz)| *tz)
+    }
+}
+
+/// Lists the zones used in a country, in the order of the tz database's `zone.tab`
+/// 
+/// # Arguments
+/// 
+/// * `code` - The ISO 3166-1 alpha-2 country code, in any case (e.g., "DE")
+/// 
+/// # Returns
+/// 
+/// * `Vec<Tz>` - The country's zones, or an empty list for an unknown code
+/// 
+/// # Example
+/// 
+/// ```rust
+/// use chrono_tz::Tz;
+/// use timezone_converter::timezones_for_country;
+/// 
+/// assert_eq!(timezones_for_country("de"), [Tz::Europe__Berlin, Tz::Europe__Busingen]);
+/// assert_eq!(timezones_for_country("US")[0], Tz::America__New_York);
+/// ```
+pub fn timezones_for_country(code: &str) -> Vec<Tz> {
+    Index::get().country_zones(code.trim()).collect()
+}
+
+/// Finds the zones used in a city, exact matches first and then cities starting with the name
+/// 
+/// Besides the cities zones are named after, a table of major cities such as Mumbai or San
+/// Francisco is searched. Case, spaces, underscores and hyphens are ignored.
+/// 
+/// # Arguments
+/// 
+/// * `city` - The city name (e.g., "Mumbai")
+/// 
+/// # Returns
+/// 
+/// * `Vec<Tz>` - The matching zones without duplicates, best matches first
+/// 
+/// # Example
+/// 
+/// ```rust
+/// use chrono_tz::Tz;
+/// use timezone_converter::find_by_city;
+/// 
+/// assert_eq!(find_by_city("Mumbai"), [Tz::Asia__Kolkata]);
+/// assert_eq!(find_by_city("new york"), [Tz::America__New_York]);
+/// ```
+pub fn find_by_city(city: &str) -> Vec<Tz> {
+    ranked(city_matches(&normalize(city)))
+}
+
+/// Searches for zones by city, country code, country name or identifier, best matches first
+/// 
+/// Exact city matches rank first, then the zones of a matching country, then cities
+/// starting with the query, then zones whose identifier contains it.
+/// 
+/// # Arguments
+/// 
+/// * `query` - What the user typed (e.g., "Germany", "DE", "sao paulo" or "pacific")
+/// 
+/// # Returns
+/// 
+/// * `Vec<Tz>` - The matching zones without duplicates, best matches first
+/// 
+/// # Example
+/// 
+/// ```rust
+/// use chrono_tz::Tz;
+/// use timezone_converter::search;
+/// 
+/// assert_eq!(search("Brussels")[0], Tz::Europe__Brussels);
+/// assert_eq!(search("Japan"), [Tz::Asia__Tokyo]);
+/// ```
+pub fn search(query: &str) -> Vec<Tz> {
+    let normalized = normalize(query);
+    if normalized.is_empty() {
+        return Vec::new();
+    }
+    let index = Index::get();
+    let mut matches = city_matches(&normalized);
+
+    for (code, names) in &index.countries {
+        let code_matches = query.trim().len() == 2 && code.eq_ignore_ascii_case(query.trim());
+        if code_matches || names.contains(&normalized) {
+            matches.extend(index.country_zones(code).map(|tz| (1, tz)));
+        }
+    }
+
+    matches.extend(
+        chrono_tz::TZ_VARIANTS
+            .iter()
+            .filter(|tz| !is_backward_link(**tz) && normalize(tz.name()).contains(&normalized))
+            .map(|tz| (3, *tz)),
+    );
+    ranked(matches)
+}
+
+/// Finds the cities equal to a normalized query, ranked 0, and those starting with it, ranked 2
+fn city_matches(query: &str) -> Vec<(u8, Tz)> {
+    if query.is_empty() {
+        return Vec::new();
+    }
+    Index::get()
+        .cities
+        .iter()
+        .filter(|(name, _)| name.starts_with(query))
+        .map(|(name, tz)| (if name == query { 0 } else { 2 }, *tz))
+        .collect()
+}
+
+/// Orders matches by rank, keeping the first occurrence of each zone
+fn ranked(mut matches: Vec<(u8, Tz)>) -> Vec<Tz> {
+    matches.sort_by_key(|(rank, _)| *rank);
+    let mut zones: Vec<Tz> = Vec::with_capacity(matches.len());
+    for (_, tz) in matches {
+        if !zones.contains(&tz) {
+            zones.push(tz);
+        }
+    }
+    zones
+}
+
+/// The forms of a country name a query can match: in full, and without or only its parenthesised part
+/// (so "Britain (UK)" matches "britain" and "uk")
+fn country_names(name: &str) -> Vec<String> {
+    let mut names = vec![normalize(name)];
+    if let Some((outer, inner)) = name.split_once(" (") {
+        names.push(normalize(outer));
+        names.push(normalize(inner.trim_end_matches(')')));
+    }
+    names
+}
+
+/// Lowercases a name and treats spaces, underscores and hyphens alike, dropping dots and apostrophes
+fn normalize(name: &str) -> String {
+    let cleaned: String = name
+        .chars()
+        .filter(|c| !matches!(c, '.' | '\''))
+        .map(|c| if matches!(c, '_' | '-') { ' ' } else { c.to_ascii_lowercase() })
+        .collect();
+    cleaned.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_zones_by_country_and_city() {
+        assert!(timezones_for_country("XX").is_empty());
+        assert_eq!(timezones_for_country(" in "), [Tz::Asia__Kolkata]);
+        assert!(timezones_for_country("AU").contains(&Tz::Australia__Sydney));
+
+        assert_eq!(find_by_city("St. Louis"), [Tz::America__Chicago]);
+        assert_eq!(find_by_city("buenos_aires"), [Tz::America__Argentina__Buenos_Aires]);
+        assert_eq!(find_by_city("San F"), [Tz::America__Los_Angeles]);
+        assert!(find_by_city("").is_empty());
+    }
+
+    #[test]
+    fn search_ranks_matches() {
+        assert_eq!(search("UK")[0], Tz::Europe__London);
+        // Germany's zones rank above cities such as Denver that start with "de"
+        assert_eq!(search("de")[..3], [Tz::Europe__Berlin, Tz::Europe__Busingen, Tz::Asia__Kolkata]);
+        // The city of Sydney ranks above other zones containing "sydney"
+        assert_eq!(search("sydney")[0], Tz::Australia__Sydney);
+        let pacific = search("pacific");
+        assert!(pacific.contains(&Tz::Pacific__Auckland));
+        // Legacy names such as US/Pacific are left out
+        assert!(pacific.iter().all(|tz| tz.name().starts_with("Pacific/")));
+        assert!(search("  ").is_empty());
+    }
+}
@@ -0,0 +1,99 @@
+//! Serde adapters for types that don't implement `Serialize`/`Deserialize` themselves
+
+use serde::de::Error as _;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::TimeZoneConverter;
+
+/// Serializes a `chrono::Duration` as a whole number of seconds
+pub(crate) mod duration_seconds {
+    use chrono::Duration;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub(crate) fn serialize<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_i64(duration.num_seconds())
+    }
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Duration, D::Error> {
+        let seconds = i64::deserialize(deserializer)?;
+        Duration::try_seconds(seconds)
+            .ok_or_else(|| serde::de::Error::custom(format!("{} seconds is out of range", seconds)))
+    }
+}
+
+/// Serializes a `chrono_tz::Tz` as its IANA name
+pub(crate) mod tz_name {
+    use chrono_tz::Tz;
+    use serde::de::Error as _;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub(crate) fn serialize<S: Serializer>(tz: &Tz, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(tz.name())
+    }
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Tz, D::Error> {
+        let name = String::deserialize(deserializer)?;
+        name.parse().map_err(D::Error::custom)
+    }
+}
+
+/// Serializes a `DateTime<Tz>` as an RFC 3339 timestamp followed by the IANA name in brackets,
+/// e.g. `2024-03-10T16:30:00+09:00[Asia/Tokyo]`, the format used by RFC 9557 and Temporal
+pub(crate) mod zoned_datetime {
+    use chrono::{DateTime, Offset, Utc};
+    use chrono_tz::Tz;
+    use serde::de::Error as _;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    use crate::parse_tz;
+
+    pub(crate) fn serialize<S: Serializer>(datetime: &DateTime<Tz>, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(&format_args!("{}[{}]", datetime.to_rfc3339(), datetime.timezone().name()))
+    }
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<DateTime<Tz>, D::Error> {
+        let text = String::deserialize(deserializer)?;
+        let (timestamp, zone) = text
+            .strip_suffix(']')
+            .and_then(|rest| rest.split_once('['))
+            .ok_or_else(|| D::Error::custom(format!("'{}' has no [timezone] suffix", text)))?;
+        let tz = parse_tz(zone).map_err(D::Error::custom)?;
+        let datetime = DateTime::parse_from_rfc3339(timestamp).map_err(D::Error::custom)?;
+        let zoned = datetime.with_timezone(&Utc).with_timezone(&tz);
+        if zoned.offset().fix() != *datetime.offset() {
+            return Err(D::Error::custom(format!(
+                "offset {} does not match {} at {}",
+                datetime.offset(),
+                zone,
+                timestamp
+            )));
+        }
+        Ok(zoned)
+    }
+}
+
+/// The serialized form of a [`TimeZoneConverter`]: its pair of IANA names
+#[derive(Serialize, Deserialize)]
+struct ConverterNames<'a> {
+    #[serde(borrow)]
+    source: std::borrow::Cow<'a, str>,
+    #[serde(borrow)]
+    target: std::borrow::Cow<'a, str>,
+}
+
+impl Serialize for TimeZoneConverter {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        ConverterNames {
+            source: self.source_tz.name(),
+            target: self.target_tz.name(),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for TimeZoneConverter {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let names = ConverterNames::deserialize(deserializer)?;
+        TimeZoneConverter::new(&names.source, &names.target).map_err(D::Error::custom)
+    }
+}
@@ -0,0 +1,578 @@
+//! Timezones read at runtime from a system zoneinfo directory of TZif files
+
+use std::collections::HashMap;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::path::{Component, Path, PathBuf};
+use std::sync::{Mutex, OnceLock};
+use std::time::SystemTime;
+
+use chrono::{Duration, FixedOffset, LocalResult, NaiveDate, NaiveDateTime, NaiveTime, Offset, TimeZone};
+use chrono_tz::OffsetComponents;
+
+use crate::{Errors, PosixTz, ZoneProvider, ZoneSpec};
+
+/// The zoneinfo directory used when `TZDIR` is not set
+const DEFAULT_ZONEINFO_DIR: &str = "/usr/share/zoneinfo";
+
+/// A file's modification time and length, which change when tzdata updates rewrite it
+type FileStamp = (Option<SystemTime>, u64);
+
+/// A system timezone database: a zoneinfo directory of TZif files, such as `/usr/share/zoneinfo`
+/// 
+/// Zones are read when they are looked up, so a long-running process picks up tzdata
+/// updates installed after it started by looking the zone up again. Each file is parsed
+/// once per version of it on disk and kept for the life of the process.
+/// 
+/// # Example
+/// 
+/// ```rust,no_run
+/// use timezone_converter::{SystemTzdb, TimeZoneConverter};
+/// 
+/// let tzdb = SystemTzdb::default();
+/// let converter = TimeZoneConverter::with_system_tzdb("America/New_York", "Europe/London", &tzdb).unwrap();
+/// println!("tzdata {:?}", tzdb.version());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SystemTzdb {
+    /// The zoneinfo directory
+    dir: PathBuf,
+}
+
+/// A timezone read from a TZif file by [`SystemTzdb`]
+#[derive(Clone, Copy)]
+pub struct SystemZone {
+    /// The parsed file, shared by every lookup of the same version of it
+    data: &'static Tzif,
+}
+
+/// The offset of a [`SystemZone`] at a particular instant
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SystemOffset {
+    /// The zone the offset belongs to
+    zone: SystemZone,
+    /// The offset, in seconds east of UTC
+    offset: i32,
+    /// How much of the offset is DST, in seconds
+    dst: i32,
+    /// The abbreviation in effect
+    abbreviation: &'static str,
+}
+
+/// The contents of a TZif file
+#[derive(Debug)]
+struct Tzif {
+    /// The zone's name, its path below the zoneinfo directory
+    name: String,
+    /// The instants, in seconds since the epoch, at which the local time type changes
+    transitions: Vec<i64>,
+    /// The index into `types` of the type each transition changes to
+    transition_types: Vec<usize>,
+    /// The local time types
+    types: Vec<LocalType>,
+    /// The rule for instants after the last transition
+    footer: Option<PosixTz>,
+    /// The release of the tz database the file was read from, if its directory records it
+    version: Option<String>,
+}
+
+/// A local time type of a TZif file
+#[derive(Debug)]
+struct LocalType {
+    /// The offset, in seconds east of UTC
+    offset: i32,
+    /// Whether the type is DST
+    is_dst: bool,
+    /// The abbreviation
+    abbreviation: String,
+}
+
+impl SystemTzdb {
+    /// Uses the TZif files in a zoneinfo directory
+    /// 
+    /// # Arguments
+    /// 
+    /// * `dir` - The directory (e.g., "/usr/share/zoneinfo")
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    /// Gets the zoneinfo directory
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Looks up a timezone
+    /// 
+    /// IANA names, including legacy ones and abbreviations such as "PST", are read from the
+    /// directory. Literal offsets and POSIX TZ strings are accepted as by [`ZoneSpec`]'s
+    /// `FromStr`, as they need no data.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `name` - The timezone (e.g., "America/New_York")
+    /// 
+    /// # Returns
+    /// 
+    /// * `Result<ZoneSpec, Errors>` - The zone, `Errors::InvalidTimeZone` if the directory has no such zone, or `Errors::ParseError` if its file is not valid TZif
+    pub fn zone(&self, name: &str) -> Result<ZoneSpec, Errors> {
+        let name = name.trim();
+        if let Some(zone) = self.load(name)? {
+            return Ok(ZoneSpec::System(zone));
+        }
+        match name.parse::<ZoneSpec>() {
+            Ok(ZoneSpec::Named(tz)) => self.load(tz.name())?.map(ZoneSpec::System).ok_or_else(|| Errors::InvalidTimeZone {
+                input: name.to_string(),
+                suggestions: Vec::new(),
+            }),
+            Ok(zone) => Ok(zone),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Gets the release of the tz database installed in the directory (e.g., "2025b")
+    /// 
+    /// The release is read from the `+VERSION` file or the header of `tzdata.zi`, so
+    /// `None` is returned for directories that have neither.
+    pub fn version(&self) -> Option<String> {
+        if let Ok(version) = std::fs::read_to_string(self.dir.join("+VERSION")) {
+            return Some(version.trim().to_string());
+        }
+        let zi = std::fs::read_to_string(self.dir.join("tzdata.zi")).ok()?;
+        Some(zi.lines().next()?.strip_prefix("# version ")?.trim().to_string())
+    }
+
+    /// Reads a zone's file, or gets it from the cache if it has not changed on disk
+    fn load(&self, name: &str) -> Result<Option<SystemZone>, Errors> {
+        // Only plain relative names are looked up, so a name cannot escape the directory
+        let relative = Path::new(name);
+        if name.is_empty() || !relative.components().all(|part| matches!(part, Component::Normal(_))) {
+            return Ok(None);
+        }
+        let path = self.dir.join(relative);
+        let Ok(metadata) = std::fs::metadata(&path) else {
+            return Ok(None);
+        };
+        if !metadata.is_file() {
+            return Ok(None);
+        }
+        let stamp: FileStamp = (metadata.modified().ok(), metadata.len());
+
+        static CACHE: OnceLock<Mutex<HashMap<PathBuf, (FileStamp, &'static Tzif)>>> = OnceLock::new();
+        let mut cache = CACHE.get_or_init(Default::default).lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        if let Some((cached, data)) = cache.get(&path) {
+            if *cached == stamp {
+                return Ok(Some(SystemZone { data }));
+            }
+        }
+        let bytes = std::fs::read(&path).map_err(|err| Errors::ParseError(format!("could not read {}: {}", path.display(), err)))?;
+        let mut tzif = Tzif::parse(name, &bytes)?;
+        tzif.version = self.version();
+        let data: &'static Tzif = Box::leak(Box::new(tzif));
+        cache.insert(path, (stamp, data));
+        Ok(Some(SystemZone { data }))
+    }
+}
+
+impl Default for SystemTzdb {
+    /// Uses the directory named by the `TZDIR` environment variable, or `/usr/share/zoneinfo`
+    fn default() -> Self {
+        Self::new(std::env::var_os("TZDIR").map(PathBuf::from).unwrap_or_else(|| PathBuf::from(DEFAULT_ZONEINFO_DIR)))
+    }
+}
+
+impl ZoneProvider for SystemTzdb {
+    fn resolve(&self, name: &str) -> Result<ZoneSpec, Errors> {
+        self.zone(name)
+    }
+}
+
+impl SystemZone {
+    /// Gets the zone's name (e.g., "America/New_York")
+    pub fn name(&self) -> &'static str {
+        &self.data.name
+    }
+
+    /// Gets the release of the tz database the zone was read from (e.g., "2024b"), if its
+    /// directory records it
+    pub fn tzdb_version(&self) -> Option<&'static str> {
+        self.data.version.as_deref()
+    }
+
+    /// Whether the zone's offset never changes
+    pub(crate) fn is_fixed(&self) -> bool {
+        self.data.transitions.is_empty() && self.data.footer.is_none_or(|footer| footer.dst_abbreviation().is_none())
+    }
+
+    /// The offset in effect at an instant, in seconds since the epoch
+    fn offset_at(&self, timestamp: i64) -> SystemOffset {
+        let data = self.data;
+        let index = data.transitions.partition_point(|at| *at <= timestamp);
+        if index == data.transitions.len() {
+            if let Some(footer) = &data.footer {
+                let naive = chrono::DateTime::from_timestamp(timestamp, 0).unwrap_or_default().naive_utc();
+                let offset = footer.offset_from_utc_datetime(&naive);
+                return SystemOffset {
+                    zone: *self,
+                    offset: offset.fix().local_minus_utc(),
+                    dst: offset.dst_offset().num_seconds() as i32,
+                    abbreviation: if offset.is_dst() { footer.dst_abbreviation().unwrap_or_default() } else { footer.std_abbreviation() },
+                };
+            }
+        }
+        // Before the first transition, the first local time type applies
+        let type_index = index.checked_sub(1).map_or(0, |i| data.transition_types[i]);
+        let local = &data.types[type_index];
+        SystemOffset {
+            zone: *self,
+            offset: local.offset,
+            dst: if local.is_dst { local.offset - self.std_offset_near(index).unwrap_or(local.offset - 3600) } else { 0 },
+            abbreviation: &local.abbreviation,
+        }
+    }
+
+    /// The standard offset nearest to a transition, for working out how much of a DST offset is DST
+    fn std_offset_near(&self, index: usize) -> Option<i32> {
+        let data = self.data;
+        let earlier = data.transition_types[..index.saturating_sub(1)].iter().rev();
+        let later = data.transition_types[index..].iter();
+        earlier.chain(later).map(|i| &data.types[*i]).find(|local| !local.is_dst).map(|local| local.offset)
+    }
+}
+
+impl PartialEq for SystemZone {
+    fn eq(&self, other: &Self) -> bool {
+        std::ptr::eq(self.data, other.data)
+    }
+}
+
+impl Eq for SystemZone {}
+
+impl Hash for SystemZone {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        std::ptr::hash(self.data, state);
+    }
+}
+
+impl fmt::Debug for SystemZone {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("SystemZone").field(&self.name()).finish()
+    }
+}
+
+impl fmt::Display for SystemZone {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl SystemOffset {
+    /// Gets the abbreviation in effect (e.g., "EDT")
+    pub fn abbreviation(&self) -> &'static str {
+        self.abbreviation
+    }
+}
+
+impl fmt::Display for SystemOffset {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.abbreviation)
+    }
+}
+
+impl TimeZone for SystemZone {
+    type Offset = SystemOffset;
+
+    fn from_offset(offset: &SystemOffset) -> Self {
+        offset.zone
+    }
+
+    fn offset_from_local_date(&self, local: &NaiveDate) -> LocalResult<SystemOffset> {
+        self.offset_from_local_datetime(&local.and_time(NaiveTime::MIN))
+    }
+
+    fn offset_from_local_datetime(&self, local: &NaiveDateTime) -> LocalResult<SystemOffset> {
+        let data = self.data;
+        let footer_offsets = data.footer.iter().flat_map(|footer| [Some(footer.std_offset()), footer.dst_offset()]).flatten();
+        let mut candidates: Vec<i32> = data.types.iter().map(|local| local.offset).chain(footer_offsets.map(|offset| offset.local_minus_utc())).collect();
+        // Larger offsets give earlier instants
+        candidates.sort_unstable_by(|a, b| b.cmp(a));
+        candidates.dedup();
+
+        // An offset fits the local time if it is the one in effect at the instant it gives
+        let local_seconds = local.and_utc().timestamp();
+        let mut fits: Vec<SystemOffset> = candidates
+            .iter()
+            .map(|offset| self.offset_at(local_seconds - i64::from(*offset)))
+            .filter(|offset| candidates.contains(&offset.offset) && self.offset_at(local_seconds - i64::from(offset.offset)) == *offset)
+            .collect();
+        fits.dedup();
+        match fits[..] {
+            [] => LocalResult::None,
+            [only] => LocalResult::Single(only),
+            [first, .., last] => LocalResult::Ambiguous(first, last),
+        }
+    }
+
+    fn offset_from_utc_date(&self, utc: &NaiveDate) -> SystemOffset {
+        self.offset_from_utc_datetime(&utc.and_time(NaiveTime::MIN))
+    }
+
+    fn offset_from_utc_datetime(&self, utc: &NaiveDateTime) -> SystemOffset {
+        self.offset_at(utc.and_utc().timestamp())
+    }
+}
+
+impl Offset for SystemOffset {
+    fn fix(&self) -> FixedOffset {
+        FixedOffset::east_opt(self.offset).expect("offsets are checked when parsed")
+    }
+}
+
+impl OffsetComponents for SystemOffset {
+    fn base_utc_offset(&self) -> Duration {
+        Duration::seconds((self.offset - self.dst).into())
+    }
+
+    fn dst_offset(&self) -> Duration {
+        Duration::seconds(self.dst.into())
+    }
+}
+
+impl Tzif {
+    /// Parses a TZif file (RFC 8536), using the 64-bit data of version 2 and later files
+    fn parse(name: &str, bytes: &[u8]) -> Result<Self, Errors> {
+        let invalid = |reason: &str| Errors::ParseError(format!("invalid TZif data for {}: {}", name, reason));
+        let mut reader = Reader { bytes };
+        let header = reader.header().ok_or_else(|| invalid("bad header"))?;
+        let (header, time_size) = if header.version >= b'2' {
+            reader.skip(header.data_len(4).unwrap_or(usize::MAX)).ok_or_else(|| invalid("truncated version 1 data"))?;
+            (reader.header().ok_or_else(|| invalid("bad version 2 header"))?, 8)
+        } else {
+            (header, 4)
+        };
+        // The counts come from the file, so check them against its length before allocating
+        if header.data_len(time_size).is_none_or(|len| len > reader.bytes.len()) {
+            return Err(invalid("counts exceed the file's length"));
+        }
+
+        let transitions: Vec<i64> = (0..header.time_count).map(|_| reader.int(time_size)).collect::<Option<_>>().ok_or_else(|| invalid("truncated transitions"))?;
+        let transition_types: Vec<usize> = (0..header.time_count)
+            .map(|_| reader.take(1).map(|b| usize::from(b[0])))
+            .collect::<Option<_>>()
+            .ok_or_else(|| invalid("truncated transition types"))?;
+        let mut raw_types = Vec::with_capacity(header.type_count);
+        for _ in 0..header.type_count {
+            let offset = reader.int(4).ok_or_else(|| invalid("truncated local time types"))?;
+            let flags = reader.take(2).ok_or_else(|| invalid("truncated local time types"))?;
+            raw_types.push((offset as i32, flags[0] != 0, usize::from(flags[1])));
+        }
+        let chars = reader.take(header.char_count).ok_or_else(|| invalid("truncated abbreviations"))?;
+        reader
+            .skip(header.leap_count * (time_size + 4) + header.std_count + header.ut_count)
+            .ok_or_else(|| invalid("truncated indicators"))?;
+
+        let mut types = Vec::with_capacity(raw_types.len());
+        for (offset, is_dst, start) in raw_types {
+            if FixedOffset::east_opt(offset).is_none() {
+                return Err(invalid("offset out of range"));
+            }
+            let rest = chars.get(start..).ok_or_else(|| invalid("abbreviation out of range"))?;
+            let end = rest.iter().position(|b| *b == 0).unwrap_or(rest.len());
+            types.push(LocalType { offset, is_dst, abbreviation: String::from_utf8_lossy(&rest[..end]).into_owned() });
+        }
+        if types.is_empty() || transition_types.iter().any(|i| *i >= types.len()) {
+            return Err(invalid("transition to an unknown local time type"));
+        }
+
+        let footer = match (header.version >= b'2', std::str::from_utf8(reader.bytes)) {
+            (true, Ok(text)) => match text.trim_matches('\n') {
+                "" => None,
+                rule => Some(rule.parse::<PosixTz>()?),
+            },
+            _ => None,
+        };
+        Ok(Self { name: name.to_string(), transitions, transition_types, types, footer, version: None })
+    }
+}
+
+/// The counts in a TZif header
+struct Header {
+    version: u8,
+    ut_count: usize,
+    std_count: usize,
+    leap_count: usize,
+    time_count: usize,
+    type_count: usize,
+    char_count: usize,
+}
+
+impl Header {
+    /// The length of the data block that follows the header, with times of `time_size` bytes,
+    /// or `None` if it overflows
+    fn data_len(&self, time_size: usize) -> Option<usize> {
+        [
+            self.time_count.checked_mul(time_size + 1)?,
+            self.type_count.checked_mul(6)?,
+            self.char_count,
+            self.leap_count.checked_mul(time_size + 4)?,
+            self.std_count,
+            self.ut_count,
+        ]
+        .into_iter()
+        .try_fold(0usize, usize::checked_add)
+    }
+}
+
+/// Reads big-endian TZif fields from the front of a byte slice
+struct Reader<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, len: usize) -> Option<&'a [u8]> {
+        if len > self.bytes.len() {
+            return None;
+        }
+        let (taken, rest) = self.bytes.split_at(len);
+        self.bytes = rest;
+        Some(taken)
+    }
+
+    fn skip(&mut self, len: usize) -> Option<()> {
+        self.take(len).map(|_| ())
+    }
+
+    /// Reads a signed 4- or 8-byte integer
+    fn int(&mut self, size: usize) -> Option<i64> {
+        let bytes = self.take(size)?;
+        Some(match size {
+            4 => i64::from(i32::from_be_bytes(bytes.try_into().ok()?)),
+            _ => i64::from_be_bytes(bytes.try_into().ok()?),
+        })
+    }
+
+    fn header(&mut self) -> Option<Header> {
+        let header = self.take(44)?;
+        if &header[..4] != b"TZif" {
+            return None;
+        }
+        let count = |i: usize| u32::from_be_bytes(header[20 + 4 * i..24 + 4 * i].try_into().unwrap_or_default()) as usize;
+        Some(Header {
+            version: header[4],
+            ut_count: count(0),
+            std_count: count(1),
+            leap_count: count(2),
+            time_count: count(3),
+            type_count: count(4),
+            char_count: count(5),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Datelike, Utc};
+
+    /// Builds a version 2 TZif file with the given transitions, types and footer
+    fn tzif(transitions: &[(i64, u8)], types: &[(i32, bool, &str)], footer: &str) -> Vec<u8> {
+        let mut chars = Vec::new();
+        let mut type_bytes = Vec::new();
+        for (offset, is_dst, abbreviation) in types {
+            type_bytes.extend(offset.to_be_bytes());
+            type_bytes.extend([u8::from(*is_dst), chars.len() as u8]);
+            chars.extend(abbreviation.bytes().chain([0]));
+        }
+        let header = |time_count: usize| {
+            let mut header = b"TZif2".to_vec();
+            header.extend([0; 15]);
+            for count in [0, 0, 0, time_count, types.len(), chars.len()] {
+                header.extend((count as u32).to_be_bytes());
+            }
+            header
+        };
+        // An empty version 1 block, as RFC 8536 allows readers to skip it
+        let mut bytes = header(0);
+        bytes.extend(&type_bytes);
+        bytes.extend(&chars);
+        bytes.extend(header(transitions.len()));
+        bytes.extend(transitions.iter().flat_map(|(at, _)| at.to_be_bytes()));
+        bytes.extend(transitions.iter().map(|(_, i)| *i));
+        bytes.extend(&type_bytes);
+        bytes.extend(&chars);
+        bytes.extend(format!("\n{}\n", footer).bytes());
+        bytes
+    }
+
+    #[test]
+    fn reads_transitions_and_footer() {
+        let march = Utc.with_ymd_and_hms(2023, 3, 12, 7, 0, 0).unwrap().timestamp();
+        let november = Utc.with_ymd_and_hms(2023, 11, 5, 6, 0, 0).unwrap().timestamp();
+        let bytes = tzif(&[(march, 1), (november, 0)], &[(-18000, false, "EST"), (-14400, true, "EDT")], "EST5EDT,M3.2.0,M11.1.0");
+        let zone = SystemZone { data: Box::leak(Box::new(Tzif::parse("Test/Eastern", &bytes).unwrap())) };
+
+        let at = |y, m, d, h| Utc.with_ymd_and_hms(y, m, d, h, 0, 0).unwrap().with_timezone(&zone).to_string();
+        assert_eq!(at(2023, 1, 1, 12), "2023-01-01 07:00:00 EST");
+        assert_eq!(at(2023, 7, 1, 12), "2023-07-01 08:00:00 EDT");
+        // After the last transition, the footer's rule applies
+        assert_eq!(at(2030, 7, 1, 12), "2030-07-01 08:00:00 EDT");
+
+        let summer = Utc.with_ymd_and_hms(2023, 7, 1, 12, 0, 0).unwrap().with_timezone(&zone);
+        assert_eq!(summer.offset().dst_offset(), Duration::hours(1));
+        let ambiguous = NaiveDate::from_ymd_opt(2023, 11, 5).unwrap().and_hms_opt(1, 30, 0).unwrap();
+        let LocalResult::Ambiguous(first, second) = zone.offset_from_local_datetime(&ambiguous) else {
+            panic!("01:30 on 5 November should be ambiguous");
+        };
+        assert_eq!((first.abbreviation(), second.abbreviation()), ("EDT", "EST"));
+        let skipped = NaiveDate::from_ymd_opt(2023, 3, 12).unwrap().and_hms_opt(2, 30, 0).unwrap();
+        assert_eq!(zone.offset_from_local_datetime(&skipped), LocalResult::None);
+
+        assert!(matches!(Tzif::parse("Bad", b"TZif2"), Err(Errors::ParseError(_))));
+        // A type count far beyond the file's length is rejected before anything is allocated
+        let mut huge = bytes.clone();
+        let second_header = huge.windows(4).rposition(|window| window == b"TZif").unwrap();
+        huge[second_header + 36..second_header + 40].copy_from_slice(&u32::MAX.to_be_bytes());
+        assert!(matches!(Tzif::parse("Huge", &huge), Err(Errors::ParseError(reason)) if reason.contains("counts exceed")));
+    }
+
+    #[test]
+    fn looks_zones_up_in_a_directory() {
+        let dir = std::env::temp_dir().join(format!("tzc-zoneinfo-{}", std::process::id()));
+        std::fs::create_dir_all(dir.join("Test")).unwrap();
+        std::fs::write(dir.join("Test/Fixed"), tzif(&[], &[(19800, false, "IST")], "IST-5:30")).unwrap();
+        std::fs::write(dir.join("tzdata.zi"), "# version 2099z\n").unwrap();
+        let tzdb = SystemTzdb::new(&dir);
+
+        let zone = tzdb.zone("Test/Fixed").unwrap();
+        assert_eq!(zone.name(), "Test/Fixed");
+        assert_eq!(zone, tzdb.zone("Test/Fixed").unwrap());
+        assert_eq!(tzdb.zone("+05:30").unwrap().name(), "+05:30");
+        assert!(matches!(tzdb.zone("America/New_York"), Err(Errors::InvalidTimeZone { .. })));
+        assert!(matches!(tzdb.zone("../Test/Fixed"), Err(Errors::InvalidTimeZone { .. })));
+        assert_eq!(tzdb.version().as_deref(), Some("2099z"));
+        let converter = crate::TimeZoneConverter::with_system_tzdb("Test/Fixed", "+05:30", &tzdb).unwrap();
+        assert_eq!(converter.tzdb_version(), "2099z");
+        let info = converter.timezone_info_at(Utc::now(), crate::Zone::Source).unwrap();
+        assert_eq!(info.tzdb_version(), "2099z");
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn matches_the_bundled_database() {
+        let tzdb = SystemTzdb::default();
+        let Ok(ZoneSpec::System(zone)) = tzdb.zone("US/Eastern") else {
+            // No zoneinfo directory on this machine
+            return;
+        };
+        let bundled = chrono_tz::Tz::America__New_York;
+        let mut at = Utc.with_ymd_and_hms(1990, 1, 1, 0, 0, 0).unwrap();
+        while at.year() < 2040 {
+            let expected = bundled.offset_from_utc_datetime(&at.naive_utc()).fix();
+            assert_eq!(zone.offset_from_utc_datetime(&at.naive_utc()).fix(), expected, "{}", at);
+            at += Duration::hours(6);
+        }
+        let converter = crate::TimeZoneConverter::with_system_tzdb("America/New_York", "PST", &tzdb).unwrap();
+        let dt = Utc.with_ymd_and_hms(2024, 7, 1, 12, 0, 0).unwrap();
+        assert_eq!(converter.convert_zoned(dt).unwrap().to_string(), "2024-07-01 05:00:00 PDT");
+    }
+}
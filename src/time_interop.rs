@@ -0,0 +1,116 @@
+//! Conversions that accept and return types from the `time` crate
+
+use chrono::{DateTime, Datelike, NaiveDate, NaiveDateTime, NaiveTime, Offset, Timelike, Utc};
+use time::{Date, Month, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset};
+
+use crate::{resolve_local, Errors, TimeZoneConverter};
+
+impl TimeZoneConverter {
+    /// Converts a `time::OffsetDateTime` to the target timezone
+    /// 
+    /// The instant is carried over with full nanosecond precision, and the result's offset
+    /// is the target timezone's offset at that instant.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `dt` - The instant to convert, with any offset
+    /// 
+    /// # Returns
+    /// 
+    /// * `Result<OffsetDateTime, Errors>` - The same instant at the target timezone's offset, or an error if it is out of range
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use time::macros::datetime;
+    /// use timezone_converter::TimeZoneConverter;
+    /// 
+    /// let converter = TimeZoneConverter::new("UTC", "Asia/Kolkata").unwrap();
+    /// let converted = converter.convert_offset_datetime(datetime!(2024-07-01 12:00:00.123456789 UTC)).unwrap();
+    /// assert_eq!(converted, datetime!(2024-07-01 17:30:00.123456789 +05:30));
+    /// ```
+    pub fn convert_offset_datetime(&self, dt: OffsetDateTime) -> Result<OffsetDateTime, Errors> {
+        let converted = offset_datetime_to_utc(dt)?.with_timezone(&self.target_tz);
+        let offset = UtcOffset::from_whole_seconds(converted.offset().fix().local_minus_utc())
+            .map_err(|err| Errors::ConversionError(err.to_string()))?;
+        Ok(dt.to_offset(offset))
+    }
+
+    /// Converts a source-local `time::PrimitiveDateTime` to the target timezone's wall clock
+    /// 
+    /// Times skipped or repeated by a source-zone DST transition are resolved with the
+    /// converter's [`crate::AmbiguityPolicy`].
+    /// 
+    /// # Arguments
+    /// 
+    /// * `dt` - The wall-clock time in the source timezone
+    /// 
+    /// # Returns
+    /// 
+    /// * `Result<PrimitiveDateTime, Errors>` - The wall-clock time in the target timezone
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use time::macros::datetime;
+    /// use timezone_converter::TimeZoneConverter;
+    /// 
+    /// let converter = TimeZoneConverter::new("America/New_York", "Europe/London").unwrap();
+    /// let converted = converter.convert_primitive(datetime!(2024-11-04 10:00:00.5)).unwrap();
+    /// assert_eq!(converted, datetime!(2024-11-04 15:00:00.5));
+    /// ```
+    pub fn convert_primitive(&self, dt: PrimitiveDateTime) -> Result<PrimitiveDateTime, Errors> {
+        let converted = resolve_local(&self.source_tz, primitive_to_naive(dt)?, self.ambiguity_policy)?;
+        naive_to_primitive(converted.with_timezone(&self.target_tz).naive_local())
+    }
+}
+
+/// Converts a `time` instant to a chrono one
+fn offset_datetime_to_utc(dt: OffsetDateTime) -> Result<DateTime<Utc>, Errors> {
+    DateTime::from_timestamp(dt.unix_timestamp(), dt.nanosecond())
+        .ok_or_else(|| Errors::ConversionError(format!("{} is out of range", dt)))
+}
+
+/// Converts a `time` wall-clock time to a chrono one
+fn primitive_to_naive(dt: PrimitiveDateTime) -> Result<NaiveDateTime, Errors> {
+    let date = NaiveDate::from_ymd_opt(dt.year(), u8::from(dt.month()) as u32, dt.day() as u32);
+    let time = NaiveTime::from_hms_nano_opt(dt.hour() as u32, dt.minute() as u32, dt.second() as u32, dt.nanosecond());
+    date.zip(time)
+        .map(|(date, time)| date.and_time(time))
+        .ok_or_else(|| Errors::ConversionError(format!("{} is out of range", dt)))
+}
+
+/// Converts a chrono wall-clock time to a `time` one
+fn naive_to_primitive(naive: NaiveDateTime) -> Result<PrimitiveDateTime, Errors> {
+    let error = |err: time::error::ComponentRange| Errors::ConversionError(err.to_string());
+    let month = Month::try_from(naive.month() as u8).map_err(error)?;
+    let date = Date::from_calendar_date(naive.year(), month, naive.day() as u8).map_err(error)?;
+    // `time` has no leap seconds, so one is folded into the second before it
+    let nanosecond = naive.nanosecond().min(999_999_999);
+    let time = Time::from_hms_nano(naive.hour() as u8, naive.minute() as u8, naive.second() as u8, nanosecond).map_err(error)?;
+    Ok(PrimitiveDateTime::new(date, time))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use time::macros::datetime;
+
+    #[test]
+    fn keeps_nanoseconds_across_offsets() {
+        let timezone = TimeZoneConverter::new("UTC", "America/St_Johns").unwrap();
+        let dt = datetime!(2024-01-15 12:00:00.000000001 +09:00);
+        let converted = timezone.convert_offset_datetime(dt).unwrap();
+        assert_eq!(converted, dt);
+        assert_eq!(converted.offset(), UtcOffset::from_hms(-3, -30, 0).unwrap());
+        assert_eq!(converted.nanosecond(), 1);
+    }
+
+    #[test]
+    fn primitive_follows_ambiguity_policy() {
+        let timezone = TimeZoneConverter::new("America/New_York", "UTC").unwrap();
+        // 01:30 happens twice on Nov 3 2024; the default policy picks the earlier (EDT) one
+        assert_eq!(timezone.convert_primitive(datetime!(2024-11-03 01:30)).unwrap(), datetime!(2024-11-03 05:30));
+        assert_eq!(timezone.convert_primitive(datetime!(2024-02-29 23:59:59.999)).unwrap(), datetime!(2024-03-01 04:59:59.999));
+    }
+}
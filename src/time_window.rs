@@ -0,0 +1,197 @@
+//! Daily local-time windows, such as quiet hours, checked in either timezone
+
+use chrono::{DateTime, LocalResult, NaiveDate, NaiveTime, TimeZone as ChronoTimeZone, Utc};
+use chrono_tz::Tz;
+
+use crate::{previous_offset_change, resolve_local_forward, Errors, TimeZoneConverter, Zone, ZoneSpec};
+
+/// The most days searched for a window's next opening, enough for a local day skipped by a zone moving across the date line
+const MAX_DAYS_TO_OPEN: u32 = 3;
+
+/// A span of wall-clock time that recurs every day, such as 22:00 to 07:00
+/// 
+/// The window includes its start and excludes its end. A start later than the end
+/// wraps past midnight, and equal times cover the whole day.
+/// 
+/// # Example
+/// 
+/// ```rust
+/// use chrono::NaiveTime;
+/// use timezone_converter::LocalTimeWindow;
+/// 
+/// let at = |h| NaiveTime::from_hms_opt(h, 0, 0).unwrap();
+/// let quiet = LocalTimeWindow::new(at(22), at(7));
+/// assert!(quiet.contains(at(23)) && quiet.contains(at(6)));
+/// assert!(!quiet.contains(at(7)) && !quiet.contains(at(12)));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct LocalTimeWindow {
+    /// The wall-clock time the window opens, inclusive
+    start: NaiveTime,
+    /// The wall-clock time the window closes, exclusive
+    end: NaiveTime,
+}
+
+impl LocalTimeWindow {
+    /// Creates a window from its opening and closing wall-clock times
+    /// 
+    /// # Arguments
+    /// 
+    /// * `start` - The time the window opens, inclusive (e.g., 22:00)
+    /// * `end` - The time the window closes, exclusive (e.g., 07:00)
+    /// 
+    /// # Returns
+    /// 
+    /// * `LocalTimeWindow` - The window
+    pub fn new(start: NaiveTime, end: NaiveTime) -> Self {
+        Self { start, end }
+    }
+
+    /// Gets the time the window opens
+    pub fn start(&self) -> NaiveTime {
+        self.start
+    }
+
+    /// Gets the time the window closes
+    pub fn end(&self) -> NaiveTime {
+        self.end
+    }
+
+    /// Whether a wall-clock time is inside the window
+    pub fn contains(&self, time: NaiveTime) -> bool {
+        if self.start < self.end {
+            self.start <= time && time < self.end
+        } else {
+            // Wraps past midnight, or covers the whole day when the times are equal
+            self.start <= time || time < self.end
+        }
+    }
+}
+
+impl TimeZoneConverter {
+    /// Whether an instant is inside a daily window of wall-clock time in either timezone
+    /// 
+    /// # Arguments
+    /// 
+    /// * `window` - The window (e.g., 22:00 to 07:00)
+    /// * `at` - The instant to check
+    /// * `zone` - The timezone whose wall clock the window follows
+    /// 
+    /// # Returns
+    /// 
+    /// * `bool` - Whether the local time at `at` is inside the window
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use chrono::{NaiveTime, TimeZone, Utc};
+    /// use timezone_converter::{LocalTimeWindow, TimeZoneConverter, Zone};
+    /// 
+    /// let converter = TimeZoneConverter::new("UTC", "Australia/Sydney").unwrap();
+    /// let quiet = LocalTimeWindow::new(NaiveTime::from_hms_opt(22, 0, 0).unwrap(), NaiveTime::from_hms_opt(7, 0, 0).unwrap());
+    /// // 15:00 UTC is 01:00 the next morning in Sydney
+    /// let at = Utc.with_ymd_and_hms(2024, 7, 1, 15, 0, 0).unwrap();
+    /// assert!(converter.is_within(quiet, at, Zone::Target));
+    /// assert!(!converter.is_within(quiet, at, Zone::Source));
+    /// ```
+    pub fn is_within(&self, window: LocalTimeWindow, at: DateTime<Utc>, zone: Zone) -> bool {
+        window.contains(at.with_timezone(self.zone(zone)).time())
+    }
+
+    /// Finds when a daily window of wall-clock time next opens in either timezone
+    /// 
+    /// An instant already inside the window is returned as it is, so non-urgent work can
+    /// be deferred to the result unconditionally. If the opening time is skipped by a DST
+    /// transition, the window opens when the clocks jump past it; if it occurs twice, at its
+    /// first occurrence.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `window` - The window (e.g., 07:00 to 22:00)
+    /// * `from` - The instant to search from
+    /// * `zone` - The timezone whose wall clock the window follows
+    /// 
+    /// # Returns
+    /// 
+    /// * `Result<DateTime<Tz>, Errors>` - The first instant at or after `from` inside the window, in that timezone, or an error if it is out of range or the zone is not a tz database zone
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use chrono::{NaiveTime, TimeZone, Utc};
+    /// use timezone_converter::{LocalTimeWindow, TimeZoneConverter, Zone};
+    /// 
+    /// let converter = TimeZoneConverter::new("UTC", "Europe/Berlin").unwrap();
+    /// let awake = LocalTimeWindow::new(NaiveTime::from_hms_opt(7, 0, 0).unwrap(), NaiveTime::from_hms_opt(22, 0, 0).unwrap());
+    /// let alert = Utc.with_ymd_and_hms(2024, 7, 1, 23, 0, 0).unwrap();
+    /// let deliver = converter.next_window_open(awake, alert, Zone::Target).unwrap();
+    /// assert_eq!(deliver.to_string(), "2024-07-02 07:00:00 CEST");
+    /// ```
+    pub fn next_window_open(&self, window: LocalTimeWindow, from: DateTime<Utc>, zone: Zone) -> Result<DateTime<Tz>, Errors> {
+        let named = self.named_tz(zone)?;
+        let tz = self.zone(zone);
+        if self.is_within(window, from, zone) {
+            return Ok(from.with_timezone(&named));
+        }
+        let mut date = from.with_timezone(tz).date_naive();
+        for _ in 0..MAX_DAYS_TO_OPEN {
+            let opening = window_opening(tz, date, window.start)?;
+            if opening >= from {
+                return Ok(opening.with_timezone(&named));
+            }
+            date = date.succ_opt().ok_or_else(|| Errors::ConversionError(format!("no day follows {}", date)))?;
+        }
+        Err(Errors::ConversionError(format!("the window does not open within {} days of {}", MAX_DAYS_TO_OPEN, from)))
+    }
+}
+
+/// Finds the instant the wall clock first reaches a time on a local date
+fn window_opening(tz: &ZoneSpec, date: NaiveDate, time: NaiveTime) -> Result<DateTime<Utc>, Errors> {
+    let naive = date.and_time(time);
+    let resolved = resolve_local_forward(tz, naive)?.with_timezone(&Utc);
+    if matches!(tz.from_local_datetime(&naive), LocalResult::None) {
+        // The time was skipped, so the clock first passes it when it jumps over the gap
+        return Ok(previous_offset_change(tz, resolved).unwrap_or(resolved));
+    }
+    Ok(resolved)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn window(start: (u32, u32), end: (u32, u32)) -> LocalTimeWindow {
+        LocalTimeWindow::new(NaiveTime::from_hms_opt(start.0, start.1, 0).unwrap(), NaiveTime::from_hms_opt(end.0, end.1, 0).unwrap())
+    }
+
+    #[test]
+    fn checks_quiet_hours_in_either_zone() {
+        let converter = TimeZoneConverter::new("America/Los_Angeles", "Asia/Kolkata").unwrap();
+        let quiet = window((22, 0), (7, 0));
+        let at = Utc.with_ymd_and_hms(2024, 7, 1, 6, 0, 0).unwrap();
+        assert!(converter.is_within(quiet, at, Zone::Source));
+        assert!(!converter.is_within(quiet, at, Zone::Target));
+        assert!(converter.is_within(window((9, 0), (9, 0)), at, Zone::Source));
+
+        let opens = converter.next_window_open(quiet, at, Zone::Target).unwrap();
+        assert_eq!(opens.to_string(), "2024-07-01 22:00:00 IST");
+        assert_eq!(converter.next_window_open(quiet, at, Zone::Source).unwrap().with_timezone(&Utc), at);
+
+        let converter = TimeZoneConverter::new("America/Los_Angeles", "+05:30").unwrap();
+        assert!(converter.is_within(quiet, at, Zone::Source));
+        assert!(matches!(converter.next_window_open(quiet, at, Zone::Target), Err(Errors::ConversionError(_))));
+    }
+
+    #[test]
+    fn opens_after_a_skipped_start() {
+        let converter = TimeZoneConverter::new("UTC", "America/New_York").unwrap();
+        let from = Utc.with_ymd_and_hms(2024, 3, 10, 5, 0, 0).unwrap();
+        // 02:30 is skipped on Mar 10, so the window opens as the clocks jump to 03:00
+        let opens = converter.next_window_open(window((2, 30), (5, 0)), from, Zone::Target).unwrap();
+        assert_eq!(opens.to_string(), "2024-03-10 03:00:00 EDT");
+        assert!(converter.is_within(window((2, 30), (5, 0)), opens.with_timezone(&Utc), Zone::Target));
+
+        let repeated = converter.next_window_open(window((1, 30), (5, 0)), Utc.with_ymd_and_hms(2024, 11, 3, 4, 0, 0).unwrap(), Zone::Target).unwrap();
+        assert_eq!(repeated.to_rfc3339(), "2024-11-03T01:30:00-04:00");
+    }
+}
@@ -0,0 +1,136 @@
+//! JavaScript bindings built with `wasm-bindgen`
+
+use chrono::{DateTime, NaiveDateTime, SecondsFormat, Utc};
+use wasm_bindgen::prelude::*;
+
+use crate::{Errors, TimeZoneConverter};
+
+/// A [`TimeZoneConverter`] exported to JavaScript as `TimeZoneConverter`
+/// 
+/// Instants cross the boundary as milliseconds since the Unix epoch, the value of
+/// `Date.prototype.getTime()`, and come back as RFC 3339 strings carrying the target offset.
+/// 
+/// ```js
+/// import { TimeZoneConverter, listTimezones } from "timezone-converter";
+/// 
+/// const converter = new TimeZoneConverter("America/New_York", "Europe/London");
+/// converter.convert(Date.now());                    // "2024-11-04T15:00:00.000+00:00"
+/// converter.convertLocal("2024-11-04T10:00");        // "2024-11-04T15:00:00.000+00:00"
+/// converter.convertFormatted(Date.now(), "%H:%M");   // "15:00"
+/// ```
+#[wasm_bindgen(js_name = TimeZoneConverter)]
+#[derive(Debug)]
+pub struct WasmConverter {
+    /// The converter every call is delegated to
+    inner: TimeZoneConverter,
+}
+
+#[wasm_bindgen(js_class = TimeZoneConverter)]
+impl WasmConverter {
+    /// Creates a converter between two IANA timezone identifiers
+    /// 
+    /// Throws an `Error` listing suggestions if either identifier is invalid.
+    #[wasm_bindgen(constructor)]
+    pub fn new(source: &str, target: &str) -> Result<WasmConverter, JsError> {
+        let inner = TimeZoneConverter::new(source, target).map_err(js_error)?;
+        Ok(Self { inner })
+    }
+
+    /// The source timezone identifier
+    #[wasm_bindgen(getter)]
+    pub fn source(&self) -> String {
+        self.inner.source_tz.name().to_string()
+    }
+
+    /// The target timezone identifier
+    #[wasm_bindgen(getter)]
+    pub fn target(&self) -> String {
+        self.inner.target_tz.name().to_string()
+    }
+
+    /// Converts an instant, in epoch milliseconds, to an RFC 3339 string in the target timezone
+    pub fn convert(&self, millis: f64) -> Result<String, JsError> {
+        let converted = millis_to_utc(millis).map_err(js_error)?.with_timezone(&self.inner.target_tz);
+        Ok(converted.to_rfc3339_opts(SecondsFormat::Millis, false))
+    }
+
+    /// Converts a source-local wall-clock time such as `"2024-11-04T10:00"` to an RFC 3339 string in the target timezone
+    /// 
+    /// Skipped and repeated times are resolved with the converter's ambiguity policy.
+    #[wasm_bindgen(js_name = convertLocal)]
+    pub fn convert_local(&self, local: &str) -> Result<String, JsError> {
+        let naive = parse_local(local).map_err(js_error)?;
+        let result = self.inner.convert_checked(naive).map_err(js_error)?;
+        Ok(result.datetime.to_rfc3339_opts(SecondsFormat::Millis, false))
+    }
+
+    /// Converts an instant, in epoch milliseconds, to the target timezone and formats it with a `chrono` strftime format
+    #[wasm_bindgen(js_name = convertFormatted)]
+    pub fn convert_formatted(&self, millis: f64, format: &str) -> Result<String, JsError> {
+        let utc = millis_to_utc(millis).map_err(js_error)?;
+        self.inner.convert_formatted(utc, format).map_err(js_error)
+    }
+
+    /// Gets the source offset minus the target offset, in seconds, at an instant in epoch milliseconds
+    #[wasm_bindgen(js_name = differenceSeconds)]
+    pub fn difference_seconds(&self, millis: f64) -> Result<f64, JsError> {
+        let utc = millis_to_utc(millis).map_err(js_error)?;
+        Ok(self.inner.time_difference_at(utc).num_seconds() as f64)
+    }
+}
+
+/// Lists every IANA timezone identifier the converter accepts, in alphabetical order
+#[wasm_bindgen(js_name = listTimezones)]
+pub fn list_timezones() -> Vec<String> {
+    let mut names: Vec<String> = chrono_tz::TZ_VARIANTS.iter().map(|tz| tz.name().to_string()).collect();
+    names.sort_unstable();
+    names
+}
+
+/// Converts epoch milliseconds from JavaScript, which may be fractional, into an instant
+fn millis_to_utc(millis: f64) -> Result<DateTime<Utc>, Errors> {
+    if !millis.is_finite() {
+        return Err(Errors::ConversionError(format!("{} is not a valid time", millis)));
+    }
+    let nanos = (millis.rem_euclid(1000.0) * 1_000_000.0) as u32;
+    DateTime::from_timestamp(millis.div_euclid(1000.0) as i64, nanos.min(999_999_999))
+        .ok_or_else(|| Errors::ConversionError(format!("{} is out of range", millis)))
+}
+
+/// Parses a wall-clock time in the forms produced by `<input type="datetime-local">`
+fn parse_local(local: &str) -> Result<NaiveDateTime, Errors> {
+    ["%Y-%m-%dT%H:%M", "%Y-%m-%dT%H:%M:%S%.f", "%Y-%m-%d %H:%M", "%Y-%m-%d %H:%M:%S%.f"]
+        .iter()
+        .find_map(|format| NaiveDateTime::parse_from_str(local, format).ok())
+        .ok_or_else(|| Errors::ParseError(format!("'{}' is not a local date and time", local)))
+}
+
+/// Converts an error into a JavaScript `Error`
+fn js_error(err: Errors) -> JsError {
+    JsError::new(&err.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn converts_like_the_library() {
+        let converter = WasmConverter::new("America/New_York", "Europe/London").unwrap();
+        assert_eq!(converter.source(), "America/New_York");
+        assert_eq!(converter.convert(1_730_732_400_250.0).unwrap(), "2024-11-04T15:00:00.250+00:00");
+        assert_eq!(converter.convert_local("2024-11-04T10:00").unwrap(), "2024-11-04T15:00:00.000+00:00");
+        assert_eq!(converter.convert_formatted(1_730_732_400_000.0, "%H:%M %Z").unwrap(), "15:00 GMT");
+        assert_eq!(converter.difference_seconds(1_730_732_400_000.0).unwrap(), -18000.0);
+    }
+
+    #[test]
+    fn helpers() {
+        assert_eq!(millis_to_utc(-1.5).unwrap().to_rfc3339(), "1969-12-31T23:59:59.998500+00:00");
+        assert!(millis_to_utc(f64::NAN).is_err());
+        assert!(parse_local("2024-11-04").is_err());
+        let names = list_timezones();
+        assert!(names.windows(2).all(|pair| pair[0] < pair[1]));
+        assert!(names.iter().any(|name| name == "Asia/Tokyo"));
+    }
+}
@@ -0,0 +1,148 @@
+//! Mapping between Windows timezone names and IANA zones, using the CLDR `windowsZones` data
+
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use chrono_tz::Tz;
+
+use crate::aliases::canonical_zone;
+use crate::{edit_distance, offset_seconds_at, tab_rows, Errors, ZONE_TAB};
+
+/// The CLDR mapping: Windows name, territory and zone
+const WINDOWS_ZONES_TAB: &str = include_str!("../data/windows_zones.tab");
+
+/// The territory CLDR uses for the zone a Windows name stands for by default
+const DEFAULT_TERRITORY: &str = "001";
+
+/// How often offsets are compared when matching a zone CLDR does not list to a Windows name
+const MATCH_SAMPLE_HOURS: i64 = 12;
+
+/// The years over which offsets are compared, so the match does not depend on the clock
+const MATCH_YEARS: std::ops::Range<i32> = 2025..2028;
+
+/// Finds the IANA zone a Windows timezone name stands for by default
+/// 
+/// Names are matched case-insensitively, so the `StandardName` or registry key name found
+/// in Exchange and Outlook data (e.g. "Eastern Standard Time") can be passed as is.
+/// 
+/// # Arguments
+/// 
+/// * `name` - The Windows timezone ID (e.g., "W. Europe Standard Time")
+/// 
+/// # Returns
+/// 
+/// * `Result<Tz, Errors>` - The zone, or `Errors::InvalidTimeZone` with close Windows names if `name` is unknown
+/// 
+/// # Example
+/// 
+/// ```rust
+/// use chrono_tz::Tz;
+/// use timezone_converter::from_windows_name;
+/// 
+/// assert_eq!(from_windows_name("Eastern Standard Time").unwrap(), Tz::America__New_York);
+/// assert_eq!(from_windows_name("india standard time").unwrap(), Tz::Asia__Kolkata);
+/// ```
+pub fn from_windows_name(name: &str) -> Result<Tz, Errors> {
+    let name = name.trim();
+    mappings()
+        .find(|(windows, territory, _)| *territory == DEFAULT_TERRITORY && windows.eq_ignore_ascii_case(name))
+        .map(|(_, _, tz)| tz)
+        .ok_or_else(|| Errors::InvalidTimeZone {
+            input: name.to_string(),
+            suggestions: windows_suggestions(name),
+        })
+}
+
+/// Finds the Windows timezone name for an IANA zone
+/// 
+/// Zones CLDR lists are mapped as it says. Any other zone is given the first Windows name
+/// used in its country whose zone keeps the same UTC offsets over the coming years.
+/// 
+/// # Arguments
+/// 
+/// * `tz` - The IANA zone; legacy names such as `US/Eastern` are resolved first
+/// 
+/// # Returns
+/// 
+/// * `Option<&'static str>` - The Windows timezone ID, or `None` if no Windows zone matches
+/// 
+/// # Example
+/// 
+/// ```rust
+/// use chrono_tz::Tz;
+/// use timezone_converter::to_windows_name;
+/// 
+/// assert_eq!(to_windows_name(Tz::Europe__Paris), Some("Romance Standard Time"));
+/// assert_eq!(to_windows_name(Tz::America__Detroit), Some("Eastern Standard Time"));
+/// ```
+pub fn to_windows_name(tz: Tz) -> Option<&'static str> {
+    let (tz, _) = canonical_zone(tz);
+    let listed = mappings()
+        .filter(|(_, _, zone)| *zone == tz)
+        .min_by_key(|(_, territory, _)| *territory != DEFAULT_TERRITORY);
+    if let Some((windows, _, _)) = listed {
+        return Some(windows);
+    }
+
+    let country = tab_rows(ZONE_TAB).find(|fields| fields.get(2) == Some(&tz.name()))?[0];
+    mappings()
+        .filter(|(_, territory, _)| *territory == country)
+        .find(|(_, _, zone)| same_offsets(tz, *zone))
+        .map(|(windows, _, _)| windows)
+}
+
+/// The rows of the CLDR mapping whose zones the compiled-in tz database knows
+fn mappings() -> impl Iterator<Item = (&'static str, &'static str, Tz)> {
+    tab_rows(WINDOWS_ZONES_TAB).filter_map(|fields| Some((fields[0], *fields.get(1)?, fields.get(2)?.parse().ok()?)))
+}
+
+/// Whether two zones have the same UTC offset at every sample over [`MATCH_YEARS`]
+fn same_offsets(a: Tz, b: Tz) -> bool {
+    let start = Utc.with_ymd_and_hms(MATCH_YEARS.start, 1, 1, 0, 0, 0).unwrap();
+    let end = Utc.with_ymd_and_hms(MATCH_YEARS.end, 1, 1, 0, 0, 0).unwrap();
+    let samples = (end - start).num_hours() / MATCH_SAMPLE_HOURS;
+    (0..samples)
+        .map(|i| start + Duration::hours(i * MATCH_SAMPLE_HOURS))
+        .all(|at: DateTime<Utc>| offset_seconds_at(&a, at) == offset_seconds_at(&b, at))
+}
+
+/// Finds the Windows names closest to an unknown one
+fn windows_suggestions(name: &str) -> Vec<String> {
+    let query = name.to_lowercase();
+    let max_distance = (query.chars().count() / 4).max(1);
+    let mut ranked: Vec<(usize, &str)> = mappings()
+        .filter(|(_, territory, _)| *territory == DEFAULT_TERRITORY)
+        .map(|(windows, _, _)| (edit_distance(&query, &windows.to_lowercase()), windows))
+        .filter(|(distance, _)| *distance <= max_distance)
+        .collect();
+    ranked.sort_unstable();
+    ranked.into_iter().take(5).map(|(_, windows)| windows.to_string()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn maps_windows_names_to_zones() {
+        assert_eq!(from_windows_name(" W. Europe Standard Time ").unwrap(), Tz::Europe__Berlin);
+        assert_eq!(from_windows_name("UTC").unwrap(), Tz::Etc__UTC);
+        assert_eq!(from_windows_name("Dateline Standard Time").unwrap(), Tz::Etc__GMTPlus12);
+        match from_windows_name("Estern Standard Time").unwrap_err() {
+            Errors::InvalidTimeZone { suggestions, .. } => assert_eq!(suggestions[0], "Eastern Standard Time"),
+            other => panic!("unexpected error {:?}", other),
+        }
+    }
+
+    #[test]
+    fn maps_zones_to_windows_names() {
+        assert_eq!(to_windows_name(Tz::America__New_York), Some("Eastern Standard Time"));
+        assert_eq!(to_windows_name(Tz::Europe__Amsterdam), Some("W. Europe Standard Time"));
+        assert_eq!(to_windows_name(Tz::US__Pacific), Some("Pacific Standard Time"));
+        // Büsingen is in Germany and keeps Berlin's offsets, though CLDR lists only Berlin there
+        assert_eq!(to_windows_name(Tz::Europe__Busingen), Some("W. Europe Standard Time"));
+        assert_eq!(to_windows_name(Tz::Antarctica__Troll), None);
+
+        for (windows, territory, tz) in mappings().filter(|(_, territory, _)| *territory == DEFAULT_TERRITORY) {
+            assert_eq!(from_windows_name(windows).unwrap(), tz, "{} {}", windows, territory);
+        }
+    }
+}
@@ -0,0 +1,212 @@
+//! A world clock over a set of labelled timezones
+
+use chrono::{DateTime, Duration, Offset, Utc};
+use chrono_tz::Tz;
+
+use crate::{parse_tz, Errors};
+
+/// A set of labelled timezones whose current times can be read together
+#[derive(Debug, Clone, Default)]
+pub struct WorldClock {
+    /// The labelled timezones, in the order they were added
+    zones: Vec<(String, Tz)>,
+}
+
+/// The time in one of a [`WorldClock`]'s zones at a particular instant
+#[derive(Debug, Clone, PartialEq)]
+pub struct ClockReading {
+    /// The label the zone was added with (e.g., "London office")
+    pub label: String,
+    /// The timezone
+    pub zone: Tz,
+    /// The local time in the zone
+    pub time: DateTime<Tz>,
+    /// The zone's offset from UTC at that instant
+    pub offset: Duration,
+}
+
+impl WorldClock {
+    /// Creates an empty WorldClock
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a labelled timezone to the clock
+    /// 
+    /// # Arguments
+    /// 
+    /// * `label` - The display label for the zone (e.g., "Tokyo office")
+    /// * `zone` - The timezone identifier (e.g., "Asia/Tokyo")
+    /// 
+    /// # Returns
+    /// 
+    /// * `Result<&mut WorldClock, Errors>` - The clock, for chaining, or an error if `zone` is invalid
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use timezone_converter::WorldClock;
+    /// 
+    /// let mut clock = WorldClock::new();
+    /// clock.add("London", "Europe/London").unwrap()
+    ///     .add("Tokyo", "Asia/Tokyo").unwrap();
+    /// assert_eq!(clock.len(), 2);
+    /// ```
+    pub fn add(&mut self, label: &str, zone: &str) -> Result<&mut Self, Errors> {
+        let tz = parse_tz(zone)?;
+        self.zones.push((label.to_string(), tz));
+        Ok(self)
+    }
+
+    /// Gets the number of zones on the clock
+    pub fn len(&self) -> usize {
+        self.zones.len()
+    }
+
+    /// Whether the clock has no zones
+    pub fn is_empty(&self) -> bool {
+        self.zones.is_empty()
+    }
+
+    /// Gets the current time in every zone, in the order the zones were added
+    /// 
+    /// # Returns
+    /// 
+    /// * `Vec<ClockReading>` - One reading per zone, all for the same instant
+    pub fn now(&self) -> Vec<ClockReading> {
+        self.at(Utc::now())
+    }
+
+    /// Gets the time in every zone at an instant, in the order the zones were added
+    /// 
+    /// # Arguments
+    /// 
+    /// * `instant` - The instant to read the clock at
+    /// 
+    /// # Returns
+    /// 
+    /// * `Vec<ClockReading>` - One reading per zone
+    pub fn at(&self, instant: DateTime<Utc>) -> Vec<ClockReading> {
+        self.zones
+            .iter()
+            .map(|(label, zone)| {
+                let time = instant.with_timezone(zone);
+                let offset = Duration::seconds(time.offset().fix().local_minus_utc() as i64);
+                ClockReading {
+                    label: label.clone(),
+                    zone: *zone,
+                    time,
+                    offset,
+                }
+            })
+            .collect()
+    }
+
+    /// Gets the time in every zone at an instant, ordered from the westernmost offset to the easternmost
+    /// 
+    /// Zones with equal offsets keep the order they were added in.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `instant` - The instant to read the clock at
+    /// 
+    /// # Returns
+    /// 
+    /// * `Vec<ClockReading>` - One reading per zone, sorted by offset
+    pub fn sorted_by_offset(&self, instant: DateTime<Utc>) -> Vec<ClockReading> {
+        let mut readings = self.at(instant);
+        readings.sort_by_key(|reading| reading.offset);
+        readings
+    }
+
+    /// Gets the time in every zone at an instant, grouping zones that currently show the same local time
+    /// 
+    /// Groups are ordered by offset, and zones within a group keep the order they were added in.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `instant` - The instant to read the clock at
+    /// 
+    /// # Returns
+    /// 
+    /// * `Vec<Vec<ClockReading>>` - The readings, one group per distinct offset
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use chrono::{TimeZone, Utc};
+    /// use timezone_converter::WorldClock;
+    /// 
+    /// let mut clock = WorldClock::new();
+    /// clock.add("Paris", "Europe/Paris").unwrap()
+    ///     .add("New York", "America/New_York").unwrap()
+    ///     .add("Berlin", "Europe/Berlin").unwrap();
+    /// 
+    /// let at = Utc.with_ymd_and_hms(2024, 7, 1, 12, 0, 0).unwrap();
+    /// let groups = clock.grouped_by_local_time(at);
+    /// assert_eq!(groups.len(), 2);
+    /// assert_eq!(groups[1].len(), 2);
+    /// ```
+    pub fn grouped_by_local_time(&self, instant: DateTime<Utc>) -> Vec<Vec<ClockReading>> {
+        let mut groups: Vec<Vec<ClockReading>> = Vec::new();
+        for reading in self.sorted_by_offset(instant) {
+            match groups.last_mut() {
+                Some(group) if group[0].offset == reading.offset => group.push(reading),
+                _ => groups.push(vec![reading]),
+            }
+        }
+        groups
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn office_clock() -> WorldClock {
+        let mut clock = WorldClock::new();
+        clock.add("Tokyo", "Asia/Tokyo").unwrap()
+            .add("New York", "America/New_York").unwrap()
+            .add("London", "Europe/London").unwrap()
+            .add("Lisbon", "Europe/Lisbon").unwrap()
+            .add("Toronto", "America/Toronto").unwrap();
+        clock
+    }
+
+    #[test]
+    fn readings_in_insertion_order() {
+        let mut clock = office_clock();
+        let at = Utc.with_ymd_and_hms(2024, 1, 15, 12, 0, 0).unwrap();
+        let readings = clock.at(at);
+        assert_eq!(readings.len(), 5);
+        assert_eq!(readings[0].label, "Tokyo");
+        assert_eq!(readings[0].time.to_string(), "2024-01-15 21:00:00 JST");
+        assert_eq!(readings[1].offset, Duration::hours(-5));
+        assert!(readings.iter().all(|reading| reading.time == at));
+
+        assert!(clock.add("Nowhere", "Nowhere/City").is_err());
+        assert_eq!(clock.now().len(), 5);
+        assert!(WorldClock::new().is_empty());
+    }
+
+    #[test]
+    fn sorted_and_grouped() {
+        let clock = office_clock();
+        let at = Utc.with_ymd_and_hms(2024, 7, 1, 12, 0, 0).unwrap();
+
+        let labels: Vec<String> = clock.sorted_by_offset(at).into_iter().map(|r| r.label).collect();
+        assert_eq!(labels, ["New York", "Toronto", "London", "Lisbon", "Tokyo"]);
+
+        let groups: Vec<Vec<String>> = clock
+            .grouped_by_local_time(at)
+            .into_iter()
+            .map(|group| group.into_iter().map(|r| r.label).collect())
+            .collect();
+        assert_eq!(groups, vec![
+            vec!["New York".to_string(), "Toronto".to_string()],
+            vec!["London".to_string(), "Lisbon".to_string()],
+            vec!["Tokyo".to_string()],
+        ]);
+    }
+}
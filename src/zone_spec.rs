@@ -0,0 +1,381 @@
+//! Timezones given either as IANA identifiers or as literal UTC offsets
+
+use std::borrow::Cow;
+use std::fmt;
+use std::str::FromStr;
+
+use chrono::{Duration, FixedOffset, LocalResult, NaiveDate, NaiveDateTime, Offset, TimeZone};
+use chrono_tz::{OffsetComponents, OffsetName, Tz, TzOffset};
+
+use crate::abbreviations::abbreviation_identifier;
+use crate::aliases::{canonical_zone, offset_name_zone};
+#[cfg(feature = "system-tz")]
+use crate::{SystemOffset, SystemZone};
+use crate::{parse_tz, resolve_abbreviation, CustomOffset, CustomZone, Errors, PosixOffset, PosixTz};
+
+/// A timezone a converter can convert from or to
+///
+/// Legacy identifiers such as `US/Eastern` are resolved to their canonical zones (see
+/// [`crate::canonicalize`]). Besides IANA identifiers, literal offsets such as `"+05:30"`,
+/// `"-0700"` or `"GMT+5:45"` are accepted, for data that records only an offset. A fixed
+/// offset never observes DST. Whole-hour offset names such as `"GMT+5"` or `"UTC-7"` resolve
+/// to the matching `Etc/` zone, as [`crate::canonicalize`] does. POSIX TZ rule strings such
+/// as `"EST5EDT,M3.2.0,M11.1.0"` are accepted too (see [`PosixTz`]); where a string could be
+/// read either way, as with `"UTC-7"`, it is read as an offset. Abbreviations with a single
+/// common meaning, such as `"PST"`, resolve to that zone (see [`crate::resolve_abbreviation`]);
+/// ones with several, such as `"CST"`, fail with [`Errors::AmbiguousAbbreviation`].
+/// Abbreviations that are also tz database identifiers, such as `"EST"`, `"CET"` or `"GMT"`,
+/// are read as the identifier in any case.
+///
+/// # Example
+///
+/// ```rust
+/// use chrono::{TimeZone, Utc};
+/// use timezone_converter::{TimeZoneConverter, ZoneSpec};
+///
+/// let zone: ZoneSpec = "UTC+5:30".parse().unwrap();
+/// assert_eq!(zone.name(), "+05:30");
+///
+/// let converter = TimeZoneConverter::new("Europe/London", "-07:00").unwrap();
+/// let dt = Utc.with_ymd_and_hms(2024, 7, 1, 12, 0, 0).unwrap();
+/// assert_eq!(converter.convert_zoned(dt).unwrap().to_string(), "2024-07-01 05:00:00 -07:00");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ZoneSpec {
+    /// An IANA timezone such as `Europe/London`
+    Named(Tz),
+    /// A fixed offset from UTC
+    Fixed(FixedOffset),
+    /// A zone following a POSIX TZ rule string
+    Posix(PosixTz),
+    /// A zone with user-defined rules
+    Custom(CustomZone),
+    /// A zone read from the system's zoneinfo directory
+    #[cfg(feature = "system-tz")]
+    System(SystemZone),
+}
+
+/// The offset of a [`ZoneSpec`] at a particular instant
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ZoneSpecOffset {
+    /// The offset of an IANA timezone, with its abbreviation and DST components
+    Named(TzOffset),
+    /// A fixed offset
+    Fixed(FixedOffset),
+    /// The offset of a POSIX TZ rule zone, with its abbreviation
+    Posix(PosixOffset),
+    /// The offset of a user-defined zone, with its abbreviation
+    Custom(CustomOffset),
+    /// The offset of a zone read from the system's zoneinfo directory, with its abbreviation
+    #[cfg(feature = "system-tz")]
+    System(SystemOffset),
+}
+
+impl ZoneSpec {
+    /// Gets the zone's name: the IANA identifier, the offset in `±HH:MM` form, the POSIX rule
+    /// string, or a custom zone's own name
+    pub fn name(&self) -> Cow<'static, str> {
+        match self {
+            Self::Named(tz) => Cow::Borrowed(tz.name()),
+            Self::Fixed(offset) => Cow::Owned(offset.to_string()),
+            Self::Posix(posix) => Cow::Owned(posix.to_string()),
+            Self::Custom(zone) => Cow::Owned(zone.name().to_string()),
+            #[cfg(feature = "system-tz")]
+            Self::System(zone) => Cow::Borrowed(zone.name()),
+        }
+    }
+
+    /// Gets the IANA timezone, if this is not a fixed offset
+    pub fn as_tz(&self) -> Option<Tz> {
+        match self {
+            Self::Named(tz) => Some(*tz),
+            _ => None,
+        }
+    }
+}
+
+impl ZoneSpecOffset {
+    /// Gets the abbreviation in effect (e.g., "EST"), if the zone defines one
+    pub fn abbreviation(&self) -> Option<&str> {
+        match self {
+            Self::Named(offset) => offset.abbreviation(),
+            Self::Fixed(_) => None,
+            Self::Posix(offset) => Some(offset.abbreviation()),
+            Self::Custom(offset) => Some(offset.abbreviation()),
+            #[cfg(feature = "system-tz")]
+            Self::System(offset) => Some(offset.abbreviation()),
+        }
+    }
+}
+
+impl fmt::Display for ZoneSpec {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.name())
+    }
+}
+
+impl FromStr for ZoneSpec {
+    type Err = Errors;
+
+    fn from_str(s: &str) -> Result<Self, Errors> {
+        if let Ok(tz) = s.parse::<Tz>() {
+            return Ok(Self::Named(canonical_zone(tz).0));
+        }
+        if let Some(tz) = offset_name_zone(s) {
+            return Ok(Self::Named(tz));
+        }
+        if let Some(offset) = parse_offset(s.trim()) {
+            return Ok(Self::Fixed(offset));
+        }
+        if let Some(tz) = abbreviation_identifier(s) {
+            return Ok(Self::Named(canonical_zone(tz).0));
+        }
+        match resolve_abbreviation(s, None)[..] {
+            [] => {}
+            [tz] => return Ok(Self::Named(tz)),
+            ref zones => return Err(Errors::AmbiguousAbbreviation(zones.to_vec())),
+        }
+        match s.parse::<PosixTz>() {
+            Ok(posix) => Ok(Self::Posix(posix)),
+            Err(_) => parse_tz(s).map(Self::Named),
+        }
+    }
+}
+
+impl From<Tz> for ZoneSpec {
+    fn from(tz: Tz) -> Self {
+        Self::Named(tz)
+    }
+}
+
+impl From<FixedOffset> for ZoneSpec {
+    fn from(offset: FixedOffset) -> Self {
+        Self::Fixed(offset)
+    }
+}
+
+impl From<PosixTz> for ZoneSpec {
+    fn from(posix: PosixTz) -> Self {
+        Self::Posix(posix)
+    }
+}
+
+impl From<CustomZone> for ZoneSpec {
+    fn from(zone: CustomZone) -> Self {
+        Self::Custom(zone)
+    }
+}
+
+#[cfg(feature = "system-tz")]
+impl From<SystemZone> for ZoneSpec {
+    fn from(zone: SystemZone) -> Self {
+        Self::System(zone)
+    }
+}
+
+impl PartialEq<Tz> for ZoneSpec {
+    fn eq(&self, other: &Tz) -> bool {
+        self.as_tz() == Some(*other)
+    }
+}
+
+impl TimeZone for ZoneSpec {
+    type Offset = ZoneSpecOffset;
+
+    fn from_offset(offset: &ZoneSpecOffset) -> Self {
+        match offset {
+            ZoneSpecOffset::Named(offset) => Self::Named(Tz::from_offset(offset)),
+            ZoneSpecOffset::Fixed(offset) => Self::Fixed(*offset),
+            ZoneSpecOffset::Posix(offset) => Self::Posix(PosixTz::from_offset(offset)),
+            ZoneSpecOffset::Custom(offset) => Self::Custom(CustomZone::from_offset(offset)),
+            #[cfg(feature = "system-tz")]
+            ZoneSpecOffset::System(offset) => Self::System(SystemZone::from_offset(offset)),
+        }
+    }
+
+    fn offset_from_local_date(&self, local: &NaiveDate) -> LocalResult<ZoneSpecOffset> {
+        match self {
+            Self::Named(tz) => tz.offset_from_local_date(local).map(ZoneSpecOffset::Named),
+            Self::Fixed(offset) => LocalResult::Single(ZoneSpecOffset::Fixed(*offset)),
+            Self::Posix(posix) => posix.offset_from_local_date(local).map(ZoneSpecOffset::Posix),
+            Self::Custom(zone) => zone.offset_from_local_date(local).map(ZoneSpecOffset::Custom),
+            #[cfg(feature = "system-tz")]
+            Self::System(zone) => zone.offset_from_local_date(local).map(ZoneSpecOffset::System),
+        }
+    }
+
+    fn offset_from_local_datetime(&self, local: &NaiveDateTime) -> LocalResult<ZoneSpecOffset> {
+        match self {
+            Self::Named(tz) => tz.offset_from_local_datetime(local).map(ZoneSpecOffset::Named),
+            Self::Fixed(offset) => LocalResult::Single(ZoneSpecOffset::Fixed(*offset)),
+            Self::Posix(posix) => posix.offset_from_local_datetime(local).map(ZoneSpecOffset::Posix),
+            Self::Custom(zone) => zone.offset_from_local_datetime(local).map(ZoneSpecOffset::Custom),
+            #[cfg(feature = "system-tz")]
+            Self::System(zone) => zone.offset_from_local_datetime(local).map(ZoneSpecOffset::System),
+        }
+    }
+
+    fn offset_from_utc_date(&self, utc: &NaiveDate) -> ZoneSpecOffset {
+        match self {
+            Self::Named(tz) => ZoneSpecOffset::Named(tz.offset_from_utc_date(utc)),
+            Self::Fixed(offset) => ZoneSpecOffset::Fixed(*offset),
+            Self::Posix(posix) => ZoneSpecOffset::Posix(posix.offset_from_utc_date(utc)),
+            Self::Custom(zone) => ZoneSpecOffset::Custom(zone.offset_from_utc_date(utc)),
+            #[cfg(feature = "system-tz")]
+            Self::System(zone) => ZoneSpecOffset::System(zone.offset_from_utc_date(utc)),
+        }
+    }
+
+    fn offset_from_utc_datetime(&self, utc: &NaiveDateTime) -> ZoneSpecOffset {
+        match self {
+            Self::Named(tz) => ZoneSpecOffset::Named(tz.offset_from_utc_datetime(utc)),
+            Self::Fixed(offset) => ZoneSpecOffset::Fixed(*offset),
+            Self::Posix(posix) => ZoneSpecOffset::Posix(posix.offset_from_utc_datetime(utc)),
+            Self::Custom(zone) => ZoneSpecOffset::Custom(zone.offset_from_utc_datetime(utc)),
+            #[cfg(feature = "system-tz")]
+            Self::System(zone) => ZoneSpecOffset::System(zone.offset_from_utc_datetime(utc)),
+        }
+    }
+}
+
+impl Offset for ZoneSpecOffset {
+    fn fix(&self) -> FixedOffset {
+        match self {
+            Self::Named(offset) => offset.fix(),
+            Self::Fixed(offset) => *offset,
+            Self::Posix(offset) => offset.fix(),
+            Self::Custom(offset) => offset.fix(),
+            #[cfg(feature = "system-tz")]
+            Self::System(offset) => offset.fix(),
+        }
+    }
+}
+
+impl OffsetComponents for ZoneSpecOffset {
+    fn base_utc_offset(&self) -> Duration {
+        match self {
+            Self::Named(offset) => offset.base_utc_offset(),
+            Self::Fixed(offset) => Duration::seconds(offset.local_minus_utc() as i64),
+            Self::Posix(offset) => offset.base_utc_offset(),
+            Self::Custom(offset) => offset.base_utc_offset(),
+            #[cfg(feature = "system-tz")]
+            Self::System(offset) => offset.base_utc_offset(),
+        }
+    }
+
+    fn dst_offset(&self) -> Duration {
+        match self {
+            Self::Named(offset) => offset.dst_offset(),
+            Self::Fixed(_) => Duration::zero(),
+            Self::Posix(offset) => offset.dst_offset(),
+            Self::Custom(offset) => offset.dst_offset(),
+            #[cfg(feature = "system-tz")]
+            Self::System(offset) => offset.dst_offset(),
+        }
+    }
+}
+
+impl fmt::Display for ZoneSpecOffset {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Named(offset) => fmt::Display::fmt(offset, f),
+            Self::Fixed(offset) => fmt::Display::fmt(offset, f),
+            Self::Posix(offset) => fmt::Display::fmt(offset, f),
+            Self::Custom(offset) => fmt::Display::fmt(offset, f),
+            #[cfg(feature = "system-tz")]
+            Self::System(offset) => fmt::Display::fmt(offset, f),
+        }
+    }
+}
+
+impl fmt::Debug for ZoneSpecOffset {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Named(offset) => fmt::Debug::fmt(offset, f),
+            Self::Fixed(offset) => fmt::Debug::fmt(offset, f),
+            Self::Posix(offset) => fmt::Debug::fmt(offset, f),
+            Self::Custom(offset) => fmt::Debug::fmt(offset, f),
+            #[cfg(feature = "system-tz")]
+            Self::System(offset) => fmt::Debug::fmt(offset, f),
+        }
+    }
+}
+
+/// Parses a literal offset such as `+05:30`, `-0700`, `+5`, `UTC-7` or `GMT+5:45`
+pub(crate) fn parse_offset(s: &str) -> Option<FixedOffset> {
+    let upper = s.to_ascii_uppercase();
+    let rest = upper.strip_prefix("UTC").or_else(|| upper.strip_prefix("GMT")).unwrap_or(&upper);
+    let (sign, rest) = match rest.as_bytes().first()? {
+        b'+' => (1, &rest[1..]),
+        b'-' => (-1, &rest[1..]),
+        _ => return None,
+    };
+    if rest.is_empty() || !rest.bytes().all(|b| b.is_ascii_digit() || b == b':') {
+        return None;
+    }
+    let (hours, minutes) = match rest.split_once(':') {
+        Some((hours, minutes)) if minutes.len() == 2 => (hours, minutes),
+        Some(_) => return None,
+        None if rest.len() == 4 => rest.split_at(2),
+        None if rest.len() <= 2 => (rest, "0"),
+        None => return None,
+    };
+    let (hours, minutes): (i32, i32) = (hours.parse().ok()?, minutes.parse().ok()?);
+    if hours.to_string().len() > 2 || hours > 18 || minutes >= 60 {
+        return None;
+    }
+    FixedOffset::east_opt(sign * (hours * 3600 + minutes * 60))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+
+    #[test]
+    fn parses_offsets_and_names() {
+        let parsed = |s: &str| s.parse::<ZoneSpec>().map(|zone| zone.name().into_owned());
+        assert_eq!(parsed("+05:30").unwrap(), "+05:30");
+        assert_eq!(parsed("-0700").unwrap(), "-07:00");
+        assert_eq!(parsed("utc-7").unwrap(), "Etc/GMT+7");
+        assert_eq!(parsed("utc-0730").unwrap(), "-07:30");
+        assert_eq!(parsed("GMT+5:45").unwrap(), "+05:45");
+        assert_eq!(parsed("+0").unwrap(), "+00:00");
+        assert_eq!(parsed("UTC").unwrap(), "UTC");
+        assert_eq!(parsed("Etc/GMT+7").unwrap(), "Etc/GMT+7");
+        assert_eq!(parsed("JST-9").unwrap(), "JST-9");
+        assert_eq!(parsed("pst").unwrap(), "America/Los_Angeles");
+        assert_eq!(parsed("CST").unwrap_err(), Errors::AmbiguousAbbreviation(vec![Tz::America__Chicago, Tz::Asia__Shanghai]));
+        assert_eq!(parsed(" EST5EDT,M3.2.0,M11.1.0 ").unwrap(), "EST5EDT,M3.2.0,M11.1.0");
+
+        for invalid in ["+", "UTC+", "+5:3", "+123", "+19", "+05:60", "05:30", "UTC 5"] {
+            assert!(matches!(invalid.parse::<ZoneSpec>(), Err(Errors::InvalidTimeZone { .. })), "{}", invalid);
+        }
+    }
+
+    #[test]
+    fn abbreviations_named_like_identifiers_ignore_case() {
+        for abbreviation in ["EST", "MST", "HST", "CET", "EET", "WET", "GMT"] {
+            let expected = ZoneSpec::Named(canonical_zone(abbreviation.parse().unwrap()).0);
+            assert_eq!(abbreviation.parse::<ZoneSpec>().unwrap(), expected, "{}", abbreviation);
+            assert_eq!(abbreviation.to_lowercase().parse::<ZoneSpec>().unwrap(), expected, "{}", abbreviation);
+        }
+        assert_eq!("est".parse::<ZoneSpec>().unwrap().name(), "America/Panama");
+    }
+
+    #[test]
+    fn behaves_like_the_underlying_zone() {
+        let instant = Utc.with_ymd_and_hms(2024, 7, 1, 12, 0, 0).unwrap();
+        let named = instant.with_timezone(&ZoneSpec::Named(Tz::America__New_York));
+        assert_eq!(named.to_string(), "2024-07-01 08:00:00 EDT");
+        assert_eq!(named.offset().dst_offset(), Duration::hours(1));
+        assert_eq!(named.offset().abbreviation(), Some("EDT"));
+
+        let fixed = instant.with_timezone(&"+05:30".parse::<ZoneSpec>().unwrap());
+        assert_eq!(fixed.to_string(), "2024-07-01 17:30:00 +05:30");
+        assert_eq!(fixed.offset().dst_offset(), Duration::zero());
+        assert_eq!(fixed, instant);
+    }
+}